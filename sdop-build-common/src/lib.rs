@@ -18,6 +18,8 @@ pub const PETS_RON_PATH: &str = "../assets/pets.ron";
 pub const FOODS_RON_PATH: &str = "../assets/foods.ron";
 pub const ITEMS_RON_PATH: &str = "../assets/items.ron";
 pub const LOCATIONS_RON_PATH: &str = "../assets/locations.ron";
+pub const ACHIEVEMENTS_RON_PATH: &str = "../assets/achievements.ron";
+pub const RECIPES_RON_PATH: &str = "../assets/recipes.ron";
 pub const SOUNDS_PATH: &str = "../assets/sounds";
 
 #[derive(Debug, Clone, Copy)]
@@ -98,6 +100,16 @@ pub struct ItemReward {
     pub odds: f32,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PackBonusTemplate {
+    pub item: String,
+    pub skill: i32,
+    #[serde(default)]
+    pub reward: Option<ItemReward>,
+    #[serde(default)]
+    pub lose_odds: f32,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LocationRewards {
     pub money_start: i32,
@@ -109,6 +121,39 @@ fn default_true() -> bool {
     true
 }
 
+fn default_daily_wins() -> u8 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+pub enum ExploreOutcomeTemplate {
+    Nothing,
+    Rewards(f32),
+    Injure,
+    Sicken,
+    Unlock(String),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventChoiceTemplate {
+    pub text: String,
+    pub outcome: ExploreOutcomeTemplate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExploreEventTemplate {
+    pub name: String,
+    pub odds: f32,
+    pub choices: Vec<EventChoiceTemplate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExploreStageTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub events: Vec<ExploreEventTemplate>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct LocationTemplate {
     pub name: String,
@@ -120,6 +165,46 @@ pub struct LocationTemplate {
     #[serde(default = "default_true")]
     pub in_shop: bool,
     pub life_stages: Vec<sdop_common::LifeStage>,
+    #[serde(default)]
+    pub stages: Vec<ExploreStageTemplate>,
+    #[serde(default = "default_daily_wins")]
+    pub daily_wins: u8,
+    #[serde(default)]
+    pub packing: Vec<PackBonusTemplate>,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub cover: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum AchievementGoalTemplate {
+    EvolveAllAdults,
+    CompleteAllBooks,
+    CatchFish(u16),
+    SucceedAllLocations,
+    SurviveFor(SdopDuration),
+    SeeAlien,
+    PlayAllMinigames,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AchievementTemplate {
+    pub name: String,
+    pub desc: String,
+    pub goal: AchievementGoalTemplate,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum IngredientTemplate {
+    Food(String),
+    Item(String),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecipeTemplate {
+    pub food: String,
+    pub ingredients: Vec<IngredientTemplate>,
 }
 
 #[derive(Serialize, Deserialize, EnumString, Display)]
@@ -164,3 +164,7 @@ impl LifeStage {
         *self as u8
     }
 }
+
+// Limits on expedition stages, the build script checks locations.ron against them
+pub const MAX_EXPLORE_STAGES: usize = 4;
+pub const MAX_EVENT_CHOICES: usize = 3;
@@ -0,0 +1,89 @@
+//! Compares catching up on time away in `CATCH_UP_STEP` steps against running
+//! every 100ms step, both for speed and for how the pets end up.
+//!
+//! cargo bench -p sdop-game --bench catch_up
+
+use std::time::{Duration, Instant};
+
+use sdop_game::{GameEvent, harness::Harness};
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const AWAY_HOURS: u32 = 48;
+const RUNS: u64 = 24;
+
+#[derive(Default)]
+struct Outcomes {
+    elapsed: Duration,
+    deaths: u32,
+    death_hours: u32,
+    poops: u32,
+    got_sick: u32,
+    evolved: u32,
+    fed_hours: u32,
+}
+
+impl Outcomes {
+    fn print(&self, name: &str) {
+        let runs = RUNS as f32;
+        println!(
+            "{name:>9}: {:>10.2?} per run | died {:>5.1}% (avg hour {:>4.1}) | poops {:>5.2} | sick {:>5.2} | evolved {:>5.1}% | fed hours {:>5.1}",
+            self.elapsed / RUNS as u32,
+            self.deaths as f32 / runs * 100.,
+            self.death_hours as f32 / self.deaths.max(1) as f32,
+            self.poops as f32 / runs,
+            self.got_sick as f32 / runs,
+            self.evolved as f32 / runs * 100.,
+            self.fed_hours as f32 / runs,
+        );
+    }
+}
+
+fn run(catch_up: bool) -> Outcomes {
+    let mut outcomes = Outcomes::default();
+
+    for seed in 0..RUNS {
+        let mut h = Harness::new();
+        h.ctx_mut().sim_rng = fastrand::Rng::with_seed(seed);
+        h.ctx_mut().rng = fastrand::Rng::with_seed(seed);
+        h.ctx_mut().pet.stomach_filled = h.pet_definition().stomach_size;
+
+        for hour in 0..AWAY_HOURS {
+            let start = Instant::now();
+            if catch_up {
+                h.catch_up(HOUR);
+            } else {
+                h.sim_for(HOUR);
+            }
+            outcomes.elapsed += start.elapsed();
+
+            if h.ctx().pet.stomach_filled > 0. {
+                outcomes.fed_hours += 1;
+            }
+
+            for event in h.drain_events() {
+                match event {
+                    GameEvent::Pooped => outcomes.poops += 1,
+                    GameEvent::GotSick => outcomes.got_sick += 1,
+                    GameEvent::Died(_) => {
+                        outcomes.deaths += 1;
+                        outcomes.death_hours += hour;
+                    }
+                    _ => {}
+                }
+            }
+            if h.ctx().pet.should_evolve().is_some() && hour + 1 == AWAY_HOURS {
+                outcomes.evolved += 1;
+            }
+            // Clear up so poops keep coming without toxic shock taking over
+            h.ctx_mut().poops = Default::default();
+        }
+    }
+
+    outcomes
+}
+
+fn main() {
+    println!("{RUNS} pets left alone for {AWAY_HOURS} hours");
+    run(false).print("stepped");
+    run(true).print("catch up");
+}
@@ -3,7 +3,7 @@ use chrono::{Datelike, Days, NaiveDate};
 use convert_case::{Case, Casing};
 use image::{GenericImageView, Rgba};
 use sdop_build_common::*;
-use sdop_common::MelodyEntry;
+use sdop_common::{MAX_EVENT_CHOICES, MAX_EXPLORE_STAGES, MelodyEntry};
 use serde::{Deserialize, Serialize};
 use solar_calendar_events::AnnualSolarEvent;
 use std::{
@@ -26,6 +26,8 @@ struct ContentOut {
     sounds_definitions: String,
     locations_definitions: String,
     geo_definitions: String,
+    achievements_definitions: String,
+    recipes_definitions: String,
 }
 
 impl ContentOut {
@@ -39,6 +41,10 @@ impl ContentOut {
         self.locations_definitions
             .push_str(&other.locations_definitions);
         self.geo_definitions.push_str(&other.geo_definitions);
+        self.achievements_definitions
+            .push_str(&other.achievements_definitions);
+        self.recipes_definitions
+            .push_str(&other.recipes_definitions);
     }
 }
 
@@ -363,13 +369,116 @@ enum LifeStage {
     Elder,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Deserialize)]
+enum EvolveConditionTemplate {
+    ItemsOwned(Vec<String>),
+    AnyBookCompleted(Vec<String>),
+    BooksCompleted(usize),
+    ExtraWeightOver(f32),
+    Ill,
+    ColdFor(SdopDuration),
+    HotFor(SdopDuration),
+    Ate(String, usize),
+}
+
+impl EvolveConditionTemplate {
+    fn to_code(&self) -> String {
+        let items = |items: &[String]| {
+            items
+                .iter()
+                .map(|item| format!("ItemKind::{}", item.to_case(Case::Pascal)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match self {
+            EvolveConditionTemplate::ItemsOwned(names) => {
+                format!("EvolveCondition::ItemsOwned(&[{}])", items(names))
+            }
+            EvolveConditionTemplate::AnyBookCompleted(names) => {
+                format!("EvolveCondition::AnyBookCompleted(&[{}])", items(names))
+            }
+            EvolveConditionTemplate::BooksCompleted(count) => {
+                format!("EvolveCondition::BooksCompleted({})", count)
+            }
+            EvolveConditionTemplate::ExtraWeightOver(weight) => {
+                format!("EvolveCondition::ExtraWeightOver({:.2})", weight)
+            }
+            EvolveConditionTemplate::Ill => "EvolveCondition::Ill".to_string(),
+            EvolveConditionTemplate::ColdFor(duration) => format!(
+                "EvolveCondition::ColdFor(Duration::from_secs({}))",
+                duration.duration.as_secs()
+            ),
+            EvolveConditionTemplate::HotFor(duration) => format!(
+                "EvolveCondition::HotFor(Duration::from_secs({}))",
+                duration.duration.as_secs()
+            ),
+            EvolveConditionTemplate::Ate(food, count) => format!(
+                "EvolveCondition::Ate(&crate::food::FOOD_{}, {})",
+                food.replace(" ", "_").to_uppercase(),
+                count
+            ),
+        }
+    }
+}
+
+fn default_evolve_weight() -> usize {
+    1
+}
+
+#[derive(Deserialize)]
+struct EvolutionTemplate {
+    target: String,
+    #[serde(default = "default_evolve_weight")]
+    weight: usize,
+    #[serde(default)]
+    conditions: Vec<EvolveConditionTemplate>,
+}
+
+#[derive(Deserialize, Display)]
+enum FoodTaste {
+    Liked,
+    Disliked,
+    Refused,
+}
+
+fn default_fill_multiplier() -> f32 {
+    1.
+}
+
+#[derive(Deserialize)]
+struct FoodPreferenceTemplate {
+    food: String,
+    taste: FoodTaste,
+    #[serde(default = "default_fill_multiplier")]
+    fill_multiplier: f32,
+}
+
+#[derive(Deserialize)]
+struct SleepTemplate {
+    bedtime: u8,
+    wake: u8,
+}
+
+#[derive(Deserialize)]
 struct PetTemplate {
     name: String,
     life_stage: LifeStage,
     images: PetImageSet,
     stomach_size: f32,
     base_weight: f32,
+    #[serde(default)]
+    evolve_after: Option<SdopDuration>,
+    #[serde(default)]
+    evolutions: Vec<EvolutionTemplate>,
+    #[serde(default)]
+    food_preferences: Vec<FoodPreferenceTemplate>,
+    #[serde(default)]
+    sleep: Option<SleepTemplate>,
+}
+
+fn pet_var_name_for(name: &str) -> String {
+    format!("PET_{}", name.replace(" ", "_").to_uppercase())
 }
 
 fn generate_pet_definitions<P: AsRef<Path>>(path: P) -> ContentOut {
@@ -386,8 +495,7 @@ fn generate_pet_definitions<P: AsRef<Path>>(path: P) -> ContentOut {
     // Define IDS
     let mut pet_vars = Vec::new();
     for (i, template) in templates.iter().enumerate() {
-        let pet_var_name = template.name.replace(" ", "_").to_uppercase();
-        let pet_var_name = format!("PET_{}", pet_var_name);
+        let pet_var_name = pet_var_name_for(&template.name);
         // Normal
         let asset_path_base = PathBuf::from_str(ASSETS_PATH)
             .unwrap()
@@ -446,7 +554,75 @@ fn generate_pet_definitions<P: AsRef<Path>>(path: P) -> ContentOut {
             ));
         }
 
-        pet_definitions.push_str(");");
+        pet_definitions.push(')');
+
+        if let Some(evolve_after) = &template.evolve_after {
+            pet_definitions.push_str(&format!(
+                ".with_evolutions(Duration::from_secs({}), &{}_EVOLUTIONS)",
+                evolve_after.duration.as_secs(),
+                pet_var_name
+            ));
+        } else if !template.evolutions.is_empty() {
+            panic!("{} has evolutions but no evolve_after", template.name);
+        }
+        if let Some(sleep) = &template.sleep {
+            pet_definitions.push_str(&format!(".with_sleep({}, {})", sleep.bedtime, sleep.wake));
+        }
+        pet_definitions.push_str(&format!(
+            ".with_food_preferences(&{}_FOOD_PREFERENCES);",
+            pet_var_name
+        ));
+
+        let mut food_preferences = String::new();
+        for preference in &template.food_preferences {
+            food_preferences.push_str(&format!(
+                "FoodPreference::new(&crate::food::FOOD_{}, FoodTaste::{}, {:.2}), ",
+                preference.food.replace(" ", "_").to_uppercase(),
+                preference.taste,
+                preference.fill_multiplier
+            ));
+        }
+        pet_definitions.push_str(&format!(
+            "static {}_FOOD_PREFERENCES: [FoodPreference; {}] = [{}];",
+            pet_var_name,
+            template.food_preferences.len(),
+            food_preferences
+        ));
+
+        let mut evolutions = String::new();
+        for (j, evolution) in template.evolutions.iter().enumerate() {
+            if !templates.iter().any(|pet| pet.name == evolution.target) {
+                panic!(
+                    "{} evolves into unknown pet {}",
+                    template.name, evolution.target
+                );
+            }
+
+            let conditions_var_name = format!("{}_EVOLUTION_{}", pet_var_name, j);
+            let conditions = evolution
+                .conditions
+                .iter()
+                .map(EvolveConditionTemplate::to_code)
+                .collect::<Vec<_>>();
+            pet_definitions.push_str(&format!(
+                "static {}: [EvolveCondition; {}] = [{}];",
+                conditions_var_name,
+                conditions.len(),
+                conditions.join(", ")
+            ));
+            evolutions.push_str(&format!(
+                "Evolution::new({}_ID, {}, &{}), ",
+                pet_var_name_for(&evolution.target),
+                evolution.weight,
+                conditions_var_name
+            ));
+        }
+        pet_definitions.push_str(&format!(
+            "static {}_EVOLUTIONS: [Evolution; {}] = [{}];",
+            pet_var_name,
+            template.evolutions.len(),
+            evolutions
+        ));
 
         pet_vars.push(pet_var_name);
     }
@@ -652,7 +828,11 @@ fn generate_item_enum<P: AsRef<Path>>(path: P, food_path: P) -> ContentOut {
 
         category_fn.push_str(&format!("Self::{} => ItemCategory::Map,\n", enum_name,));
 
-        in_shop_def.push_str(&format!("Self::{} => {},", enum_name, template.in_shop));
+        in_shop_def.push_str(&format!(
+            "Self::{} => {},",
+            enum_name,
+            template.in_shop && !template.hidden
+        ));
 
         item_count += 1;
     }
@@ -907,6 +1087,38 @@ fn generate_locations() -> ContentOut {
     let mut locations_def = String::new();
     let mut names = Vec::new();
 
+    // Hidden locations are only found through an event, nothing else hands out their map
+    for hidden in location_templates.iter().filter(|location| location.hidden) {
+        let map = format!("Map{}", hidden.name.to_case(Case::Pascal));
+        for location in &location_templates {
+            let rewards = location.rewards.items.iter().map(|reward| &reward.item);
+            let packing = location
+                .packing
+                .iter()
+                .filter_map(|bonus| bonus.reward.as_ref().map(|reward| &reward.item));
+            if rewards
+                .chain(packing)
+                .any(|item| item.to_case(Case::Pascal) == map)
+            {
+                panic!(
+                    "{} rewards the map to hidden {}",
+                    location.name, hidden.name
+                );
+            }
+        }
+        let unlocked = location_templates
+            .iter()
+            .flat_map(|location| &location.stages)
+            .flat_map(|stage| &stage.events)
+            .flat_map(|event| &event.choices)
+            .any(|choice| {
+                matches!(&choice.outcome, ExploreOutcomeTemplate::Unlock(name) if name == &hidden.name)
+            });
+        if !unlocked {
+            panic!("hidden {} is never unlocked by an event", hidden.name);
+        }
+    }
+
     for (i, entry) in location_templates.iter().enumerate() {
         let mut rewards = format!(
             "LocationRewards::new({}..{}, &[",
@@ -923,7 +1135,11 @@ fn generate_locations() -> ContentOut {
 
         let cover = format!(
             "crate::assets::IMAGE_LOCATION_{}",
-            entry.name.to_case(Case::UpperSnake)
+            entry
+                .cover
+                .as_ref()
+                .unwrap_or(&entry.name)
+                .to_case(Case::UpperSnake)
         );
         let mut activities = "&[".to_owned();
         for activity in &entry.activities {
@@ -939,8 +1155,81 @@ fn generate_locations() -> ContentOut {
             stages.push_str(",");
         }
 
+        if entry.stages.len() > MAX_EXPLORE_STAGES {
+            panic!("{} has more than {} stages", entry.name, MAX_EXPLORE_STAGES);
+        }
+        let mut explore_stages = "&[".to_owned();
+        for stage in &entry.stages {
+            explore_stages.push_str(&format!("ExploreStage::new(\"{}\", &[", stage.name));
+            for event in &stage.events {
+                if event.choices.is_empty() {
+                    panic!("{} event {} has no choices", entry.name, event.name);
+                }
+                if event.choices.len() > MAX_EVENT_CHOICES {
+                    panic!(
+                        "{} event {} has more than {} choices",
+                        entry.name, event.name, MAX_EVENT_CHOICES
+                    );
+                }
+                explore_stages.push_str(&format!(
+                    "ExploreEvent::new(\"{}\", {:?}, &[",
+                    event.name, event.odds
+                ));
+                for choice in &event.choices {
+                    let outcome = match &choice.outcome {
+                        ExploreOutcomeTemplate::Rewards(multiplier) => {
+                            format!("ExploreOutcome::Rewards({:?})", multiplier)
+                        }
+                        ExploreOutcomeTemplate::Unlock(name) => {
+                            match location_templates.iter().find(|location| {
+                                location.name != entry.name && &location.name == name
+                            }) {
+                                Some(location) if location.hidden => {}
+                                Some(_) => {
+                                    panic!("{} unlocks {} which is not hidden", entry.name, name)
+                                }
+                                None => panic!("{} unlocks unknown location {}", entry.name, name),
+                            }
+                            format!(
+                                "ExploreOutcome::Unlock(&LOCATION_{})",
+                                name.to_case(Case::UpperSnake)
+                            )
+                        }
+                        outcome => format!("ExploreOutcome::{:?}", outcome),
+                    };
+                    explore_stages.push_str(&format!(
+                        "EventChoice::new(\"{}\", {}),",
+                        choice.text, outcome
+                    ));
+                }
+                explore_stages.push_str("]),");
+            }
+            explore_stages.push_str("]),");
+        }
+        explore_stages.push(']');
+
+        let mut packing = "&[".to_owned();
+        for bonus in &entry.packing {
+            let reward = match &bonus.reward {
+                Some(reward) => format!(
+                    "Some(ItemReward::new(crate::items::ItemKind::{}, {:?}))",
+                    reward.item.to_case(Case::Pascal),
+                    reward.odds
+                ),
+                None => "None".to_owned(),
+            };
+            packing.push_str(&format!(
+                "PackBonus::new(crate::items::ItemKind::{}, {}, {}, {:?}),",
+                bonus.item.to_case(Case::Pascal),
+                bonus.skill,
+                reward,
+                bonus.lose_odds
+            ));
+        }
+        packing.push(']');
+
         let location_def = &format!(
-            "pub static {}: Location = Location::new({}, \"{}\", Duration::from_secs({}), Duration::from_secs({}), {}, {}, {}, {}, crate::items::ItemKind::Map{}, &[{}]);",
+            "pub static {}: Location = Location::new({}, \"{}\", Duration::from_secs({}), Duration::from_secs({}), {}, {}, {}, {}, crate::items::ItemKind::Map{}, &[{}], {}).with_daily_wins({}).with_packing({});",
             const_name,
             i,
             entry.name,
@@ -951,7 +1240,10 @@ fn generate_locations() -> ContentOut {
             cover,
             activities,
             entry.name.to_case(Case::Pascal),
-            stages
+            stages,
+            explore_stages,
+            entry.daily_wins,
+            packing
         );
 
         locations_def.push_str(&location_def);
@@ -978,6 +1270,115 @@ fn generate_locations() -> ContentOut {
     }
 }
 
+fn generate_achievements() -> ContentOut {
+    let achievements_path = PathBuf::from_str(ACHIEVEMENTS_RON_PATH).unwrap();
+
+    let contents = std::fs::read_to_string(achievements_path).unwrap();
+    let templates: Vec<AchievementTemplate> = ron::from_str(&contents).unwrap();
+
+    let mut achievements_def = String::new();
+    let mut names = Vec::new();
+
+    for (i, entry) in templates.iter().enumerate() {
+        let goal = match &entry.goal {
+            AchievementGoalTemplate::CatchFish(count) => {
+                format!("AchievementGoal::CatchFish({})", count)
+            }
+            AchievementGoalTemplate::SurviveFor(duration) => format!(
+                "AchievementGoal::SurviveFor(Duration::from_secs({}))",
+                duration.duration.as_secs()
+            ),
+            goal => format!("AchievementGoal::{:?}", goal),
+        };
+
+        let const_name = format!("ACHIEVEMENT_{}", entry.name.to_case(Case::UpperSnake));
+
+        achievements_def.push_str(&format!(
+            "pub static {}: Achievement = Achievement::new({}, \"{}\", \"{}\", {});",
+            const_name,
+            i,
+            entry.name.to_uppercase(),
+            entry.desc,
+            goal
+        ));
+
+        names.push(const_name);
+    }
+
+    achievements_def.push_str("pub static ACHIEVEMENTS: &[&'static Achievement] = &[");
+    for name in names {
+        achievements_def.push('&');
+        achievements_def.push_str(&name);
+        achievements_def.push(',');
+    }
+    achievements_def.push_str("];");
+
+    achievements_def.push_str(&format!(
+        "pub const ACHIEVEMENT_COUNT: usize = {};",
+        templates.len()
+    ));
+
+    ContentOut {
+        achievements_definitions: achievements_def,
+        ..Default::default()
+    }
+}
+
+fn generate_recipes() -> ContentOut {
+    let contents = std::fs::read_to_string(FOODS_RON_PATH).unwrap();
+    let food_templates: Vec<FoodTemplate> = ron::from_str(&contents).unwrap();
+
+    let contents = std::fs::read_to_string(ITEMS_RON_PATH).unwrap();
+    let item_templates: Vec<ItemTemplate> = ron::from_str(&contents).unwrap();
+
+    let contents = std::fs::read_to_string(RECIPES_RON_PATH).unwrap();
+    let templates: Vec<RecipeTemplate> = ron::from_str(&contents).unwrap();
+
+    let food_var = |name: &str| {
+        if !food_templates.iter().any(|food| food.name == name) {
+            panic!("Recipe uses unknown food {}", name);
+        }
+        format!(
+            "&crate::food::FOOD_{}",
+            name.replace(" ", "_").to_uppercase()
+        )
+    };
+
+    let mut recipes_def = String::new();
+    recipes_def.push_str("pub static RECIPES: &[Recipe] = &[");
+    for template in &templates {
+        let mut ingredients = String::new();
+        for ingredient in &template.ingredients {
+            let ingredient = match ingredient {
+                IngredientTemplate::Food(name) => format!("Ingredient::Food({})", food_var(name)),
+                IngredientTemplate::Item(name) => {
+                    if !item_templates.iter().any(|item| &item.name == name) {
+                        panic!("Recipe uses unknown item {}", name);
+                    }
+                    format!(
+                        "Ingredient::Item(crate::items::ItemKind::{})",
+                        name.to_case(Case::Pascal)
+                    )
+                }
+            };
+            ingredients.push_str(&ingredient);
+            ingredients.push(',');
+        }
+
+        recipes_def.push_str(&format!(
+            "Recipe::new({}, &[{}]),",
+            food_var(&template.food),
+            ingredients
+        ));
+    }
+    recipes_def.push_str("];");
+
+    ContentOut {
+        recipes_definitions: recipes_def,
+        ..Default::default()
+    }
+}
+
 fn generate_geo() -> ContentOut {
     const TEMPLATE: &'static str = r#"
 const ZERO__*T_REP_UPPER*_: _*T_REP*_ = 0 as _*T_REP*_;
@@ -1175,6 +1576,8 @@ fn main() {
         Box::new(|| generate_sounds()),
         Box::new(|| generate_locations()),
         Box::new(|| generate_geo()),
+        Box::new(generate_achievements),
+        Box::new(generate_recipes),
     ];
 
     for func in gen_fun {
@@ -1195,6 +1598,12 @@ fn main() {
         contents.locations_definitions,
     );
     write_file(&out_dir, "dist_geo.rs", contents.geo_definitions);
+    write_file(
+        &out_dir,
+        "dist_achievements.rs",
+        contents.achievements_definitions,
+    );
+    write_file(&out_dir, "dist_recipes.rs", contents.recipes_definitions);
 
     println!("cargo::rerun-if-changed=build.rs");
     println!("cargo::rerun-if-changed={}", ASSETS_PATH);
@@ -0,0 +1,222 @@
+use core::time::Duration;
+
+use bincode::{Decode, Encode};
+
+use crate::{
+    bit_array::{BitArray, bytes_for_bits},
+    events::{GameEvent, GameEvents},
+    explore::{LOCATION_COUNT, LOCATIONS},
+    game_context::GameContext,
+    items::{BOOK_COUNT, BOOKS},
+    pet::{
+        PetInstance,
+        definition::{PET_ADULT_ID_COUNT, PET_ADULTS},
+    },
+    scene::game_select::{MINIGAME_COUNT, MiniGame},
+    sounds::{SONG_FAN_FARE, SongPlayOptions},
+};
+
+include!(concat!(env!("OUT_DIR"), "/dist_achievements.rs"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AchievementGoal {
+    EvolveAllAdults,
+    CompleteAllBooks,
+    CatchFish(u16),
+    SucceedAllLocations,
+    SurviveFor(Duration),
+    SeeAlien,
+    PlayAllMinigames,
+}
+
+pub struct Achievement {
+    pub id: usize,
+    pub name: &'static str,
+    pub desc: &'static str,
+    pub goal: AchievementGoal,
+}
+
+impl Achievement {
+    pub const fn new(
+        id: usize,
+        name: &'static str,
+        desc: &'static str,
+        goal: AchievementGoal,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            desc,
+            goal,
+        }
+    }
+}
+
+/// Everything every pet has done towards the achievements, kept across generations
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub struct Achievements {
+    unlocked: BitArray<{ bytes_for_bits(ACHIEVEMENT_COUNT) }>,
+    adults: BitArray<{ bytes_for_bits(PET_ADULT_ID_COUNT) }>,
+    books: BitArray<{ bytes_for_bits(BOOK_COUNT) }>,
+    locations: BitArray<{ bytes_for_bits(LOCATION_COUNT) }>,
+    minigames: BitArray<{ bytes_for_bits(MINIGAME_COUNT) }>,
+    fish_caught: u16,
+    seen_alien: bool,
+    longest_alive: Duration,
+}
+
+impl Achievements {
+    pub fn is_unlocked(&self, achievement: &Achievement) -> bool {
+        self.unlocked.get_bit(achievement.id)
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.into_iter().count()
+    }
+
+    pub fn record_fish(&mut self) {
+        self.fish_caught = self.fish_caught.saturating_add(1);
+    }
+
+    pub fn record_minigame(&mut self, minigame: MiniGame) {
+        self.minigames.set_bit(minigame as usize, true);
+    }
+
+    pub fn record_alive(&mut self, age: Duration) {
+        self.longest_alive = self.longest_alive.max(age);
+    }
+
+    /// Picks up whatever `pet` has done since it was last looked at
+    pub fn observe(&mut self, pet: &PetInstance) {
+        if let Some(index) = PET_ADULTS.iter().position(|id| *id == pet.def_id) {
+            self.adults.set_bit(index, true);
+        }
+
+        for (i, book) in BOOKS.iter().enumerate() {
+            if pet.book_history.get_read(*book).completed() {
+                self.books.set_bit(i, true);
+            }
+        }
+
+        for location in LOCATIONS {
+            if pet.explore.get_by_id(location.id).successful > 0 {
+                self.locations.set_bit(location.id, true);
+            }
+        }
+
+        self.seen_alien |= pet.seen_alien;
+        self.record_alive(pet.age);
+    }
+
+    /// How far along `achievement` is as done out of needed
+    pub fn progress(&self, achievement: &Achievement) -> (usize, usize) {
+        match achievement.goal {
+            AchievementGoal::EvolveAllAdults => (self.adults.into_iter().count(), PET_ADULTS.len()),
+            AchievementGoal::CompleteAllBooks => (self.books.into_iter().count(), BOOKS.len()),
+            AchievementGoal::CatchFish(count) => {
+                (self.fish_caught.min(count) as usize, count as usize)
+            }
+            AchievementGoal::SucceedAllLocations => {
+                (self.locations.into_iter().count(), LOCATIONS.len())
+            }
+            AchievementGoal::SurviveFor(duration) => (
+                (self.longest_alive.min(duration).as_secs() / 3600) as usize,
+                (duration.as_secs() / 3600) as usize,
+            ),
+            AchievementGoal::SeeAlien => (self.seen_alien as usize, 1),
+            AchievementGoal::PlayAllMinigames => {
+                (self.minigames.into_iter().count(), MINIGAME_COUNT)
+            }
+        }
+    }
+
+    fn reached(&self, achievement: &Achievement) -> bool {
+        match achievement.goal {
+            AchievementGoal::SurviveFor(duration) => self.longest_alive >= duration,
+            _ => {
+                let (done, needed) = self.progress(achievement);
+                done >= needed
+            }
+        }
+    }
+
+    /// Unlocks anything newly reached returning true if there was something
+    pub fn unlock_reached(&mut self, events: &mut GameEvents) -> bool {
+        let mut unlocked = false;
+        for achievement in ACHIEVEMENTS {
+            if !self.is_unlocked(achievement) && self.reached(achievement) {
+                self.unlocked.set_bit(achievement.id, true);
+                events.push(GameEvent::AchievementUnlocked(achievement.id));
+                unlocked = true;
+            }
+        }
+        unlocked
+    }
+}
+
+pub fn update_achievements(game_ctx: &mut GameContext) {
+    let achievements = &mut game_ctx.achievements;
+
+    achievements.observe(&game_ctx.pet);
+    for other in game_ctx.household.others() {
+        achievements.observe(&other.pet);
+    }
+    if let Some(longest) = &game_ctx.pet_history.stats().longest_lived {
+        achievements.record_alive(longest.age());
+    }
+
+    if achievements.unlock_reached(&mut game_ctx.events) {
+        game_ctx
+            .sound_system
+            .push_song(SONG_FAN_FARE, SongPlayOptions::new().with_effect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_once() {
+        let mut achievements = Achievements::default();
+        let mut events = GameEvents::default();
+        assert!(!achievements.unlock_reached(&mut events));
+
+        let pet = PetInstance {
+            seen_alien: true,
+            age: Duration::from_days(8),
+            ..Default::default()
+        };
+        achievements.observe(&pet);
+        for _ in 0..30 {
+            achievements.record_fish();
+        }
+
+        assert!(achievements.unlock_reached(&mut events));
+        assert!(!achievements.unlock_reached(&mut events));
+        assert_eq!(achievements.unlocked_count(), 3);
+        assert_eq!(events.len(), 3);
+
+        let angler = ACHIEVEMENTS
+            .iter()
+            .find(|achievement| matches!(achievement.goal, AchievementGoal::CatchFish(_)))
+            .unwrap();
+        assert!(achievements.is_unlocked(angler));
+        let (done, needed) = achievements.progress(angler);
+        assert_eq!(done, needed);
+    }
+
+    #[test]
+    fn test_minigames_progress() {
+        let mut achievements = Achievements::default();
+        let gamer = ACHIEVEMENTS
+            .iter()
+            .find(|achievement| achievement.goal == AchievementGoal::PlayAllMinigames)
+            .unwrap();
+
+        achievements.record_minigame(MiniGame::DogeEm);
+        achievements.record_minigame(MiniGame::DogeEm);
+        assert_eq!(achievements.progress(gamer), (1, MINIGAME_COUNT));
+    }
+}
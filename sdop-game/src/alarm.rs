@@ -8,6 +8,7 @@ use crate::{
     Timestamp,
     anime::{Anime, HasAnime},
     assets::{FRAMES_ALARM, FRAMES_ALARM_MASK, IMAGE_ALARM_0},
+    events::{GameEvent, GameEvents},
     sprite::{Sprite, SpriteMask},
 };
 
@@ -139,7 +140,17 @@ impl AlarmState {
         }
     }
 
-    pub fn tick(&mut self, time: &Timestamp) {
+    pub fn tick(&mut self, time: &Timestamp, events: &mut GameEvents) {
+        let was_ringing = self.ringing;
+        self.tick_ringing(time);
+        match (was_ringing, self.ringing) {
+            (false, true) => events.push(GameEvent::AlarmStarted),
+            (true, false) => events.push(GameEvent::AlarmStopped),
+            _ => {}
+        }
+    }
+
+    fn tick_ringing(&mut self, time: &Timestamp) {
         if let Some(triggered_time) = self.triggered_time {
             if *time - triggered_time > Duration::from_mins(3) {
                 self.triggered_time = None;
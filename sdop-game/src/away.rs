@@ -0,0 +1,102 @@
+use core::time::Duration;
+
+use crate::{death::DeathCause, events::GameEvent, money::Money};
+
+/// What happened while the game was off, built up during catch up
+#[derive(Clone, Copy, PartialEq)]
+pub struct AwaySummary {
+    pub away: Duration,
+    // Time past the offline cap that was never simulated
+    pub skipped: Duration,
+    pub poops: u8,
+    pub lowest_food_percent: f32,
+    pub starved: bool,
+    pub got_sick: bool,
+    pub recovered: bool,
+    pub explore: Option<(bool, Money)>,
+    pub suiters: u8,
+    pub egg_ready: bool,
+    pub died: Option<DeathCause>,
+}
+
+impl Default for AwaySummary {
+    fn default() -> Self {
+        Self {
+            away: Duration::ZERO,
+            skipped: Duration::ZERO,
+            poops: 0,
+            lowest_food_percent: 1.,
+            starved: false,
+            got_sick: false,
+            recovered: false,
+            explore: None,
+            suiters: 0,
+            egg_ready: false,
+            died: None,
+        }
+    }
+}
+
+impl AwaySummary {
+    pub fn record(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::Pooped => self.poops = self.poops.saturating_add(1),
+            GameEvent::GotSick => self.got_sick = true,
+            GameEvent::Recovered => self.recovered = true,
+            GameEvent::Died(cause) => self.died = Some(cause),
+            GameEvent::SuiterArrived => self.suiters = self.suiters.saturating_add(1),
+            GameEvent::ExploreFinished {
+                completed,
+                earnings,
+                ..
+            } => self.explore = Some((completed, earnings)),
+            _ => {}
+        }
+    }
+
+    pub fn record_food(&mut self, food_percent: f32, starving: bool) {
+        self.lowest_food_percent = self.lowest_food_percent.min(food_percent);
+        self.starved |= starving;
+    }
+
+    /// If anything happened worth telling the player about
+    pub fn eventful(&self) -> bool {
+        self.skipped > Duration::ZERO
+            || self.poops > 0
+            || self.starved
+            || self.got_sick
+            || self.recovered
+            || self.explore.is_some()
+            || self.suiters > 0
+            || self.egg_ready
+            || self.died.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut summary = AwaySummary::default();
+        assert!(!summary.eventful());
+
+        summary.record(&GameEvent::Pooped);
+        summary.record(&GameEvent::Pooped);
+        summary.record(&GameEvent::FellAsleep);
+        summary.record(&GameEvent::ExploreFinished {
+            location_id: 1,
+            completed: true,
+            earnings: 20,
+        });
+        summary.record_food(0.4, false);
+        summary.record_food(0.6, false);
+
+        assert!(summary.eventful());
+        assert_eq!(summary.poops, 2);
+        assert_eq!(summary.explore, Some((true, 20)));
+        assert_eq!(summary.lowest_food_percent, 0.4);
+        assert!(!summary.starved);
+    }
+}
@@ -4,8 +4,10 @@ pub const fn bytes_for_bits(n: usize) -> usize {
     n.div_ceil(8)
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Copy, Clone)]
 pub struct BitArray<const N: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     data: [u8; N],
 }
 
@@ -0,0 +1,190 @@
+use bincode::{Decode, Encode};
+
+use crate::{
+    Timestamp,
+    assets::StaticImage,
+    food::{FOOD_COUNT, Food},
+    game_consts::COOKED_TREAT_DURATION,
+    household::HouseholdTimers,
+    items::{Inventory, ItemKind},
+    pantry::Pantry,
+    pet::UniquePetId,
+};
+
+include!(concat!(env!("OUT_DIR"), "/dist_recipes.rs"));
+
+#[derive(Clone, Copy)]
+pub enum Ingredient {
+    // Taken from the pantry
+    Food(&'static Food),
+    // Used up when cooked
+    Item(ItemKind),
+}
+
+impl Ingredient {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ingredient::Food(food) => food.name,
+            Ingredient::Item(item) => item.name(),
+        }
+    }
+
+    pub fn image(&self) -> &'static StaticImage {
+        match self {
+            Ingredient::Food(food) => food.image,
+            Ingredient::Item(item) => item.image(),
+        }
+    }
+
+    pub fn available(&self, inventory: &Inventory, pantry: &Pantry) -> bool {
+        match self {
+            Ingredient::Food(food) => pantry.count(food) > 0,
+            Ingredient::Item(item) => inventory.has_item(*item),
+        }
+    }
+
+    /// Returns if what was used had spoiled
+    fn use_up(&self, inventory: &mut Inventory, pantry: &mut Pantry) -> bool {
+        match self {
+            Ingredient::Food(food) => pantry.take(food).unwrap_or_default(),
+            Ingredient::Item(item) => {
+                inventory.add_item(*item, -1);
+                false
+            }
+        }
+    }
+}
+
+pub struct Recipe {
+    pub food: &'static Food,
+    pub ingredients: &'static [Ingredient],
+}
+
+impl Recipe {
+    pub const fn new(food: &'static Food, ingredients: &'static [Ingredient]) -> Self {
+        Self { food, ingredients }
+    }
+
+    /// Cooking a dish needs its recipe item
+    pub fn known(&self, inventory: &Inventory) -> bool {
+        inventory.has_item(self.food.item)
+    }
+
+    pub fn can_cook(&self, inventory: &Inventory, pantry: &Pantry) -> bool {
+        self.known(inventory)
+            && self
+                .ingredients
+                .iter()
+                .all(|ingredient| ingredient.available(inventory, pantry))
+    }
+
+    /// Takes what the dish needs returning if any of it had spoiled, None if something is missing
+    pub fn use_ingredients(&self, inventory: &mut Inventory, pantry: &mut Pantry) -> Option<bool> {
+        if !self.can_cook(inventory, pantry) {
+            return None;
+        }
+        let mut spoiled = false;
+        for ingredient in self.ingredients {
+            spoiled |= ingredient.use_up(inventory, pantry);
+        }
+        Some(spoiled)
+    }
+}
+
+/// What has come out of the kitchen and who is still enjoying their last dish
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub struct Kitchen {
+    cooked: [u16; FOOD_COUNT],
+    treats: HouseholdTimers,
+}
+
+impl Kitchen {
+    pub(crate) fn new(cooked: [u16; FOOD_COUNT], treats: HouseholdTimers) -> Self {
+        Self { cooked, treats }
+    }
+
+    pub fn record_cooked(&mut self, food: &Food) {
+        self.cooked[food.id] = self.cooked[food.id].saturating_add(1);
+    }
+
+    pub fn cooked_count(&self, food: &Food) -> u16 {
+        self.cooked[food.id]
+    }
+
+    pub fn total_cooked(&self) -> u32 {
+        self.cooked.iter().map(|count| *count as u32).sum()
+    }
+
+    /// A home cooked meal keeps the pet happy for `COOKED_TREAT_DURATION`
+    pub fn serve(&mut self, upid: UniquePetId, now: Timestamp) {
+        self.treats.start(upid, now + COOKED_TREAT_DURATION);
+    }
+
+    pub fn treating(&self, upid: UniquePetId, now: Timestamp) -> bool {
+        self.treats.running(upid, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::food::FOODS;
+
+    #[test]
+    fn test_cook_uses_ingredients() {
+        let recipe = RECIPES
+            .iter()
+            .find(|recipe| {
+                recipe
+                    .ingredients
+                    .iter()
+                    .any(|ingredient| matches!(ingredient, Ingredient::Item(_)))
+            })
+            .unwrap();
+
+        let mut inventory = Inventory::default();
+        let mut pantry = Pantry::stocked(&inventory, 0);
+        for ingredient in recipe.ingredients {
+            match ingredient {
+                Ingredient::Food(food) => assert!(pantry.add(food)),
+                Ingredient::Item(item) => {
+                    inventory.add_item(*item, 1);
+                }
+            }
+        }
+        assert!(!recipe.can_cook(&inventory, &pantry));
+
+        inventory.add_item(recipe.food.item, 1);
+        assert_eq!(
+            recipe.use_ingredients(&mut inventory, &mut pantry),
+            Some(false)
+        );
+        assert!(!recipe.can_cook(&inventory, &pantry));
+        assert_eq!(recipe.use_ingredients(&mut inventory, &mut pantry), None);
+        assert!(!pantry.has_any());
+        assert!(inventory.has_item(recipe.food.item));
+    }
+
+    #[test]
+    fn test_treat_wears_off() {
+        let mut kitchen = Kitchen::default();
+        let now = Timestamp::default();
+        assert!(!kitchen.treating(1, now));
+
+        kitchen.serve(1, now);
+        kitchen.record_cooked(FOODS[0]);
+        assert!(kitchen.treating(1, now));
+        assert!(!kitchen.treating(2, now));
+        assert!(!kitchen.treating(1, now + COOKED_TREAT_DURATION));
+
+        // Each pet in the household keeps their own treat
+        let later = now + COOKED_TREAT_DURATION / 2;
+        kitchen.serve(2, later);
+        assert!(kitchen.treating(1, later));
+        assert!(kitchen.treating(2, now + COOKED_TREAT_DURATION));
+        assert_eq!(kitchen.cooked_count(FOODS[0]), 1);
+        assert_eq!(kitchen.total_cooked(), 1);
+    }
+}
@@ -1,3 +1,5 @@
+use core::time::Duration;
+
 use bincode::{Decode, Encode};
 use chrono::{Datelike, NaiveDate};
 use fixedstr::str12;
@@ -7,11 +9,12 @@ use crate::{
     assets,
     display::{CENTER_X_I32, ComplexRender, ComplexRenderOption},
     fonts,
+    game_consts::SIM_LENGTH_STEP,
     pet::definition::{PetDefinition, PetDefinitionId},
 };
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
 pub enum DeathCause {
     LightingStrike,
     Starvation,
@@ -23,6 +26,18 @@ pub enum DeathCause {
 }
 
 impl DeathCause {
+    pub const COUNT: usize = core::mem::variant_count::<Self>();
+
+    pub const ALL: [DeathCause; Self::COUNT] = [
+        DeathCause::LightingStrike,
+        DeathCause::Starvation,
+        DeathCause::OldAge,
+        DeathCause::ToxicShock,
+        DeathCause::Leaving,
+        DeathCause::Illness,
+        DeathCause::Hypothermia,
+    ];
+
     pub const fn name(&self) -> &'static str {
         match self {
             DeathCause::LightingStrike => "Lighting",
@@ -208,6 +223,29 @@ where
     values[values.len() - 1].odds
 }
 
+/// Odds tuned per `SIM_LENGTH_STEP` scaled to the odds of passing at least once over `delta`
+pub fn sim_odds(odds: f32, delta: Duration) -> f32 {
+    if delta == SIM_LENGTH_STEP {
+        return odds;
+    }
+
+    let steps = delta.as_secs_f32() / SIM_LENGTH_STEP.as_secs_f32();
+    -libm::expm1f(steps * libm::log1pf(-odds))
+}
+
+pub fn passed_sim_threshold_chance<T>(
+    rng: &mut fastrand::Rng,
+    values: &[Threshold<T>],
+    current: T,
+    delta: Duration,
+) -> bool
+where
+    T: Ord,
+{
+    let odds = sim_odds(get_threshold_odds(values, current), delta);
+    rng.f32() < odds
+}
+
 pub fn passed_threshold_chance<T>(
     rng: &mut fastrand::Rng,
     values: &[Threshold<T>],
@@ -219,3 +257,19 @@ where
     let odds = get_threshold_odds(values, current);
     rng.f32() < odds
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sim_odds() {
+        let odds = crate::game_consts::sim_tick_odds_per_hour(0.5);
+        assert_eq!(sim_odds(odds, SIM_LENGTH_STEP), odds);
+
+        let per_hour = sim_odds(odds, Duration::from_hours(1));
+        let expected = 1. - libm::powf(1. - odds, 36_000.);
+        assert!((per_hour - expected).abs() < 0.001);
+        assert!((per_hour - 0.39).abs() < 0.01);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{death::DeathCause, money::Money, pet::definition::PetDefinitionId};
+
+pub const MAX_EVENTS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameEvent {
+    Pooped,
+    GotSick,
+    Recovered,
+    FellAsleep,
+    WokeUp,
+    Died(DeathCause),
+    Evolved {
+        from: PetDefinitionId,
+        to: PetDefinitionId,
+    },
+    EggLaid,
+    EggHatched,
+    SuiterArrived,
+    SuiterLeft,
+    ExploreFinished {
+        location_id: usize,
+        completed: bool,
+        earnings: Money,
+    },
+    AlarmStarted,
+    AlarmStopped,
+    LeftLightsOn,
+    LinkConnected,
+    LinkLost,
+    AchievementUnlocked(usize),
+}
+
+/// Events waiting to be pulled by the frontend, the oldest are dropped once full
+#[derive(Default)]
+pub struct GameEvents {
+    queue: heapless::Deque<GameEvent, MAX_EVENTS>,
+    pushed: u32,
+}
+
+impl GameEvents {
+    pub fn push(&mut self, event: GameEvent) {
+        if self.queue.is_full() {
+            self.queue.pop_front();
+        }
+        let _ = self.queue.push_back(event);
+        self.pushed = self.pushed.wrapping_add(1);
+    }
+
+    /// Count of every event ever pushed, pulled or not
+    pub fn pushed(&self) -> u32 {
+        self.pushed
+    }
+
+    /// The last `count` events pushed that are still queued
+    pub fn latest(&self, count: usize) -> impl Iterator<Item = &GameEvent> {
+        self.queue
+            .iter()
+            .skip(self.queue.len().saturating_sub(count))
+    }
+
+    pub fn pull(&mut self) -> Option<GameEvent> {
+        self.queue.pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drops_oldest_when_full() {
+        let mut events = GameEvents::default();
+        events.push(GameEvent::Pooped);
+        for _ in 0..MAX_EVENTS {
+            events.push(GameEvent::GotSick);
+        }
+
+        assert_eq!(events.len(), MAX_EVENTS);
+        assert_eq!(events.pull(), Some(GameEvent::GotSick));
+    }
+}
@@ -3,13 +3,18 @@ use core::{ops::Range, time::Duration};
 use crate::{
     Timestamp,
     assets::{self, StaticImage},
-    game_consts::EXPLORE_MONEY_RESET_TIME,
+    food::{FOODS, Food},
+    game_consts::{
+        EXPLORE_FATIGUE, EXPLORE_INJURY_DURATION, EXPLORE_MONEY_RESET_TIME,
+        EXPLORE_PACKED_FOOD_SKILL,
+    },
     items::{Inventory, ItemKind},
-    money::Money,
-    pet::{LifeStage, PetInstance},
+    money::{Money, MoneyCategory, Wallet},
+    pet::{LifeStage, PetInstance, UniquePetId},
 };
 use bincode::{Decode, Encode};
 use sdop_common::LifeStageMask;
+pub use sdop_common::MAX_EXPLORE_STAGES;
 
 include!(concat!(env!("OUT_DIR"), "/dist_locations.rs"));
 
@@ -41,6 +46,120 @@ impl LocationRewards {
     }
 }
 
+/// Gear that helps at a location when packed
+pub struct PackBonus {
+    pub item: ItemKind,
+    pub skill: ExploreSkill,
+    // Only has a chance of turning up with this packed
+    reward: Option<ItemReward>,
+    // Chance it doesn't make it home
+    pub lose_odds: f32,
+}
+
+impl PackBonus {
+    pub const fn new(
+        item: ItemKind,
+        skill: ExploreSkill,
+        reward: Option<ItemReward>,
+        lose_odds: f32,
+    ) -> Self {
+        Self {
+            item,
+            skill,
+            reward,
+            lose_odds,
+        }
+    }
+}
+
+/// What was packed for the trip, the food is eaten on the way and the gear comes home unless lost
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub struct Loadout {
+    food: Option<usize>,
+    item: Option<ItemKind>,
+}
+
+impl Loadout {
+    pub fn new(food: Option<&Food>, item: Option<ItemKind>) -> Self {
+        Self {
+            food: food.map(|food| food.id),
+            item,
+        }
+    }
+
+    pub fn food(&self) -> Option<&'static Food> {
+        FOODS.get(self.food?).copied()
+    }
+
+    pub fn item(&self) -> Option<ItemKind> {
+        self.item
+    }
+
+    pub fn skill(&self, location: &Location) -> ExploreSkill {
+        let food = if self.food.is_some() {
+            EXPLORE_PACKED_FOOD_SKILL
+        } else {
+            0
+        };
+        let item = self
+            .item
+            .and_then(|item| location.pack_bonus(item))
+            .map_or(0, |bonus| bonus.skill);
+        food + item
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum ExploreOutcome {
+    Nothing,
+    // Scales the money and item odds when the expedition ends
+    Rewards(f32),
+    Injure,
+    Sicken,
+    // Hands over the map to somewhere off the beaten track
+    Unlock(&'static Location),
+}
+
+pub struct EventChoice {
+    pub text: &'static str,
+    pub outcome: ExploreOutcome,
+}
+
+impl EventChoice {
+    pub const fn new(text: &'static str, outcome: ExploreOutcome) -> Self {
+        Self { text, outcome }
+    }
+}
+
+pub struct ExploreEvent {
+    pub name: &'static str,
+    pub odds: f32,
+    pub choices: &'static [EventChoice],
+}
+
+impl ExploreEvent {
+    pub const fn new(name: &'static str, odds: f32, choices: &'static [EventChoice]) -> Self {
+        Self {
+            name,
+            odds,
+            choices,
+        }
+    }
+}
+
+/// A leg of an expedition, at most one of its events happens each run
+pub struct ExploreStage {
+    pub name: &'static str,
+    pub events: &'static [ExploreEvent],
+}
+
+impl ExploreStage {
+    pub const fn new(name: &'static str, events: &'static [ExploreEvent]) -> Self {
+        Self { name, events }
+    }
+}
+
 pub struct Location {
     pub id: usize,
     pub name: &'static str,
@@ -52,6 +171,10 @@ pub struct Location {
     pub activities: &'static [&'static str],
     pub item: ItemKind,
     pub ls_mask: LifeStageMask,
+    pub stages: &'static [ExploreStage],
+    // Successful runs that count each day
+    pub daily_wins: u8,
+    pub packing: &'static [PackBonus],
 }
 
 impl Location {
@@ -66,6 +189,7 @@ impl Location {
         activities: &'static [&'static str],
         item: ItemKind,
         life_stages: &'static [LifeStage],
+        stages: &'static [ExploreStage],
     ) -> Self {
         Self {
             id,
@@ -78,9 +202,26 @@ impl Location {
             activities,
             item,
             ls_mask: LifeStage::create_bitmask(life_stages),
+            stages,
+            daily_wins: 1,
+            packing: &[],
         }
     }
 
+    pub const fn with_daily_wins(mut self, daily_wins: u8) -> Self {
+        self.daily_wins = daily_wins;
+        self
+    }
+
+    pub const fn with_packing(mut self, packing: &'static [PackBonus]) -> Self {
+        self.packing = packing;
+        self
+    }
+
+    pub fn pack_bonus(&self, item: ItemKind) -> Option<&'static PackBonus> {
+        self.packing.iter().find(|bonus| bonus.item == item)
+    }
+
     pub const fn total_checks(&self) -> u32 {
         CHECKS_PER_LOCATION
     }
@@ -88,6 +229,24 @@ impl Location {
     pub const fn check_interval(&self) -> Duration {
         Duration::from_millis((self.length.as_millis() as u32 / CHECKS_PER_LOCATION) as u64)
     }
+
+    /// Locations without stages are walked as a single one
+    pub const fn stage_count(&self) -> usize {
+        if self.stages.is_empty() {
+            1
+        } else {
+            self.stages.len()
+        }
+    }
+
+    pub fn stage(&self, index: usize) -> Option<&'static ExploreStage> {
+        self.stages.get(index)
+    }
+
+    pub fn stage_at(&self, elapsed: Duration) -> usize {
+        let stage_length = self.length.as_millis() / self.stage_count() as u128;
+        ((elapsed.as_millis() / stage_length.max(1)) as usize).min(self.stage_count() - 1)
+    }
 }
 
 pub const LOCATION_UNKNOWN: Location = Location::new(
@@ -101,6 +260,7 @@ pub const LOCATION_UNKNOWN: Location = Location::new(
     &[],
     ItemKind::None,
     &[],
+    &[],
 );
 
 pub const fn get_location(id: usize) -> &'static Location {
@@ -217,6 +377,48 @@ impl ExploreHistory {
     pub fn get_by_id(&self, id: usize) -> &LocationHistory {
         &self.location_history[id]
     }
+
+    /// The location explored most recently
+    pub fn last_location(&self) -> usize {
+        self.location_history
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, history)| history.last_ran)
+            .map(|(i, _)| i)
+            .unwrap_or_default()
+    }
+
+    /// When the pet is rested enough to explore anywhere again
+    pub fn rested_at(&self) -> Timestamp {
+        self.get_by_id(self.last_location()).last_ran + EXPLORE_FATIGUE
+    }
+
+    pub fn ready_at(&self, location: &Location) -> Timestamp {
+        self.get_by_id(location.id).last_ran + location.cooldown
+    }
+}
+
+/// Successful runs at a location on the day of the latest one
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub struct DailyWins {
+    day: Timestamp,
+    count: u8,
+}
+
+impl DailyWins {
+    fn today(&self, now: Timestamp) -> u8 {
+        if self.day.inner().date() == now.inner().date() {
+            self.count
+        } else {
+            0
+        }
+    }
+
+    fn record(&mut self, now: Timestamp) {
+        self.count = self.today(now).saturating_add(1);
+        self.day = now;
+    }
 }
 
 pub struct ExploreDetailedResult {
@@ -224,6 +426,10 @@ pub struct ExploreDetailedResult {
     pub passed: u32,
     pub earnings: Money,
     pub items: heapless::Vec<ItemKind, MAX_REWARD_ITEMS_LOCATION>,
+    pub events: heapless::Vec<(&'static ExploreEvent, &'static EventChoice), MAX_EXPLORE_STAGES>,
+    pub loadout: Loadout,
+    // Packed gear that didn't come home
+    pub lost: Option<ItemKind>,
 }
 
 impl Default for ExploreDetailedResult {
@@ -239,6 +445,9 @@ impl ExploreDetailedResult {
             passed,
             earnings: Default::default(),
             items: Default::default(),
+            events: Default::default(),
+            loadout: Default::default(),
+            lost: None,
         }
     }
 
@@ -254,6 +463,15 @@ impl ExploreDetailedResult {
 
 const PLACEHOLDER_ACTIVTY: &'static str = "????";
 
+/// The event that came up on a stage and what was done about it
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone)]
+pub struct StageEvent {
+    event: u8,
+    // None until the owner answers or the stage ends
+    choice: Option<u8>,
+}
+
 pub struct ExploreSystem {
     current: Option<&'static Location>,
     current_activity: &'static str,
@@ -261,6 +479,12 @@ pub struct ExploreSystem {
     passes: u32,
     until_check: Duration,
     last_result: ExploreDetailedResult,
+    // Last stage whose event has been rolled
+    stage: Option<usize>,
+    events: [Option<StageEvent>; MAX_EXPLORE_STAGES],
+    injured: Option<(UniquePetId, Timestamp)>,
+    wins: [DailyWins; LOCATION_COUNT],
+    loadout: Loadout,
 }
 
 impl Default for ExploreSystem {
@@ -272,6 +496,11 @@ impl Default for ExploreSystem {
             passes: 0,
             until_check: Duration::ZERO,
             last_result: Default::default(),
+            stage: None,
+            events: Default::default(),
+            injured: None,
+            wins: Default::default(),
+            loadout: Default::default(),
         }
     }
 }
@@ -284,13 +513,22 @@ impl ExploreSystem {
         rng: &mut fastrand::Rng,
         pet: &mut PetInstance,
         inventory: &mut Inventory,
-        wallet: &mut Money,
-    ) {
+        wallet: &mut Wallet,
+    ) -> bool {
         let current = match self.current {
             Some(current) => current,
-            None => return,
+            None => return false,
         };
 
+        // Gear gone from the inventory since setting off is no help
+        if self
+            .loadout
+            .item
+            .is_some_and(|item| !inventory.has_item(item))
+        {
+            self.loadout.item = None;
+        }
+
         if self.current_activity == PLACEHOLDER_ACTIVTY {
             self.current_activity = rng
                 .choice(self.current_location().activities)
@@ -298,8 +536,32 @@ impl ExploreSystem {
         }
 
         self.elapsed += delta;
+        self.advance_stages(
+            current,
+            current.stage_at(self.elapsed.min(current.length)),
+            rng,
+            pet,
+            inventory,
+            *now,
+        );
+
         if self.elapsed > current.length {
+            if let Some(stage) = self.stage {
+                self.settle(stage, rng, pet, inventory, *now);
+            }
+
             let mut result = ExploreDetailedResult::new(current, self.passes);
+            result.loadout = self.loadout;
+            let bonus = self.loadout.item.and_then(|item| current.pack_bonus(item));
+            let mut multiplier = 1.;
+            for stage in 0..current.stage_count() {
+                if let Some((event, choice)) = self.stage_choice(stage) {
+                    if let ExploreOutcome::Rewards(scale) = choice.outcome {
+                        multiplier *= scale;
+                    }
+                    let _ = result.events.push((event, choice));
+                }
+            }
             // Update money run
             {
                 let history = pet.explore.get_mut_by_id(current.id);
@@ -312,8 +574,13 @@ impl ExploreSystem {
             if result.completed() {
                 let percent_passed = result.percent_passed();
 
-                for reward in current.rewards.items {
-                    if rng.f32() < reward.odds * percent_passed {
+                for reward in current
+                    .rewards
+                    .items
+                    .iter()
+                    .chain(bonus.and_then(|bonus| bonus.reward.as_ref()))
+                {
+                    if rng.f32() < reward.odds * percent_passed * multiplier {
                         if inventory.add_item(reward.item, 1) {
                             let _ = result.items.push(reward.item);
                         }
@@ -323,7 +590,8 @@ impl ExploreSystem {
                 result.earnings = {
                     let raw = (rng.i32(current.rewards.money.start..current.rewards.money.end)
                         as f32
-                        * percent_passed) as Money;
+                        * percent_passed
+                        * multiplier) as Money;
 
                     let history = pet.explore.get_by_id(current.id);
 
@@ -332,7 +600,7 @@ impl ExploreSystem {
 
                     raw.min(max_earn)
                 };
-                *wallet += result.earnings;
+                result.earnings = wallet.earn(MoneyCategory::Explore, result.earnings, *now);
             }
 
             {
@@ -342,19 +610,37 @@ impl ExploreSystem {
                 history.successful += if result.completed() { 1 } else { 0 };
                 history.running_money_earned += result.earnings;
             }
+            if result.completed() {
+                self.wins[current.id].record(*now);
+            }
+            let lost =
+                bonus.filter(|bonus| rng.f32() < bonus.lose_odds && inventory.has_item(bonus.item));
+            if let Some(bonus) = lost {
+                inventory.add_item(bonus.item, -1);
+                result.lost = Some(bonus.item);
+            }
 
             self.last_result = result;
             self.passes = 0;
             self.until_check = Duration::ZERO;
             self.elapsed = Duration::ZERO;
             self.current = None;
+            self.stage = None;
+            self.events = Default::default();
+            self.loadout = Loadout::default();
+
+            true
         } else {
             self.until_check += delta;
 
-            if self.until_check > current.check_interval() {
+            // Catch up can cover more than one check
+            while self.until_check >= current.check_interval() {
                 // Get left overs
                 self.until_check = self.until_check - current.check_interval();
-                let skill = pet.explore_skill();
+                let mut skill = pet.explore_skill() + self.loadout.skill(current);
+                if self.injured(pet.upid, *now) {
+                    skill /= 2;
+                }
                 let odds = rng.i32((skill / 4)..=skill);
                 let location_odds = rng.i32(0..current.difficulty);
                 if odds > location_odds {
@@ -370,9 +656,156 @@ impl ExploreSystem {
                     .choice(self.current_location().activities)
                     .unwrap_or(&PLACEHOLDER_ACTIVTY);
             }
+
+            false
+        }
+    }
+
+    // Settles the stages left behind and rolls an event for each one entered up to `target`
+    fn advance_stages(
+        &mut self,
+        location: &'static Location,
+        target: usize,
+        rng: &mut fastrand::Rng,
+        pet: &mut PetInstance,
+        inventory: &mut Inventory,
+        now: Timestamp,
+    ) {
+        while self.stage.is_none_or(|stage| stage < target) {
+            if let Some(stage) = self.stage {
+                self.settle(stage, rng, pet, inventory, now);
+            }
+            let next = self.stage.map_or(0, |stage| stage + 1);
+            self.events[next] = location
+                .stage(next)
+                .and_then(|stage| stage.events.iter().position(|event| rng.f32() < event.odds))
+                .map(|event| StageEvent {
+                    event: event as u8,
+                    choice: None,
+                });
+            self.stage = Some(next);
+        }
+    }
+
+    // Left unanswered the pet makes up its own mind
+    fn settle(
+        &mut self,
+        stage: usize,
+        rng: &mut fastrand::Rng,
+        pet: &mut PetInstance,
+        inventory: &mut Inventory,
+        now: Timestamp,
+    ) {
+        if let Some(event) = self.stage_event(stage) {
+            let choice = rng.usize(0..event.choices.len());
+            self.resolve(stage, choice, rng, pet, inventory, now);
+        }
+    }
+
+    fn resolve(
+        &mut self,
+        stage: usize,
+        choice: usize,
+        rng: &mut fastrand::Rng,
+        pet: &mut PetInstance,
+        inventory: &mut Inventory,
+        now: Timestamp,
+    ) {
+        let Some(entry) = self.events[stage].as_mut() else {
+            return;
+        };
+        entry.choice = Some(choice as u8);
+
+        let Some((_, choice)) = self.stage_choice(stage) else {
+            return;
+        };
+        match choice.outcome {
+            ExploreOutcome::Nothing | ExploreOutcome::Rewards(_) => {}
+            ExploreOutcome::Injure => {
+                self.injured = Some((pet.upid, now + EXPLORE_INJURY_DURATION));
+            }
+            ExploreOutcome::Sicken => {
+                if !pet.is_ill() {
+                    pet.fall_ill(rng, Duration::from_secs(1));
+                }
+            }
+            ExploreOutcome::Unlock(location) => {
+                if !inventory.has_item(location.item) {
+                    inventory.add_item(location.item, 1);
+                }
+            }
+        }
+    }
+
+    // The event on `stage` if it is still waiting on an answer
+    fn stage_event(&self, stage: usize) -> Option<&'static ExploreEvent> {
+        let entry = self.events.get(stage).copied().flatten()?;
+        if entry.choice.is_some() {
+            return None;
+        }
+        self.current?.stage(stage)?.events.get(entry.event as usize)
+    }
+
+    fn stage_choice(&self, stage: usize) -> Option<(&'static ExploreEvent, &'static EventChoice)> {
+        let entry = self.events.get(stage).copied().flatten()?;
+        let event = self
+            .current?
+            .stage(stage)?
+            .events
+            .get(entry.event as usize)?;
+        Some((event, event.choices.get(entry.choice? as usize)?))
+    }
+
+    pub fn current_stage(&self) -> Option<&'static ExploreStage> {
+        self.current?.stage(self.stage?)
+    }
+
+    pub fn stage_index(&self) -> usize {
+        self.stage.unwrap_or_default()
+    }
+
+    /// The event on the current stage waiting on the owner
+    pub fn pending_event(&self) -> Option<&'static ExploreEvent> {
+        self.stage_event(self.stage?)
+    }
+
+    /// The owner's answer to the pending event, false if there is nothing to answer
+    pub fn answer(
+        &mut self,
+        choice: usize,
+        rng: &mut fastrand::Rng,
+        pet: &mut PetInstance,
+        inventory: &mut Inventory,
+        now: Timestamp,
+    ) -> bool {
+        let Some(stage) = self.stage else {
+            return false;
+        };
+        match self.stage_event(stage) {
+            Some(event) if choice < event.choices.len() => {
+                self.resolve(stage, choice, rng, pet, inventory, now);
+                true
+            }
+            _ => false,
         }
     }
 
+    /// What was chosen on the current stage
+    pub fn answered(&self) -> Option<&'static EventChoice> {
+        self.stage_choice(self.stage?).map(|(_, choice)| choice)
+    }
+
+    pub fn injured(&self, upid: UniquePetId, now: Timestamp) -> bool {
+        matches!(self.injured, Some((injured, until)) if injured == upid && now < until)
+    }
+
+    /// Successful runs still to be had at `location` today
+    pub fn wins_left(&self, location: &Location, now: Timestamp) -> u8 {
+        location
+            .daily_wins
+            .saturating_sub(self.wins[location.id].today(now))
+    }
+
     pub fn current_percent_passed(&self) -> f32 {
         let check_interval = self.current.unwrap_or(&LOCATION_UNKNOWN).check_interval();
         if self.elapsed < check_interval {
@@ -383,8 +816,15 @@ impl ExploreSystem {
         self.passes as f32 / total_odds
     }
 
-    pub fn start_exploring(&mut self, location_id: usize) {
+    pub fn start_exploring(&mut self, location_id: usize, loadout: Loadout) {
         self.current = Some(get_location(location_id));
+        self.stage = None;
+        self.events = Default::default();
+        self.loadout = loadout;
+    }
+
+    pub fn loadout(&self) -> &Loadout {
+        &self.loadout
     }
 
     pub fn currently_exploring(&self) -> bool {
@@ -428,6 +868,11 @@ impl ExploreSystem {
             current: self.current.map(|i| i.id),
             elapsed: self.elapsed,
             passes: self.passes,
+            stage: self.stage.map(|stage| stage as u8),
+            events: self.events,
+            injured: self.injured,
+            wins: self.wins,
+            loadout: self.loadout,
         }
     }
 }
@@ -438,16 +883,58 @@ pub struct ExploreSystemSave {
     current: Option<usize>,
     elapsed: Duration,
     passes: u32,
+    stage: Option<u8>,
+    events: [Option<StageEvent>; MAX_EXPLORE_STAGES],
+    injured: Option<(UniquePetId, Timestamp)>,
+    wins: [DailyWins; LOCATION_COUNT],
+    loadout: Loadout,
 }
 
 impl Default for ExploreSystemSave {
     fn default() -> Self {
+        Self::new(Default::default(), Default::default(), Default::default())
+    }
+}
+
+impl ExploreSystemSave {
+    /// An expedition picked up part way with none of its stages rolled
+    pub fn new(current: Option<usize>, elapsed: Duration, passes: u32) -> Self {
         Self {
-            current: Default::default(),
-            elapsed: Default::default(),
-            passes: Default::default(),
+            current,
+            elapsed,
+            passes,
+            stage: None,
+            events: Default::default(),
+            injured: None,
+            wins: Default::default(),
+            loadout: Default::default(),
         }
     }
+
+    pub fn with_stages(
+        mut self,
+        stage: Option<u8>,
+        events: [Option<StageEvent>; MAX_EXPLORE_STAGES],
+    ) -> Self {
+        self.stage = stage;
+        self.events = events;
+        self
+    }
+
+    pub fn with_injured(mut self, injured: Option<(UniquePetId, Timestamp)>) -> Self {
+        self.injured = injured;
+        self
+    }
+
+    pub fn with_wins(mut self, wins: [DailyWins; LOCATION_COUNT]) -> Self {
+        self.wins = wins;
+        self
+    }
+
+    pub fn with_loadout(mut self, loadout: Loadout) -> Self {
+        self.loadout = loadout;
+        self
+    }
 }
 
 impl From<ExploreSystemSave> for ExploreSystem {
@@ -462,6 +949,11 @@ impl From<ExploreSystemSave> for ExploreSystem {
             passes: value.passes,
             until_check: Duration::ZERO,
             last_result: Default::default(),
+            stage: value.stage.map(|stage| stage as usize),
+            events: value.events,
+            injured: value.injured,
+            wins: value.wins,
+            loadout: value.loadout,
         }
     }
 }
@@ -5,7 +5,9 @@ use core::{i32, time::Duration};
 use crate::{
     Timestamp,
     assets::{self, StaticImage},
+    game_consts::{FOOD_STOCK_BASE_COST, FOOD_STOCK_COST_PER_FILL},
     items::ItemKind,
+    money::Money,
 };
 
 include!(concat!(env!("OUT_DIR"), "/dist_foods.rs"));
@@ -52,6 +54,11 @@ impl Food {
     pub fn get_eat_expire(&self) -> Duration {
         self.expire
     }
+
+    /// Price of a single unit of stock at the shop
+    pub fn stock_cost(&self) -> Money {
+        FOOD_STOCK_BASE_COST + (self.fill_factor * FOOD_STOCK_COST_PER_FILL) as Money
+    }
 }
 
 impl Eq for Food {}
@@ -41,6 +41,8 @@ pub enum HomeFurnitureKind {
     PaintingPc,
     PaintingSun,
     PaintingMallsBalls,
+    Stove,
+    Fridge,
 }
 
 impl HomeFurnitureKind {
@@ -63,6 +65,8 @@ impl HomeFurnitureKind {
             Self::PaintingPc => assets::IMAGE_PAINTING_PC.isize,
             Self::PaintingSun => assets::IMAGE_PAINTING_SUN.isize,
             Self::PaintingMallsBalls => assets::IMAGE_PAINTING_MALLS_BALLS.isize,
+            Self::Stove => assets::IMAGE_STOVE.isize,
+            Self::Fridge => assets::IMAGE_FRIDGE.isize,
         }
     }
 
@@ -229,6 +233,12 @@ impl HomeFurnitureRender {
                 pos,
                 &assets::IMAGE_PAINTING_MALLS_BALLS,
             )),
+            Self::Kind::Stove => {
+                HomeFurnitureRender::Sprite(BasicSprite::new(pos, &assets::IMAGE_STOVE))
+            }
+            Self::Kind::Fridge => {
+                HomeFurnitureRender::Sprite(BasicSprite::new(pos, &assets::IMAGE_FRIDGE))
+            }
         }
     }
 
@@ -261,10 +271,10 @@ impl HomeFurnitureRender {
                 render.set_rining(args.game_ctx.alarm.should_be_rining());
             }
             Self::ThermometerMercury(render) => {
-                render.temperature = args.input.temperature();
+                render.temperature = args.game_ctx.room_temperature.get();
             }
             Self::ThermometerDigital(render) => {
-                render.temperature = args.input.temperature();
+                render.temperature = args.game_ctx.room_temperature.get();
             }
             Self::FishTank(fishtank_render) => {
                 while fishtank_render.fish_count() < args.game_ctx.home_fish_tank.count() {
@@ -2,17 +2,31 @@ use core::{ops::Range, time::Duration};
 
 use chrono::NaiveTime;
 
-use crate::{death::Threshold, items::ItemKind, money::Money};
+use crate::{death::Threshold, explore::ExploreSkill, items::ItemKind, money::Money};
 
 pub const UI_FLASH_TIMER: Duration = Duration::from_millis(250);
 pub const UI_FLASHING_TIMER: Duration = Duration::from_millis(500);
 
 pub const ROOM_TEMPTURE: f32 = 25.;
+// How much of the gap to the outdoor temperature the room ends up following
+pub const ROOM_OUTDOOR_INFLUENCE: f32 = 0.7;
+pub const ROOM_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_hours(1);
+pub const SPACE_HEATER_TEMPERATURE: f32 = 20.;
+pub const AIR_CON_TEMPERATURE: f32 = 24.;
+pub const OUTDOOR_MEAN_TEMPERATURE: f32 = 12.;
+pub const OUTDOOR_SEASONAL_SWING: f32 = 12.;
+pub const OUTDOOR_DAILY_SWING: f32 = 6.;
 
 pub const LOW_POWER_THRESHOLD: Duration = Duration::from_mins(1);
 
 pub const SIM_LENGTH_STEP: Duration = Duration::from_millis(100);
 
+// Divides the death and evolve check intervals so checks land at the same times
+pub const CATCH_UP_STEP: Duration = Duration::from_mins(1);
+
+// Keeps catching up on boot to about ten thousand steps
+pub const DEFAULT_OFFLINE_CAP: Duration = Duration::from_days(7);
+
 pub const DEATH_CHECK_INTERVERAL: Duration = Duration::from_mins(5);
 
 pub const EVOLVE_CHECK_INTERVERAL: Duration = Duration::from_mins(1);
@@ -21,6 +35,26 @@ pub const ALIEN_ODDS: f32 = 0.05;
 
 pub const STARTING_FILLED: f32 = 10.;
 
+pub const ADOPT_COST: Money = 500;
+
+// Share of the shop price paid when selling an item back
+pub const SELL_BACK_COMMON: f32 = 0.3;
+pub const SELL_BACK_RARE: f32 = 0.5;
+// How far the daily second hand price can move either way
+pub const SELL_BACK_DAILY_SWING: f32 = 0.2;
+
+// Home cooked dishes fill more than the shop's and cheer the pet up for a while
+pub const COOKED_FILL_MULTIPLIER: f32 = 1.5;
+pub const COOKED_TREAT_DURATION: Duration = Duration::from_hours(3);
+
+// A unit of food stock costs the base plus this much per point of fill
+pub const FOOD_STOCK_BASE_COST: Money = 5;
+pub const FOOD_STOCK_COST_PER_FILL: f32 = 2.;
+// Stock kept out of a fridge this long has gone off
+pub const FOOD_SHELF_LIFE: Duration = Duration::from_days(2);
+// Of each food on the starting menu
+pub const STARTING_FOOD_STOCK: usize = 3;
+
 const HOUR: Duration = Duration::from_hours(1);
 const DAY: Duration = Duration::from_days(1);
 
@@ -113,6 +147,11 @@ pub const SUITER_SHOW_UP_ODDS_THRESHOLD: &[Threshold<Duration>] = &[
 
 pub const SUITER_LEAVE_ODDS: f32 = sim_tick_odds_per_hour(0.25);
 
+pub const LINK_HELLO_INTERVAL: Duration = Duration::from_secs(1);
+pub const LINK_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub const VITALS_SAMPLE_INTERVAL: Duration = Duration::from_mins(15);
+
 pub const EGG_HATCH_MAX: Duration = Duration::from_days(1);
 
 pub const ILLNESS_SINCE_GAME_DURATION: Duration = Duration::from_hours(6);
@@ -121,6 +160,20 @@ pub const ILLNESS_BASE_ODDS: f32 = sim_tick_odds_per_day(0.05);
 pub const ILLNESS_STARVING_ODDS: f32 = sim_tick_odds_per_day(0.1);
 pub const ILLNESS_BABY_ODDS: f32 = sim_tick_odds_per_day(0.2);
 pub const ILLNESS_CHILD_ODDS: f32 = sim_tick_odds_per_day(0.1);
+// Per ill housemate
+pub const ILLNESS_CONTAGION_ODDS: f32 = sim_tick_odds_per_hour(0.05);
+// For `SPOILED_FOOD_UPSET` after eating spoiled food
+pub const ILLNESS_SPOILED_FOOD_ODDS: f32 = sim_tick_odds_per_hour(0.1);
+pub const SPOILED_FOOD_UPSET: Duration = Duration::from_hours(12);
+
+// How far either way a gene can push a stat from the species' value
+pub const GENOME_STOMACH_SWING: f32 = 0.2;
+pub const GENOME_WEIGHT_SWING: f32 = 0.2;
+pub const GENOME_RESISTANCE_SWING: f32 = 0.3;
+pub const GENOME_EXPLORE_SWING: f32 = 4.;
+pub const GENOME_MUTATION_ODDS: f32 = 0.1;
+// Weight multiplier for the evolution a pet's genes lean towards
+pub const GENOME_EVOLUTION_BIAS: usize = 2;
 
 pub const ILLNESS_SINCE_ODDS: &[Threshold<Duration>] = &[
     Threshold::new(Duration::from_hours(4), sim_tick_odds_per_day(0.0)),
@@ -135,6 +188,10 @@ pub const ILLNESS_AUTO_HEAL_ODDS_ADULT: f32 = sim_tick_odds_per_hour(0.1);
 
 pub const HEALING_COST_RANGE: Range<Money> = 100..1000;
 
+pub const COFFEE_SLEEP_DELAY_HOURS: i32 = 1;
+// Sleeping this long with the lights on is a care mistake
+pub const LIGHTS_ON_CARE_MISTAKE: Duration = Duration::from_mins(30);
+
 pub const RANDOM_NAMES: &[&str] = &[
     "Abel", "Adam", "Amos", "Cain", "Caleb", "Dan", "David", "Eli", "Esau", "Gad", "Hagar",
     "Isaac", "Jacob", "Japhet", "Jonah", "Job", "Joel", "Judah", "Levi", "Lot", "Micah", "Moab",
@@ -251,3 +308,9 @@ pub const CHILD_LIFE_STAGE_ITEMS: &[ItemKind] = &[ItemKind::MapToTheNinch];
 pub const ADULT_LIFE_STAGE_ITEMS: &[ItemKind] = &[ItemKind::MapIntoTheOffice];
 
 pub const EXPLORE_MONEY_RESET_TIME: Duration = Duration::from_days(1);
+// Hurt on an expedition the pet explores at half skill and can't set off again until healed
+pub const EXPLORE_INJURY_DURATION: Duration = Duration::from_hours(6);
+// Rest after any expedition before heading out again, on top of each location's own cooldown
+pub const EXPLORE_FATIGUE: Duration = Duration::from_mins(20);
+// A packed lunch keeps the pet going on any expedition
+pub const EXPLORE_PACKED_FOOD_SKILL: ExploreSkill = 3;
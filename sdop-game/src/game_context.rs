@@ -2,26 +2,36 @@ use core::time::Duration;
 
 use crate::{
     Timestamp,
+    achievements::Achievements,
     alarm::AlarmState,
+    cooking::Kitchen,
     date_utils::SpecialDayUpdater,
     egg::SavedEgg,
-    explore::ExploreSystem,
+    events::GameEvents,
+    explore::{ExploreSystem, Loadout},
     fish_tank::HomeFishTank,
     furniture::HomeLayout,
-    items::Inventory,
-    money::Money,
+    household::Household,
+    input::Input,
+    items::{Inventory, ItemKind},
+    lights::Lights,
+    link::LinkSession,
+    money::Wallet,
+    pantry::Pantry,
     pet::{PetInstance, record::PetHistory},
     poop::{MAX_POOPS, Poop},
     scene::{SharedSceneOutput, home_scene::HomeSceneData},
     shop::Shop,
     sounds::SoundSystem,
     suiter::SuiterSystem,
+    temperature::{OutdoorTemperatureSource, RoomTemperature, outdoor_curve},
+    vitals::VitalsLog,
 };
 
 pub struct GameContext {
     pub pet: PetInstance,
     pub poops: [Option<Poop>; MAX_POOPS],
-    pub money: Money,
+    pub wallet: Wallet,
     pub inventory: Inventory,
     pub home_fish_tank: HomeFishTank,
     pub home_layout: HomeLayout,
@@ -40,6 +50,16 @@ pub struct GameContext {
     pub sim_rng: fastrand::Rng,
     pub alarm: AlarmState,
     pub explore_system: ExploreSystem,
+    pub events: GameEvents,
+    pub lights: Lights,
+    pub room_temperature: RoomTemperature,
+    pub outdoor_temperature_source: OutdoorTemperatureSource,
+    pub household: Household,
+    pub link: LinkSession,
+    pub achievements: Achievements,
+    pub vitals: VitalsLog,
+    pub kitchen: Kitchen,
+    pub pantry: Pantry,
 }
 
 impl GameContext {
@@ -47,7 +67,7 @@ impl GameContext {
         Self {
             pet: PetInstance::default(),
             poops: Default::default(),
-            money: Money::default(),
+            wallet: Wallet::default(),
             inventory: Inventory::default(),
             home_fish_tank: Default::default(),
             home_layout: Default::default(),
@@ -66,10 +86,91 @@ impl GameContext {
             sim_rng: fastrand::Rng::with_seed(0),
             alarm: AlarmState::default(),
             explore_system: ExploreSystem::default(),
+            events: GameEvents::default(),
+            lights: Lights::default(),
+            room_temperature: RoomTemperature::default(),
+            outdoor_temperature_source: outdoor_curve,
+            household: Household::default(),
+            link: LinkSession::default(),
+            achievements: Achievements::default(),
+            vitals: VitalsLog::default(),
+            kitchen: Kitchen::default(),
+            pantry: Pantry::default(),
+        }
+    }
+
+    /// The sensor reading if there is one, otherwise whatever the source says
+    pub fn outdoor_temperature(&self, input: &Input, timestamp: Timestamp) -> f32 {
+        input
+            .temperature()
+            .unwrap_or_else(|| (self.outdoor_temperature_source)(timestamp))
+    }
+
+    /// Swaps the selected pet with another in the household, doing it twice puts them back
+    pub fn swap_selected_pet(&mut self, index: usize) {
+        let other = &mut self.household.others_mut()[index];
+        core::mem::swap(&mut self.pet, &mut other.pet);
+        core::mem::swap(&mut self.explore_system, &mut other.explore);
+        core::mem::swap(&mut self.vitals, &mut other.vitals);
+    }
+
+    /// Selects the next pet in the household, the current one goes to the back of the line
+    pub fn select_next_pet(&mut self) {
+        if let Some(next) = self.household.take_next() {
+            let pet = core::mem::replace(&mut self.pet, next.pet);
+            let explore = core::mem::replace(&mut self.explore_system, next.explore);
+            let vitals = core::mem::replace(&mut self.vitals, next.vitals);
+            self.household.add(pet, explore, vitals);
+        }
+    }
+
+    /// Replaces the selected pet with the next one in the household, false if there is nobody left
+    pub fn promote_next_pet(&mut self) -> bool {
+        match self.household.take_next() {
+            Some(next) => {
+                self.pet = next.pet;
+                self.explore_system = next.explore;
+                self.vitals = next.vitals;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Selects any pet that is dying or evolving so home can show it happening
+    pub fn select_pet_needing_attention(&mut self) {
+        let index = self.household.others().iter().position(|other| {
+            other.pet.should_die().is_some()
+                || (other.pet.should_evolve().is_some() && !other.pet.is_sleeping())
+        });
+        if let Some(index) = index {
+            self.swap_selected_pet(index);
         }
     }
 
     pub fn poop_count(&self) -> usize {
         self.poops.iter().filter(|i| i.is_some()).count()
     }
+
+    /// Copies of `item` away with pets out exploring
+    pub fn packed_count(&self, item: ItemKind) -> u32 {
+        core::iter::once(&self.explore_system)
+            .chain(self.household.others().iter().map(|other| &other.explore))
+            .filter(|explore| explore.loadout().item() == Some(item))
+            .count() as u32
+    }
+
+    /// Copies of `item` still at home to be packed
+    pub fn unpacked_count(&self, item: ItemKind) -> u32 {
+        self.inventory
+            .item_count(item)
+            .saturating_sub(self.packed_count(item))
+    }
+
+    /// Sends the selected pet off leaving behind any gear that is no longer free to take
+    pub fn start_exploring(&mut self, location_id: usize, loadout: Loadout) {
+        let item = loadout.item().filter(|item| self.unpacked_count(*item) > 0);
+        self.explore_system
+            .start_exploring(location_id, Loadout::new(loadout.food(), item));
+    }
 }
@@ -0,0 +1,272 @@
+extern crate std;
+
+use core::time::Duration;
+use std::vec::Vec;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::{
+    AwaySummary, Button, ButtonState, ButtonStates, Game, ROOM_TEMPTURE, SaveFile, Timestamp,
+    game_consts::SIM_LENGTH_STEP,
+    input::Input,
+    scene::{SceneTickArgs, enter_text_scene::ENTERABLE_CHARS},
+    sim::{catch_up_sim, tick_sim},
+};
+
+pub use crate::{
+    achievements::{ACHIEVEMENTS, AchievementGoal},
+    cooking::{Ingredient, RECIPES},
+    death::DeathCause,
+    events::GameEvent,
+    explore::{ExploreOutcome, LOCATIONS, Loadout},
+    food::{FOODS, Food},
+    furniture::{HomeFurnitureKind, HomeFurnitureLocation},
+    game_context::GameContext,
+    items::ItemKind,
+    money::{Money, MoneyCategory},
+    pet::{
+        definition::{FoodTaste, PET_ADULTS, PetDefinition, PetDefinitionId},
+        genome::{Gene, Genome},
+    },
+    scene::{SceneEnum, home_scene::menu_options::MenuOption},
+    suiter::Suiter,
+};
+
+pub const HARNESS_START: Timestamp = Timestamp::new(NaiveDateTime::new(
+    NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+    NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+));
+pub const HARNESS_SEED: u64 = 1;
+pub const HARNESS_NAME: &str = "sdop";
+
+const UP: ButtonStates = [ButtonState::Up; 3];
+
+/// Drives a `Game` frame by frame with a fixed start time, seed and delta so
+/// runs are reproducible.
+pub struct Harness {
+    game: Game,
+    delta: Duration,
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Harness {
+    /// Fresh game sitting in the new pet flow
+    pub fn blank() -> Self {
+        Self::blank_at(HARNESS_START)
+    }
+
+    /// Like `blank` but starting at `start`
+    pub fn blank_at(start: Timestamp) -> Self {
+        let mut game = Game::blank(Some(start));
+        game.game_ctx.rng = fastrand::Rng::with_seed(HARNESS_SEED);
+        game.game_ctx.sim_rng = fastrand::Rng::with_seed(HARNESS_SEED);
+        // Pinned so the room stays pleasant unless a test says otherwise
+        game.update_temperature(ROOM_TEMPTURE);
+
+        Self {
+            game,
+            delta: SIM_LENGTH_STEP,
+        }
+    }
+
+    /// Fresh game with a named baby at home
+    pub fn new() -> Self {
+        Self::new_at(HARNESS_START)
+    }
+
+    /// Like `new` but starting at `start`
+    pub fn new_at(start: Timestamp) -> Self {
+        let mut result = Self::blank_at(start);
+        result.enter_text(HARNESS_NAME);
+        result.run_until(Duration::from_secs(1), |h| {
+            matches!(h.scene(), SceneEnum::Home(_))
+        });
+        result
+    }
+
+    /// Saves then boots a new game `away` later, the way a frontend would after being closed
+    pub fn reload(&mut self, away: Duration) -> AwaySummary {
+        let save = SaveFile::generate(self.now(), &self.game.game_ctx);
+        let mut game = Game::new(self.now() + away);
+        game.set_offline_cap(self.game.offline_cap);
+        game.input = self.game.input;
+        let summary = game.load_save(self.now() + away, save);
+        self.game = game;
+        summary
+    }
+
+    pub fn with_delta(mut self, delta: Duration) -> Self {
+        self.delta = delta;
+        self
+    }
+
+    pub fn set_delta(&mut self, delta: Duration) {
+        self.delta = delta;
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn game_mut(&mut self) -> &mut Game {
+        &mut self.game
+    }
+
+    pub fn ctx(&self) -> &GameContext {
+        &self.game.game_ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut GameContext {
+        &mut self.game.game_ctx
+    }
+
+    pub fn scene(&self) -> &SceneEnum {
+        self.game.scene_manger.scene_enum()
+    }
+
+    pub fn now(&self) -> Timestamp {
+        self.game.get_time()
+    }
+
+    pub fn pet_definition(&self) -> &'static PetDefinition {
+        self.ctx().pet.definition()
+    }
+
+    /// Adds to the wallet as a gift so it shows in the ledger like any other income
+    pub fn give_money(&mut self, amount: Money) {
+        let now = self.now();
+        self.ctx_mut().wallet.earn(MoneyCategory::Gift, amount, now);
+    }
+
+    pub fn set_temperature(&mut self, temperature: f32) {
+        self.game.update_temperature(temperature);
+    }
+
+    /// Drops the temperature sensor so the outdoor source takes over
+    pub fn remove_sensor(&mut self) {
+        self.game.input = Input::default();
+    }
+
+    /// Pulls every event queued since the last call
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        core::iter::from_fn(|| self.game.pull_event()).collect()
+    }
+
+    pub fn step(&mut self, buttons: ButtonStates) {
+        self.game.update_input_states(buttons);
+        self.game.tick(self.delta);
+        self.game.refresh_display(self.delta);
+    }
+
+    pub fn script(&mut self, frames: &[ButtonStates]) {
+        for buttons in frames {
+            self.step(*buttons);
+        }
+    }
+
+    pub fn idle(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step(UP);
+        }
+    }
+
+    pub fn run_for(&mut self, duration: Duration) {
+        let frames = duration.as_micros() / self.delta.as_micros().max(1);
+        self.idle(frames as usize);
+    }
+
+    /// Idles until `done` holds or `limit` of game time passes, returns if `done` held
+    pub fn run_until(&mut self, limit: Duration, done: impl Fn(&Self) -> bool) -> bool {
+        let mut elapsed = Duration::ZERO;
+        while !done(self) {
+            if elapsed >= limit {
+                return false;
+            }
+            self.step(UP);
+            elapsed += self.delta;
+        }
+        true
+    }
+
+    /// Runs only the sim over `duration` one `SIM_LENGTH_STEP` at a time, like while the game is on
+    pub fn sim_for(&mut self, duration: Duration) {
+        tick_sim(1., &mut self.advance_sim(duration));
+    }
+
+    /// Runs only the sim over `duration` the way time away is caught up on load
+    pub fn catch_up(&mut self, duration: Duration) -> AwaySummary {
+        catch_up_sim(&mut self.advance_sim(duration))
+    }
+
+    fn advance_sim(&mut self, duration: Duration) -> SceneTickArgs<'_> {
+        let game = &mut self.game;
+        game.last_time = game.last_time + duration;
+        SceneTickArgs {
+            timestamp: game.last_time,
+            delta: duration,
+            input: &game.input,
+            game_ctx: &mut game.game_ctx,
+            last_scene: None,
+            frames: game.frames,
+        }
+    }
+
+    pub fn press(&mut self, button: Button) {
+        let mut buttons = UP;
+        buttons[button.index()] = ButtonState::Down;
+        self.step(buttons);
+        self.step(UP);
+    }
+
+    pub fn press_times(&mut self, button: Button, times: usize) {
+        for _ in 0..times {
+            self.press(button);
+        }
+    }
+
+    pub fn select_home_option(&mut self, option: MenuOption) {
+        assert!(matches!(self.scene(), SceneEnum::Home(_)), "not at home");
+
+        let count = self.ctx().home.options().inner().len();
+        for _ in 0..count {
+            if *self.ctx().home.options().current() == option {
+                self.press(Button::Middle);
+                self.step(UP);
+                return;
+            }
+            self.press(Button::Right);
+        }
+
+        panic!("home option not available");
+    }
+
+    /// Types `text` into a freshly opened enter text scene and submits it
+    pub fn enter_text(&mut self, text: &str) {
+        self.run_until(Duration::from_secs(1), |h| {
+            matches!(h.scene(), SceneEnum::EnterText(_))
+        });
+        assert!(
+            matches!(self.scene(), SceneEnum::EnterText(_)),
+            "not entering text"
+        );
+
+        for (i, c) in text.chars().enumerate() {
+            let char_index = ENTERABLE_CHARS.find(c).expect("char can't be entered");
+            self.press(Button::Middle);
+            self.press_times(Button::Right, char_index);
+            self.press(Button::Middle);
+            if i + 1 < text.len() {
+                self.press(Button::Right);
+            }
+        }
+
+        // Moving left past the first letter wraps to submit
+        self.press_times(Button::Left, text.len());
+        self.press(Button::Middle);
+    }
+}
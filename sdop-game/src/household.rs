@@ -0,0 +1,148 @@
+use bincode::{Decode, Encode};
+
+use crate::{
+    Timestamp,
+    explore::{ExploreSystem, ExploreSystemSave},
+    pet::{PetInstance, UniquePetId},
+    vitals::VitalsLog,
+};
+
+pub const MAX_HOUSEHOLD: usize = 4;
+pub(crate) const MAX_OTHERS: usize = MAX_HOUSEHOLD - 1;
+
+/// A pet living at home that isn't the one currently selected
+pub struct HouseholdPet {
+    pub pet: PetInstance,
+    pub explore: ExploreSystem,
+    pub vitals: VitalsLog,
+}
+
+/// The pets sharing the home with the selected one in `GameContext::pet`
+#[derive(Default)]
+pub struct Household {
+    others: heapless::Vec<HouseholdPet, MAX_OTHERS>,
+}
+
+impl Household {
+    /// Every pet at home, the selected one included
+    pub fn len(&self) -> usize {
+        self.others.len() + 1
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.others.is_full()
+    }
+
+    pub fn others(&self) -> &[HouseholdPet] {
+        &self.others
+    }
+
+    pub fn others_mut(&mut self) -> &mut [HouseholdPet] {
+        &mut self.others
+    }
+
+    pub fn add(&mut self, pet: PetInstance, explore: ExploreSystem, vitals: VitalsLog) -> bool {
+        self.others
+            .push(HouseholdPet {
+                pet,
+                explore,
+                vitals,
+            })
+            .is_ok()
+    }
+
+    /// Takes the next pet in line out of the household
+    pub fn take_next(&mut self) -> Option<HouseholdPet> {
+        if self.others.is_empty() {
+            None
+        } else {
+            Some(self.others.remove(0))
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.others.clear();
+    }
+
+    pub fn save(&self) -> HouseholdSave {
+        let mut result = HouseholdSave::default();
+        for (slot, other) in result.pets.iter_mut().zip(&self.others) {
+            *slot = Some(HouseholdPetSave::new(
+                other.pet,
+                other.explore.save(),
+                other.vitals,
+            ));
+        }
+        result
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone)]
+pub struct HouseholdPetSave {
+    pet: PetInstance,
+    explore: ExploreSystemSave,
+    vitals: VitalsLog,
+}
+
+impl HouseholdPetSave {
+    pub fn new(pet: PetInstance, explore: ExploreSystemSave, vitals: VitalsLog) -> Self {
+        Self {
+            pet,
+            explore,
+            vitals,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub struct HouseholdSave {
+    pets: [Option<HouseholdPetSave>; MAX_OTHERS],
+}
+
+impl HouseholdSave {
+    pub fn new(pets: [Option<HouseholdPetSave>; MAX_OTHERS]) -> Self {
+        Self { pets }
+    }
+}
+
+impl From<HouseholdSave> for Household {
+    fn from(value: HouseholdSave) -> Self {
+        let mut result = Household::default();
+        for saved in value.pets.into_iter().flatten() {
+            result.add(saved.pet, saved.explore.into(), saved.vitals);
+        }
+        result
+    }
+}
+
+/// Keeps when something wears off for each pet in the household
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub struct HouseholdTimers {
+    timers: [Option<(UniquePetId, Timestamp)>; MAX_HOUSEHOLD],
+}
+
+impl HouseholdTimers {
+    /// Replaces the pet's timer, a full set gives up the one that ends first
+    pub fn start(&mut self, upid: UniquePetId, until: Timestamp) {
+        let slot = self
+            .timers
+            .iter()
+            .position(|timer| matches!(timer, Some((existing, _)) if *existing == upid))
+            .or_else(|| self.timers.iter().position(Option::is_none))
+            .or_else(|| {
+                (0..MAX_HOUSEHOLD).min_by_key(|index| self.timers[*index].map(|(_, until)| until))
+            })
+            .unwrap_or_default();
+        self.timers[slot] = Some((upid, until));
+    }
+
+    pub fn running(&self, upid: UniquePetId, now: Timestamp) -> bool {
+        self.timers
+            .iter()
+            .flatten()
+            .any(|(existing, until)| *existing == upid && now < *until)
+    }
+}
@@ -1,5 +1,3 @@
-use crate::game_consts;
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Button {
     Left,
@@ -38,7 +36,7 @@ pub type ButtonStates = [ButtonState; 3];
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Input {
-    temperature: f32,
+    temperature: Option<f32>,
     states: ButtonStates,
     last_state: ButtonStates,
 }
@@ -46,7 +44,7 @@ pub struct Input {
 impl Default for Input {
     fn default() -> Self {
         Self {
-            temperature: game_consts::ROOM_TEMPTURE,
+            temperature: None,
             states: [ButtonState::Up; 3],
             last_state: [ButtonState::Up; 3],
         }
@@ -56,7 +54,7 @@ impl Default for Input {
 impl Input {
     pub fn new(states: ButtonStates) -> Self {
         Self {
-            temperature: game_consts::ROOM_TEMPTURE,
+            temperature: None,
             states,
             last_state: states,
         }
@@ -85,11 +83,20 @@ impl Input {
         self.states = states;
     }
 
+    pub(crate) fn states(&self) -> ButtonStates {
+        self.states
+    }
+
+    pub(crate) fn last_states(&self) -> ButtonStates {
+        self.last_state
+    }
+
     pub fn update_temperature(&mut self, temperature: f32) {
-        self.temperature = temperature;
+        self.temperature = Some(temperature);
     }
 
-    pub fn temperature(&self) -> f32 {
+    /// Last outdoor reading from a sensor, `None` if the frontend has none
+    pub fn temperature(&self) -> Option<f32> {
         self.temperature
     }
 }
@@ -65,6 +65,11 @@ impl ItemKind {
 
     // This should be done at compile time
     pub fn is_usable(&self, game_ctx: &mut GameContext) -> bool {
+        // Gear packed for an expedition is not at home to use
+        if game_ctx.packed_count(*self) > 0 && game_ctx.unpacked_count(*self) == 0 {
+            return false;
+        }
+
         for usable in ALL_USEABLE_ITEMS {
             if usable.item == *self && (usable.usable_fn)(game_ctx) {
                 return true;
@@ -128,6 +133,8 @@ impl From<HomeFurnitureKind> for ItemKind {
             HomeFurnitureKind::PaintingPc => Self::PaintingPc,
             HomeFurnitureKind::PaintingSun => Self::PaintingSun,
             HomeFurnitureKind::PaintingMallsBalls => Self::PaintingMallsBalls,
+            HomeFurnitureKind::Stove => Self::Stove,
+            HomeFurnitureKind::Fridge => Self::Fridge,
         }
     }
 }
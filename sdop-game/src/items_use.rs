@@ -1,8 +1,9 @@
 use crate::{
+    furniture::HomeFurnitureKind,
     items::{ItemExtra, ItemKind, UsableItem, UseItemOutput},
     scene::{
-        SceneEnum, alarm_set_scene::AlarmSetScene, credits_scene::CreditsScene, fishing_scene,
-        home_scene, star_gazing_scene,
+        SceneEnum, alarm_set_scene::AlarmSetScene, cook_scene::CookScene,
+        credits_scene::CreditsScene, fishing_scene, home_scene, star_gazing_scene,
     },
 };
 
@@ -35,6 +36,7 @@ const USE_FISHING_ROD: UsableItem = UsableItem::new(ItemKind::FishingRod, |game_
 
 const USE_FISH: UsableItem = UsableItem::new(ItemKind::Fish, |game_ctx| {
     game_ctx.home_fish_tank.add(&mut game_ctx.rng);
+    game_ctx.achievements.record_fish();
     UseItemOutput::new().with_consumed()
 })
 .with_is_usable_fn(|game_ctx| game_ctx.inventory.has_item(ItemKind::FishTank));
@@ -58,6 +60,19 @@ const USE_CREDITS: UsableItem = UsableItem::new(ItemKind::CreditsScroll, |_| {
     UseItemOutput::new().with_scene(SceneEnum::Credits(CreditsScene::new()))
 });
 
+const USE_STOVE: UsableItem = UsableItem::new(ItemKind::Stove, |_| {
+    UseItemOutput::new().with_scene(SceneEnum::Cook(CookScene::new()))
+})
+.with_is_usable_fn(|game_ctx| {
+    game_ctx
+        .home_layout
+        .furniture_present(HomeFurnitureKind::Stove)
+        && !matches!(
+            game_ctx.home.state,
+            home_scene::State::Exploring | home_scene::State::GoneOut { outing_end_time: _ }
+        )
+});
+
 pub const ALL_USEABLE_ITEMS: &[UsableItem] = &[
     // USE_SHOP_UPGRADE,
     USE_FISHING_ROD,
@@ -65,4 +80,5 @@ pub const ALL_USEABLE_ITEMS: &[UsableItem] = &[
     USE_TELESCOPE,
     USE_ALARM,
     USE_CREDITS,
+    USE_STOVE,
 ];
@@ -16,31 +16,37 @@
 use core::time::Duration;
 
 use crate::{
+    achievements::update_achievements,
     display::{ConvertFn, DrawDisplay},
     fps::FPSCounter,
     game_consts::LOW_POWER_THRESHOLD,
     game_context::GameContext,
     input::Input,
-    pet::definition::PET_BABIES,
+    link::LinkHello,
+    pet::{ParentInfo, definition::PET_BABIES},
     scene::{
-        RenderArgs, SceneEnum, SceneManger, SceneOutput, SceneTickArgs, home_scene::HomeScene,
-        new_pet_scene::NewPetScene,
+        RenderArgs, SceneEnum, SceneManger, SceneOutput, SceneTickArgs, away_scene::AwayScene,
+        home_scene::HomeScene, new_pet_scene::NewPetScene,
     },
-    sim::tick_sim,
+    sim::{catch_up_sim, tick_sim},
 };
 
+mod achievements;
 mod alarm;
 mod anime;
 mod assets;
+mod away;
 mod bit_array;
 mod book;
 mod calendar;
 mod clock;
+mod cooking;
 mod date_utils;
 mod death;
 mod display;
 mod dream_bubble;
 mod egg;
+mod events;
 mod explore;
 mod firework;
 mod fish_tank;
@@ -51,43 +57,69 @@ mod furniture;
 mod game_consts;
 mod game_context;
 mod geo;
+#[cfg(feature = "std")]
+pub mod harness;
+mod household;
 mod input;
 mod invetro_light;
 mod items;
 mod items_use;
+mod lights;
+mod link;
 mod link_four;
 mod math;
 mod money;
 mod night_sky;
 #[cfg(feature = "notes")]
 mod notes;
+mod pantry;
 mod particle_system;
 mod pc;
 mod pet;
 mod poop;
+#[cfg(feature = "std")]
+mod replay;
 mod save;
 mod scene;
 mod shop;
 mod sim;
 mod sounds;
 mod sprite;
+mod status;
 mod stomach;
 mod suiter;
 mod temperature;
 mod thermometer;
 mod tic_tac_toe;
 mod tv;
+mod vitals;
 
+pub use crate::away::AwaySummary;
 pub use crate::date_utils::Timestamp;
+pub use crate::death::DeathCause;
 pub use crate::display::{HEIGHT, WIDTH};
-pub use crate::game_consts::ROOM_TEMPTURE;
+pub use crate::events::GameEvent;
+pub use crate::game_consts::{DEFAULT_OFFLINE_CAP, ROOM_TEMPTURE};
 pub use crate::input::{Button, ButtonState, ButtonStates};
 pub use crate::items::ALL_ITEMS;
+pub use crate::link::{LinkTransport, LoopbackTransport};
 #[cfg(feature = "notes")]
 pub use crate::notes::note_sound_file;
-pub use crate::save::{SAVE_SIZE, SaveFile};
+pub use crate::pet::{
+    Mood, PetName,
+    record::{PetHistory, PetHistoryStats, PetRecord},
+};
+#[cfg(feature = "std")]
+pub use crate::replay::{Replay, ReplayFrame};
+pub use crate::save::{
+    SAVE_SIZE, SaveFile,
+    storage::{OutOfBounds, STORAGE_SIZE, SaveStorage, SlotStorage, StorageError},
+};
 pub use crate::sounds::Song;
-pub use sdop_common::Note;
+pub use crate::status::{ExploreStatus, GameStatus, StatusName};
+pub use crate::temperature::{OutdoorTemperatureSource, outdoor_curve};
+pub use crate::vitals::{VitalsLog, VitalsSample};
+pub use sdop_common::{LifeStage, Note};
 
 pub struct Game {
     display: display::GameDisplay,
@@ -100,6 +132,9 @@ pub struct Game {
     fps: FPSCounter,
     since_input: Duration,
     frames: u32,
+    offline_cap: Option<Duration>,
+    #[cfg(feature = "std")]
+    recorder: Option<replay::Recorder>,
 }
 
 impl Game {
@@ -115,6 +150,9 @@ impl Game {
             fps: FPSCounter::new(),
             since_input: Duration::ZERO,
             frames: 0,
+            offline_cap: Some(DEFAULT_OFFLINE_CAP),
+            #[cfg(feature = "std")]
+            recorder: None,
         }
     }
 
@@ -134,6 +172,18 @@ impl Game {
         result
     }
 
+    /// Loads `save` if there is one otherwise starts fresh
+    pub fn start(timestamp: Timestamp, save: Option<SaveFile>) -> Self {
+        match save {
+            Some(save) => {
+                let mut result = Self::new(timestamp);
+                result.load_save(timestamp, save);
+                result
+            }
+            None => Self::blank(Some(timestamp)),
+        }
+    }
+
     pub fn update_input_states(&mut self, input_states: ButtonStates) {
         self.input.update_state(input_states);
     }
@@ -142,6 +192,12 @@ impl Game {
         self.input.update_temperature(temperature);
     }
 
+    /// Used for the outdoor temperature until a sensor reading comes in, defaults to
+    /// `outdoor_curve`
+    pub fn set_outdoor_temperature_source(&mut self, source: OutdoorTemperatureSource) {
+        self.game_ctx.outdoor_temperature_source = source;
+    }
+
     pub fn input(&self) -> Input {
         self.input
     }
@@ -157,6 +213,9 @@ impl Game {
     }
 
     pub fn tick(&mut self, delta: Duration) {
+        #[cfg(feature = "std")]
+        self.record_tick(delta);
+
         let timestamp = self.last_time + delta;
 
         self.game_ctx.speical_days.update(timestamp.inner().date());
@@ -173,7 +232,9 @@ impl Game {
             self.game_ctx.rng.bool();
         }
 
-        self.game_ctx.alarm.tick(&timestamp);
+        self.game_ctx
+            .alarm
+            .tick(&timestamp, &mut self.game_ctx.events);
 
         let mut scene_args = SceneTickArgs {
             timestamp,
@@ -188,6 +249,8 @@ impl Game {
 
         self.scene_manger.tick(&mut scene_args);
 
+        update_achievements(scene_args.game_ctx);
+
         let last_scene = self.scene_manger.take_last_scene();
         scene_args.last_scene = last_scene;
 
@@ -255,20 +318,43 @@ impl Game {
         Some(SaveFile::generate(timestamp, &self.game_ctx))
     }
 
-    pub fn load_save(&mut self, timestamp: Timestamp, save: SaveFile) {
+    /// Limits how much offline time `load_save` will simulate, `None` catches up all of it.
+    /// Defaults to `DEFAULT_OFFLINE_CAP`
+    pub fn set_offline_cap(&mut self, cap: Option<Duration>) {
+        self.offline_cap = cap;
+    }
+
+    pub fn load_save(&mut self, timestamp: Timestamp, save: SaveFile) -> AwaySummary {
         let last_timestamp = save.last_timestamp;
-        let delta = (timestamp - last_timestamp).min(Duration::from_days(7));
+        let away = timestamp - last_timestamp;
+        let delta = match self.offline_cap {
+            Some(cap) => away.min(cap),
+            None => away,
+        };
         save.load(&mut self.game_ctx);
         let mut scene_args = SceneTickArgs {
-            timestamp: last_timestamp,
+            timestamp: last_timestamp + delta,
             delta,
             input: &self.input,
             game_ctx: &mut self.game_ctx,
             last_scene: None,
             frames: self.frames,
         };
-        tick_sim(1., &mut scene_args);
+        let mut summary = catch_up_sim(&mut scene_args);
+        summary.away = away;
+        summary.skipped = away - delta;
+
         self.scene_manger = SceneManger::default();
+        if summary.eventful() {
+            self.scene_manger
+                .set_next(SceneEnum::Away(AwayScene::new(summary)));
+        }
+
+        summary
+    }
+
+    pub fn status(&self) -> GameStatus {
+        GameStatus::new(&self.game_ctx)
     }
 
     pub fn get_time(&self) -> Timestamp {
@@ -282,6 +368,28 @@ impl Game {
     pub fn set_playing_song(&mut self, playing: bool) {
         self.game_ctx.sound_system.set_playing(playing);
     }
+
+    pub fn pull_event(&mut self) -> Option<GameEvent> {
+        self.game_ctx.events.pull()
+    }
+
+    /// Exchanges anything pending with a linked device, call it every frame while linked
+    pub fn link_poll(&mut self, transport: &mut impl LinkTransport) {
+        let pet = &self.game_ctx.pet;
+        let hello = pet.should_die().is_none().then(|| LinkHello {
+            pet: ParentInfo::new(pet.upid, pet.def_id, pet.name),
+            wants_breed: pet.should_breed(),
+        });
+        self.game_ctx
+            .link
+            .poll(transport, self.last_time, hello, &mut self.game_ctx.events);
+    }
+
+    pub fn link_close(&mut self, transport: &mut impl LinkTransport) {
+        self.game_ctx
+            .link
+            .close(transport, &mut self.game_ctx.events);
+    }
 }
 
 pub trait WrappingEnum: Copy + Sized {
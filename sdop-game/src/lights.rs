@@ -0,0 +1,86 @@
+use core::time::Duration;
+
+use bincode::{Decode, Encode};
+
+use crate::{
+    events::{GameEvent, GameEvents},
+    game_consts::LIGHTS_ON_CARE_MISTAKE,
+};
+
+/// The room lights, the owner has to turn them off once the pet is asleep
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone)]
+pub struct Lights {
+    on: bool,
+    // Time asleep with the lights on since the last care mistake
+    lit_sleep: Duration,
+    pub care_mistakes: u16,
+}
+
+impl Default for Lights {
+    fn default() -> Self {
+        Self {
+            on: true,
+            lit_sleep: Duration::ZERO,
+            care_mistakes: 0,
+        }
+    }
+}
+
+impl Lights {
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+
+    pub fn toggle(&mut self) {
+        self.on = !self.on;
+    }
+
+    pub fn sim_tick(
+        &mut self,
+        delta: Duration,
+        sleeping: bool,
+        woke_up: bool,
+        events: &mut GameEvents,
+    ) {
+        if woke_up {
+            self.on = true;
+        }
+
+        if !sleeping || !self.on {
+            self.lit_sleep = Duration::ZERO;
+            return;
+        }
+
+        self.lit_sleep += delta;
+        if self.lit_sleep >= LIGHTS_ON_CARE_MISTAKE {
+            self.lit_sleep = Duration::ZERO;
+            self.care_mistakes = self.care_mistakes.saturating_add(1);
+            events.push(GameEvent::LeftLightsOn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_care_mistake() {
+        let mut lights = Lights::default();
+        let mut events = GameEvents::default();
+
+        lights.sim_tick(LIGHTS_ON_CARE_MISTAKE / 2, true, false, &mut events);
+        assert_eq!(lights.care_mistakes, 0);
+        lights.sim_tick(LIGHTS_ON_CARE_MISTAKE / 2, true, false, &mut events);
+        assert_eq!(lights.care_mistakes, 1);
+        assert_eq!(events.pull(), Some(GameEvent::LeftLightsOn));
+
+        lights.toggle();
+        lights.sim_tick(LIGHTS_ON_CARE_MISTAKE * 2, true, false, &mut events);
+        assert_eq!(lights.care_mistakes, 1);
+
+        lights.sim_tick(Duration::from_secs(1), false, true, &mut events);
+        assert!(lights.is_on());
+    }
+}
@@ -0,0 +1,372 @@
+use bincode::{Decode, Encode};
+
+use crate::{
+    Timestamp,
+    events::{GameEvent, GameEvents},
+    game_consts::{LINK_HELLO_INTERVAL, LINK_TIMEOUT},
+    pet::ParentInfo,
+};
+
+pub const LINK_VERSION: u16 = 1;
+
+const FRAME_MAGIC: [u8; 2] = *b"SL";
+const MAX_PAYLOAD: usize = 64;
+// Magic, length and checksum around the payload
+const MAX_FRAME: usize = MAX_PAYLOAD + 4;
+const BINCODE_CONFIG: bincode::config::Configuration = bincode::config::standard();
+
+/// A byte stream to another device, reads must never block
+pub trait LinkTransport {
+    /// Fills `buf` with whatever has arrived returning how many bytes were read
+    fn read(&mut self, buf: &mut [u8]) -> usize;
+
+    fn write(&mut self, bytes: &[u8]);
+}
+
+#[derive(Encode, Decode, Copy, Clone)]
+pub enum LinkMessage {
+    Hello {
+        version: u16,
+        // If we have heard the other side's hello, both sides hearing completes the handshake
+        heard: bool,
+        pet: ParentInfo,
+        wants_breed: bool,
+    },
+    Bye,
+}
+
+/// Wraps `message` as magic, length, payload then checksum
+fn encode_frame(
+    message: &LinkMessage,
+    out: &mut heapless::Vec<u8, MAX_FRAME>,
+) -> Result<(), bincode::error::EncodeError> {
+    let mut payload = [0u8; MAX_PAYLOAD];
+    let len = bincode::encode_into_slice(message, &mut payload, BINCODE_CONFIG)?;
+    let payload = &payload[..len];
+
+    out.clear();
+    let _ = out.extend_from_slice(&FRAME_MAGIC);
+    let _ = out.push(len as u8);
+    let _ = out.extend_from_slice(payload);
+    let _ = out.push(checksum(payload));
+    Ok(())
+}
+
+fn checksum(payload: &[u8]) -> u8 {
+    !payload
+        .iter()
+        .fold(payload.len() as u8, |acc, byte| acc.wrapping_add(*byte))
+}
+
+#[derive(Default, Clone, Copy)]
+enum DecodeState {
+    #[default]
+    Magic,
+    Length,
+    Payload,
+    Checksum,
+}
+
+/// Pulls frames out of a byte stream, anything garbled is skipped until the next magic
+#[derive(Default)]
+pub struct FrameDecoder {
+    state: DecodeState,
+    magic_matched: usize,
+    len: usize,
+    payload: heapless::Vec<u8, MAX_PAYLOAD>,
+}
+
+impl FrameDecoder {
+    /// Returns a message once `byte` completes a valid frame
+    pub fn push(&mut self, byte: u8) -> Option<LinkMessage> {
+        match self.state {
+            DecodeState::Magic => {
+                if byte == FRAME_MAGIC[self.magic_matched] {
+                    self.magic_matched += 1;
+                } else {
+                    self.magic_matched = (byte == FRAME_MAGIC[0]) as usize;
+                }
+                if self.magic_matched == FRAME_MAGIC.len() {
+                    self.magic_matched = 0;
+                    self.state = DecodeState::Length;
+                }
+            }
+            DecodeState::Length => {
+                self.len = byte as usize;
+                self.payload.clear();
+                self.state = if self.len == 0 || self.len > MAX_PAYLOAD {
+                    DecodeState::Magic
+                } else {
+                    DecodeState::Payload
+                };
+            }
+            DecodeState::Payload => {
+                let _ = self.payload.push(byte);
+                if self.payload.len() == self.len {
+                    self.state = DecodeState::Checksum;
+                }
+            }
+            DecodeState::Checksum => {
+                self.state = DecodeState::Magic;
+                if byte == checksum(&self.payload) {
+                    return bincode::decode_from_slice(&self.payload, BINCODE_CONFIG)
+                        .ok()
+                        .map(|(message, _)| message);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct LinkPartner {
+    pub pet: ParentInfo,
+    pub wants_breed: bool,
+}
+
+/// What we tell the other side about our pet
+#[derive(Clone, Copy)]
+pub struct LinkHello {
+    pub pet: ParentInfo,
+    pub wants_breed: bool,
+}
+
+/// Handshake and keep alive with whoever is on the other end of a `LinkTransport`
+#[derive(Default)]
+pub struct LinkSession {
+    decoder: FrameDecoder,
+    partner: Option<LinkPartner>,
+    partner_heard_us: bool,
+    last_heard: Option<Timestamp>,
+    last_hello: Option<Timestamp>,
+    connected: bool,
+}
+
+impl LinkSession {
+    /// The other pet once both sides have said hello
+    pub fn partner(&self) -> Option<&LinkPartner> {
+        self.partner.as_ref().filter(|_| self.connected)
+    }
+
+    pub fn connected(&self) -> bool {
+        self.connected
+    }
+
+    /// The partner's details when both pets are ready to breed
+    pub fn breeding_partner(&self, wants_breed: bool) -> Option<ParentInfo> {
+        self.partner()
+            .filter(|partner| wants_breed && partner.wants_breed)
+            .map(|partner| partner.pet)
+    }
+
+    pub fn poll(
+        &mut self,
+        transport: &mut impl LinkTransport,
+        now: Timestamp,
+        hello: Option<LinkHello>,
+        events: &mut GameEvents,
+    ) {
+        let mut buf = [0u8; 32];
+        let mut reply = false;
+        loop {
+            let read = transport.read(&mut buf);
+            if read == 0 {
+                break;
+            }
+            for byte in &buf[..read] {
+                match self.decoder.push(*byte) {
+                    Some(LinkMessage::Hello {
+                        version,
+                        heard,
+                        pet,
+                        wants_breed,
+                    }) if version == LINK_VERSION => {
+                        reply |= self.partner.is_none() || !heard;
+                        self.partner = Some(LinkPartner { pet, wants_breed });
+                        self.partner_heard_us = heard;
+                        self.last_heard = Some(now);
+                    }
+                    Some(LinkMessage::Bye) => self.drop_partner(),
+                    _ => {}
+                }
+            }
+        }
+
+        if self
+            .last_heard
+            .is_some_and(|last_heard| now - last_heard > LINK_TIMEOUT)
+        {
+            self.drop_partner();
+        }
+
+        match hello {
+            Some(hello) => {
+                let due = self
+                    .last_hello
+                    .is_none_or(|last_hello| now - last_hello >= LINK_HELLO_INTERVAL);
+                if reply || due {
+                    self.send(
+                        transport,
+                        &LinkMessage::Hello {
+                            version: LINK_VERSION,
+                            heard: self.partner.is_some(),
+                            pet: hello.pet,
+                            wants_breed: hello.wants_breed,
+                        },
+                    );
+                    self.last_hello = Some(now);
+                }
+            }
+            None => {
+                // Nothing to share, the other side will time out
+                self.last_hello = None;
+            }
+        }
+
+        let connected = hello.is_some() && self.partner.is_some() && self.partner_heard_us;
+        if connected != self.connected {
+            events.push(if connected {
+                GameEvent::LinkConnected
+            } else {
+                GameEvent::LinkLost
+            });
+        }
+        self.connected = connected;
+    }
+
+    /// Tells the other side we are going away
+    pub fn close(&mut self, transport: &mut impl LinkTransport, events: &mut GameEvents) {
+        self.send(transport, &LinkMessage::Bye);
+        self.drop_partner();
+        self.last_hello = None;
+        if self.connected {
+            self.connected = false;
+            events.push(GameEvent::LinkLost);
+        }
+    }
+
+    fn drop_partner(&mut self) {
+        self.partner = None;
+        self.partner_heard_us = false;
+        self.last_heard = None;
+    }
+
+    fn send(&mut self, transport: &mut impl LinkTransport, message: &LinkMessage) {
+        let mut frame = heapless::Vec::new();
+        if encode_frame(message, &mut frame).is_ok() {
+            transport.write(&frame);
+        }
+    }
+}
+
+const LOOPBACK_SIZE: usize = 256;
+
+/// In memory transport, two of them are wired together with `LoopbackTransport::exchange`
+#[derive(Default)]
+pub struct LoopbackTransport {
+    inbox: heapless::Deque<u8, LOOPBACK_SIZE>,
+    outbox: heapless::Deque<u8, LOOPBACK_SIZE>,
+}
+
+impl LoopbackTransport {
+    /// Delivers everything each side has written to the other
+    pub fn exchange(a: &mut Self, b: &mut Self) {
+        while let Some(byte) = a.outbox.pop_front() {
+            let _ = b.inbox.push_back(byte);
+        }
+        while let Some(byte) = b.outbox.pop_front() {
+            let _ = a.inbox.push_back(byte);
+        }
+    }
+}
+
+impl LinkTransport for LoopbackTransport {
+    fn read(&mut self, buf: &mut [u8]) -> usize {
+        let mut read = 0;
+        for slot in buf.iter_mut() {
+            let Some(byte) = self.inbox.pop_front() else {
+                break;
+            };
+            *slot = byte;
+            read += 1;
+        }
+        read
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            if self.outbox.push_back(*byte).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::pet::definition::PET_ADULTS;
+
+    fn hello(upid: u64) -> LinkHello {
+        LinkHello {
+            pet: ParentInfo::new(upid, PET_ADULTS[0], crate::pet::PetName::from("link")),
+            wants_breed: true,
+        }
+    }
+
+    #[test]
+    fn test_frame_resyncs() {
+        let mut frame = heapless::Vec::new();
+        encode_frame(&LinkMessage::Bye, &mut frame).unwrap();
+
+        let mut decoder = FrameDecoder::default();
+        let mut corrupt = frame.clone();
+        let last = corrupt.len() - 1;
+        corrupt[last] = !corrupt[last];
+
+        let stream = [b"xS".as_slice(), &corrupt, b"junk", &frame];
+        let decoded = stream
+            .iter()
+            .flat_map(|chunk| chunk.iter())
+            .filter_map(|byte| decoder.push(*byte))
+            .count();
+        assert_eq!(decoded, 1);
+    }
+
+    #[test]
+    fn test_handshake_and_timeout() {
+        let start = Timestamp::default();
+        let mut events = GameEvents::default();
+        let (mut a, mut b) = (LinkSession::default(), LinkSession::default());
+        let (mut ta, mut tb) = (LoopbackTransport::default(), LoopbackTransport::default());
+
+        for i in 0..3 {
+            let now = start + Duration::from_millis(100 * i);
+            a.poll(&mut ta, now, Some(hello(1)), &mut events);
+            b.poll(&mut tb, now, Some(hello(2)), &mut events);
+            LoopbackTransport::exchange(&mut ta, &mut tb);
+        }
+        assert_eq!(a.partner().unwrap().pet.upid(), 2);
+        assert_eq!(b.partner().unwrap().pet.upid(), 1);
+        assert_eq!(a.breeding_partner(true).unwrap().upid(), 2);
+        assert!(a.breeding_partner(false).is_none());
+        assert_eq!(events.pull(), Some(GameEvent::LinkConnected));
+
+        // b goes quiet
+        a.poll(
+            &mut ta,
+            start + LINK_TIMEOUT * 2,
+            Some(hello(1)),
+            &mut events,
+        );
+        assert!(!a.connected());
+        assert!(b.connected());
+
+        b.close(&mut tb, &mut events);
+        assert!(!b.connected());
+    }
+}
@@ -1 +1,212 @@
+use bincode::{Decode, Encode};
+
+use crate::Timestamp;
+
 pub type Money = i32;
+
+// Keeps the balance clear of overflow and short enough to fit on screen
+pub const MAX_MONEY: Money = 99_999_999;
+
+pub const LEDGER_ENTRIES: usize = 32;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub enum MoneyCategory {
+    Shop,
+    Heal,
+    Adopt,
+    Explore,
+    Fishing,
+    Minigame,
+    Gift,
+}
+
+impl MoneyCategory {
+    pub const COUNT: usize = core::mem::variant_count::<Self>();
+
+    pub const ALL: [MoneyCategory; Self::COUNT] = [
+        MoneyCategory::Shop,
+        MoneyCategory::Heal,
+        MoneyCategory::Adopt,
+        MoneyCategory::Explore,
+        MoneyCategory::Fishing,
+        MoneyCategory::Minigame,
+        MoneyCategory::Gift,
+    ];
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MoneyCategory::Shop => "SHOP",
+            MoneyCategory::Heal => "HEAL",
+            MoneyCategory::Adopt => "ADOPT",
+            MoneyCategory::Explore => "EXPLR",
+            MoneyCategory::Fishing => "FISH",
+            MoneyCategory::Minigame => "GAMES",
+            MoneyCategory::Gift => "GIFT",
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct Transaction {
+    pub category: MoneyCategory,
+    // Negative for spending
+    pub amount: Money,
+    pub timestamp: Timestamp,
+}
+
+/// The last `LEDGER_ENTRIES` transactions along with lifetime totals for each category
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default)]
+pub struct Ledger {
+    // Total ever recorded, the next goes in at `top % LEDGER_ENTRIES`
+    top: usize,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    entries: [Option<Transaction>; LEDGER_ENTRIES],
+    earned: [Money; MoneyCategory::COUNT],
+    spent: [Money; MoneyCategory::COUNT],
+}
+
+impl Ledger {
+    fn record(&mut self, transaction: Transaction) {
+        let index = transaction.category as usize;
+        if transaction.amount >= 0 {
+            self.earned[index] = self.earned[index].saturating_add(transaction.amount);
+        } else {
+            self.spent[index] = self.spent[index].saturating_sub(transaction.amount);
+        }
+        self.entries[self.top % LEDGER_ENTRIES] = Some(transaction);
+        self.top += 1;
+    }
+
+    pub fn count(&self) -> usize {
+        self.top.min(LEDGER_ENTRIES)
+    }
+
+    /// Newest kept transaction is index 0
+    pub fn get_latest(&self, index: usize) -> Option<&Transaction> {
+        if index >= self.count() {
+            return None;
+        }
+        self.entries[(self.top - 1 - index) % LEDGER_ENTRIES].as_ref()
+    }
+
+    /// Kept transactions from newest to oldest
+    pub fn iter(&self) -> impl Iterator<Item = &Transaction> {
+        (0..self.count()).filter_map(|index| self.get_latest(index))
+    }
+
+    pub fn earned(&self, category: MoneyCategory) -> Money {
+        self.earned[category as usize]
+    }
+
+    pub fn spent(&self, category: MoneyCategory) -> Money {
+        self.spent[category as usize]
+    }
+}
+
+/// The balance, it only changes through `earn` and `spend` so every change is in the ledger
+#[derive(Clone, Copy, Default)]
+pub struct Wallet {
+    balance: Money,
+    ledger: Ledger,
+}
+
+impl Wallet {
+    pub fn new(balance: Money, ledger: Ledger) -> Self {
+        Self {
+            balance: balance.clamp(0, MAX_MONEY),
+            ledger,
+        }
+    }
+
+    pub fn balance(&self) -> Money {
+        self.balance
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    pub fn can_afford(&self, amount: Money) -> bool {
+        amount >= 0 && amount <= self.balance
+    }
+
+    /// Adds `amount` up to `MAX_MONEY` returning what was actually added
+    pub fn earn(&mut self, category: MoneyCategory, amount: Money, now: Timestamp) -> Money {
+        let amount = amount.clamp(0, MAX_MONEY - self.balance);
+        if amount > 0 {
+            self.balance += amount;
+            self.ledger.record(Transaction {
+                category,
+                amount,
+                timestamp: now,
+            });
+        }
+        amount
+    }
+
+    /// Takes `amount` if there is enough, false leaves the balance untouched
+    pub fn spend(&mut self, category: MoneyCategory, amount: Money, now: Timestamp) -> bool {
+        if !self.can_afford(amount) {
+            return false;
+        }
+        if amount > 0 {
+            self.balance -= amount;
+            self.ledger.record(Transaction {
+                category,
+                amount: -amount,
+                timestamp: now,
+            });
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guards_balance() {
+        let now = Timestamp::default();
+        let mut wallet = Wallet::default();
+
+        assert!(!wallet.spend(MoneyCategory::Shop, 1, now));
+        assert!(!wallet.spend(MoneyCategory::Shop, -10, now));
+        assert_eq!(wallet.earn(MoneyCategory::Fishing, -10, now), 0);
+        assert_eq!(wallet.balance(), 0);
+        assert_eq!(wallet.ledger().count(), 0);
+
+        assert_eq!(
+            wallet.earn(MoneyCategory::Explore, Money::MAX, now),
+            MAX_MONEY
+        );
+        assert_eq!(wallet.earn(MoneyCategory::Explore, 1, now), 0);
+        assert!(wallet.spend(MoneyCategory::Heal, MAX_MONEY, now));
+        assert_eq!(wallet.balance(), 0);
+
+        assert_eq!(wallet.ledger().earned(MoneyCategory::Explore), MAX_MONEY);
+        assert_eq!(wallet.ledger().spent(MoneyCategory::Heal), MAX_MONEY);
+        assert_eq!(wallet.ledger().get_latest(0).unwrap().amount, -MAX_MONEY);
+    }
+
+    #[test]
+    fn test_ledger_rolls_over() {
+        let now = Timestamp::default();
+        let mut wallet = Wallet::default();
+        for amount in 1..=(LEDGER_ENTRIES as Money + 5) {
+            wallet.earn(MoneyCategory::Minigame, amount, now);
+        }
+
+        let ledger = wallet.ledger();
+        assert_eq!(ledger.count(), LEDGER_ENTRIES);
+        assert_eq!(
+            ledger.iter().next().unwrap().amount,
+            LEDGER_ENTRIES as Money + 5
+        );
+        assert_eq!(ledger.iter().last().unwrap().amount, 6);
+        assert_eq!(ledger.earned(MoneyCategory::Minigame), wallet.balance());
+    }
+}
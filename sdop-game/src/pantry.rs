@@ -0,0 +1,184 @@
+use core::time::Duration;
+
+use bincode::{Decode, Encode};
+
+use crate::{
+    Timestamp,
+    food::{FOOD_COUNT, FOODS, Food},
+    furniture::{HomeFurnitureKind, HomeLayout},
+    game_consts::{FOOD_SHELF_LIFE, SPOILED_FOOD_UPSET, STARTING_FOOD_STOCK},
+    household::HouseholdTimers,
+    items::Inventory,
+    pet::UniquePetId,
+};
+
+pub const FOOD_STOCK_MAX: usize = 6;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default)]
+pub(crate) struct FoodStock {
+    // Oldest first, how long each unit has been kept out of a fridge
+    ages: [Option<Duration>; FOOD_STOCK_MAX],
+}
+
+impl FoodStock {
+    fn count(&self) -> usize {
+        self.ages.iter().flatten().count()
+    }
+}
+
+/// Food bought from the shop waiting to be eaten or cooked, shared by the household
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone)]
+pub struct Pantry {
+    pub(crate) stock: [FoodStock; FOOD_COUNT],
+    upset: HouseholdTimers,
+}
+
+impl Default for Pantry {
+    fn default() -> Self {
+        Self::stocked(&Inventory::default(), STARTING_FOOD_STOCK)
+    }
+}
+
+impl Pantry {
+    pub(crate) fn new(stock: [FoodStock; FOOD_COUNT], upset: HouseholdTimers) -> Self {
+        Self { stock, upset }
+    }
+
+    /// `count` fresh units of every food on the menu
+    pub fn stocked(inventory: &Inventory, count: usize) -> Self {
+        let mut result = Self::new(
+            [FoodStock::default(); FOOD_COUNT],
+            HouseholdTimers::default(),
+        );
+        for food in FOODS.iter().filter(|food| inventory.has_item(food.item)) {
+            for _ in 0..count {
+                result.add(food);
+            }
+        }
+        result
+    }
+
+    pub fn count(&self, food: &Food) -> usize {
+        self.stock[food.id].count()
+    }
+
+    pub fn has_room(&self, food: &Food) -> bool {
+        self.count(food) < FOOD_STOCK_MAX
+    }
+
+    pub fn has_any(&self) -> bool {
+        FOODS.iter().any(|food| self.count(food) > 0)
+    }
+
+    /// False if there is no room left for `food`
+    pub fn add(&mut self, food: &Food) -> bool {
+        match self.stock[food.id]
+            .ages
+            .iter_mut()
+            .find(|age| age.is_none())
+        {
+            Some(slot) => {
+                *slot = Some(Duration::ZERO);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// If the unit that would be eaten next has gone off
+    pub fn next_spoiled(&self, food: &Food) -> bool {
+        self.stock[food.id].ages[0].is_some_and(|age| age >= FOOD_SHELF_LIFE)
+    }
+
+    pub fn spoiled_count(&self, food: &Food) -> usize {
+        self.stock[food.id]
+            .ages
+            .iter()
+            .flatten()
+            .filter(|age| **age >= FOOD_SHELF_LIFE)
+            .count()
+    }
+
+    /// Removes the oldest unit of `food` returning if it had spoiled, None when out of stock
+    pub fn take(&mut self, food: &Food) -> Option<bool> {
+        let spoiled = self.next_spoiled(food);
+        let ages = &mut self.stock[food.id].ages;
+        ages[0].take()?;
+        ages.rotate_left(1);
+        Some(spoiled)
+    }
+
+    pub fn sim_tick(&mut self, delta: Duration, layout: &HomeLayout) {
+        if layout.furniture_present(HomeFurnitureKind::Fridge) {
+            return;
+        }
+
+        for stock in &mut self.stock {
+            for age in stock.ages.iter_mut().flatten() {
+                *age += delta;
+            }
+        }
+    }
+
+    pub fn ate_spoiled(&mut self, upid: UniquePetId, now: Timestamp) {
+        self.upset.start(upid, now + SPOILED_FOOD_UPSET);
+    }
+
+    /// Still feeling a spoiled meal
+    pub fn upset(&self, upid: UniquePetId, now: Timestamp) -> bool {
+        self.upset.running(upid, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::furniture::HomeFurnitureLocation;
+
+    #[test]
+    fn test_oldest_eaten_first() {
+        let food = FOODS[1];
+        let mut pantry = Pantry::stocked(&Inventory::default(), 0);
+        let mut layout = HomeLayout::default();
+        assert_eq!(pantry.take(food), None);
+
+        assert!(pantry.add(food));
+        pantry.sim_tick(FOOD_SHELF_LIFE, &layout);
+        assert!(pantry.add(food));
+        layout.place(HomeFurnitureLocation::Left, HomeFurnitureKind::Fridge);
+        pantry.sim_tick(FOOD_SHELF_LIFE, &layout);
+
+        assert_eq!(pantry.spoiled_count(food), 1);
+        assert_eq!(pantry.take(food), Some(true));
+        assert_eq!(pantry.take(food), Some(false));
+        assert_eq!(pantry.count(food), 0);
+    }
+
+    #[test]
+    fn test_stock_is_capped() {
+        let food = FOODS[2];
+        let mut pantry = Pantry::stocked(&Inventory::default(), 0);
+        for _ in 0..FOOD_STOCK_MAX {
+            assert!(pantry.add(food));
+        }
+        assert!(!pantry.has_room(food));
+        assert!(!pantry.add(food));
+        assert_eq!(pantry.count(food), FOOD_STOCK_MAX);
+    }
+
+    #[test]
+    fn test_upset_per_pet() {
+        let mut pantry = Pantry::stocked(&Inventory::default(), 0);
+        let now = Timestamp::default();
+
+        pantry.ate_spoiled(1, now);
+        pantry.ate_spoiled(2, now + SPOILED_FOOD_UPSET / 2);
+        assert!(pantry.upset(1, now));
+        assert!(pantry.upset(2, now + SPOILED_FOOD_UPSET));
+        assert!(!pantry.upset(1, now + SPOILED_FOOD_UPSET));
+        assert!(!pantry.upset(3, now));
+    }
+}
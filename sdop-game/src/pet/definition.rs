@@ -7,9 +7,15 @@ use crate::{
     assets::{self, MaskedFramesSet},
     explore::ExploreSkill,
     food::Food,
-    game_consts::{ADULT_LIFE_STAGE_ITEMS, BABY_LIFE_STAGE_ITEMS, CHILD_LIFE_STAGE_ITEMS},
+    game_consts::{
+        ADULT_LIFE_STAGE_ITEMS, BABY_LIFE_STAGE_ITEMS, CHILD_LIFE_STAGE_ITEMS,
+        COFFEE_SLEEP_DELAY_HOURS,
+    },
     items::ItemKind,
-    pet::LifeStage,
+    pet::{
+        LifeStage,
+        evolve::{Evolution, EvolveCondition},
+    },
 };
 use const_for::const_for;
 
@@ -17,6 +23,33 @@ include!(concat!(env!("OUT_DIR"), "/dist_pets.rs"));
 
 pub type PetDefinitionId = i32;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FoodTaste {
+    Neutral,
+    // Makes it happy until the food wears off
+    Liked,
+    // Makes it sad until the food wears off
+    Disliked,
+    // Won't eat it at all
+    Refused,
+}
+
+pub struct FoodPreference {
+    pub food: &'static Food,
+    pub taste: FoodTaste,
+    pub fill_multiplier: f32,
+}
+
+impl FoodPreference {
+    pub const fn new(food: &'static Food, taste: FoodTaste, fill_multiplier: f32) -> Self {
+        Self {
+            food,
+            taste,
+            fill_multiplier,
+        }
+    }
+}
+
 pub struct PetDefinition {
     pub id: PetDefinitionId,
     pub name: &'static str,
@@ -24,6 +57,13 @@ pub struct PetDefinition {
     pub images: PetImageSet,
     pub stomach_size: f32,
     pub base_weight: f32,
+    // How long until it's ready to evolve, never if None
+    pub evolve_after: Option<Duration>,
+    pub evolutions: &'static [Evolution],
+    pub food_preferences: &'static [FoodPreference],
+    // Hours of the day it goes to bed and gets up
+    pub bedtime: u8,
+    pub wake: u8,
 }
 
 impl PetDefinition {
@@ -42,13 +82,64 @@ impl PetDefinition {
             images,
             stomach_size,
             base_weight,
+            evolve_after: None,
+            evolutions: &[],
+            food_preferences: &[],
+            bedtime: match life_stage {
+                LifeStage::Baby => 20,
+                LifeStage::Child => 21,
+                LifeStage::Adult => 22,
+            },
+            wake: match life_stage {
+                LifeStage::Baby => 6,
+                LifeStage::Child => 8,
+                LifeStage::Adult => 7,
+            },
         }
     }
+
+    pub const fn with_sleep(mut self, bedtime: u8, wake: u8) -> Self {
+        self.bedtime = bedtime;
+        self.wake = wake;
+        self
+    }
+
+    pub const fn with_food_preferences(
+        mut self,
+        food_preferences: &'static [FoodPreference],
+    ) -> Self {
+        self.food_preferences = food_preferences;
+        self
+    }
+
+    pub const fn with_evolutions(
+        mut self,
+        evolve_after: Duration,
+        evolutions: &'static [Evolution],
+    ) -> Self {
+        self.evolve_after = Some(evolve_after);
+        self.evolutions = evolutions;
+        self
+    }
 }
 
 impl PetDefinition {
-    pub fn food_multiplier(&self, _food: &Food) -> f32 {
-        1.
+    fn food_preference(&self, food: &Food) -> Option<&FoodPreference> {
+        self.food_preferences
+            .iter()
+            .find(|preference| preference.food == food)
+    }
+
+    pub fn food_multiplier(&self, food: &Food) -> f32 {
+        self.food_preference(food)
+            .map(|preference| preference.fill_multiplier)
+            .unwrap_or(1.)
+    }
+
+    pub fn food_taste(&self, food: &Food) -> FoodTaste {
+        self.food_preference(food)
+            .map(|preference| preference.taste)
+            .unwrap_or(FoodTaste::Neutral)
     }
 
     pub const fn poop_interval_range(&self) -> Range<Duration> {
@@ -80,18 +171,19 @@ impl PetDefinition {
         }
 
         let hour = datetime.hour() as i32;
-        let (mut start, mut end) = match self.life_stage {
-            LifeStage::Baby => (6, 20),
-            LifeStage::Child => (8, 21),
-            LifeStage::Adult => (7, 22),
-        };
+        let (mut start, mut end) = (self.wake as i32, self.bedtime as i32);
+        // Bedtime is after midnight
+        if end <= start {
+            end += 24;
+        }
 
         if coffee {
-            start -= 1;
-            end += 1;
+            start -= COFFEE_SLEEP_DELAY_HOURS;
+            end += COFFEE_SLEEP_DELAY_HOURS;
         }
 
-        !(start..end).contains(&hour)
+        let awake = |hour: i32| (start..end).contains(&hour);
+        !(awake(hour) || awake(hour + 24) || awake(hour - 24))
     }
 
     pub const fn wonder_speed(&self) -> f32 {
@@ -0,0 +1,153 @@
+use core::time::Duration;
+
+use crate::{
+    food::Food,
+    game_consts::GENOME_EVOLUTION_BIAS,
+    items::{Inventory, ItemKind},
+    pet::{PetInstance, definition::PetDefinitionId},
+};
+
+/// A requirement for an evolution, generated from `pets.ron`
+pub enum EvolveCondition {
+    ItemsOwned(&'static [ItemKind]),
+    AnyBookCompleted(&'static [ItemKind]),
+    BooksCompleted(usize),
+    ExtraWeightOver(f32),
+    Ill,
+    ColdFor(Duration),
+    HotFor(Duration),
+    Ate(&'static Food, usize),
+}
+
+impl EvolveCondition {
+    pub fn met(&self, pet: &PetInstance, inv: &Inventory) -> bool {
+        match self {
+            EvolveCondition::ItemsOwned(items) => items.iter().all(|item| inv.has_item(*item)),
+            EvolveCondition::AnyBookCompleted(books) => books
+                .iter()
+                .any(|book| pet.book_history.get_read(*book).completed()),
+            EvolveCondition::BooksCompleted(count) => pet.book_history.completed_count() >= *count,
+            EvolveCondition::ExtraWeightOver(weight) => pet.extra_weight > *weight,
+            EvolveCondition::Ill => pet.is_ill(),
+            EvolveCondition::ColdFor(duration) => pet.total_cold_for > *duration,
+            EvolveCondition::HotFor(duration) => pet.total_hot_for > *duration,
+            EvolveCondition::Ate(food, count) => pet.food_history.consumed_count(food) >= *count,
+        }
+    }
+}
+
+pub struct Evolution {
+    pub target: PetDefinitionId,
+    pub weight: usize,
+    pub conditions: &'static [EvolveCondition],
+}
+
+impl Evolution {
+    pub const fn new(
+        target: PetDefinitionId,
+        weight: usize,
+        conditions: &'static [EvolveCondition],
+    ) -> Self {
+        Self {
+            target,
+            weight,
+            conditions,
+        }
+    }
+
+    pub fn possible(&self, pet: &PetInstance, inv: &Inventory) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.met(pet, inv))
+    }
+}
+
+/// Picks one of the evolutions whose conditions are met, more weight is more likely
+/// and the `favoured` target gets its weight boosted
+pub fn choose_evolution<'a>(
+    rng: &mut fastrand::Rng,
+    possible: impl Iterator<Item = &'a Evolution> + Clone,
+    favoured: Option<PetDefinitionId>,
+) -> Option<PetDefinitionId> {
+    let weight = |evolution: &Evolution| {
+        if favoured == Some(evolution.target) {
+            evolution.weight * GENOME_EVOLUTION_BIAS
+        } else {
+            evolution.weight
+        }
+    };
+
+    let total: usize = possible.clone().map(weight).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.usize(..total);
+    for evolution in possible {
+        if roll < weight(evolution) {
+            return Some(evolution.target);
+        }
+        roll -= weight(evolution);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pet::{
+        LifeStage,
+        definition::{PET_COUNT, PetDefinition},
+    };
+
+    static NONE: [EvolveCondition; 0] = [];
+
+    #[test]
+    fn test_choose_evolution_weights() {
+        let evolutions = [Evolution::new(1, 0, &NONE), Evolution::new(2, 3, &NONE)];
+        let mut rng = fastrand::Rng::with_seed(0);
+
+        for _ in 0..20 {
+            assert_eq!(choose_evolution(&mut rng, evolutions.iter(), None), Some(2));
+        }
+        assert_eq!(
+            choose_evolution(&mut rng, evolutions[..1].iter(), None),
+            None
+        );
+        assert_eq!(
+            choose_evolution(&mut rng, evolutions[..1].iter(), Some(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_choose_evolution_favoured() {
+        let evolutions = [Evolution::new(1, 1, &NONE), Evolution::new(2, 1, &NONE)];
+        let mut rng = fastrand::Rng::with_seed(0);
+
+        let favoured = (0..1000)
+            .filter(|_| choose_evolution(&mut rng, evolutions.iter(), Some(2)) == Some(2))
+            .count();
+        assert!(favoured > 600, "{favoured}");
+    }
+
+    #[test]
+    fn test_generated_evolutions() {
+        for id in 0..PET_COUNT as PetDefinitionId {
+            let definition = PetDefinition::get_by_id(id);
+            if definition.life_stage == LifeStage::Adult {
+                assert!(definition.evolve_after.is_none());
+                continue;
+            }
+
+            assert!(definition.evolve_after.is_some());
+            assert!(!definition.evolutions.is_empty());
+            for evolution in definition.evolutions {
+                let target = PetDefinition::get_by_id(evolution.target);
+                assert_ne!(target.life_stage, LifeStage::Baby);
+                assert_ne!(target.life_stage, definition.life_stage);
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    explore::ExploreSkill,
+    food::{FOODS, Food},
+    game_consts::{
+        GENOME_EXPLORE_SWING, GENOME_MUTATION_ODDS, GENOME_RESISTANCE_SWING, GENOME_STOMACH_SWING,
+        GENOME_WEIGHT_SWING,
+    },
+    pet::{UniquePetId, definition::PetDefinition},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gene {
+    Stomach,
+    Weight,
+    Explore,
+    Resistance,
+    Food,
+    Evolution,
+}
+
+impl Gene {
+    pub const ALL: [Gene; 6] = [
+        Gene::Stomach,
+        Gene::Weight,
+        Gene::Explore,
+        Gene::Resistance,
+        Gene::Food,
+        Gene::Evolution,
+    ];
+
+    const fn shift(self) -> u32 {
+        self as u32 * GENE_BITS
+    }
+}
+
+const GENE_BITS: u32 = 5;
+pub const GENE_MAX: u8 = (1 << GENE_BITS) - 1;
+const GENOME_MASK: UniquePetId = (1 << (GENE_BITS * Gene::ALL.len() as u32)) - 1;
+
+/// Traits a pet is born with, stored in the low bits of its `UniquePetId`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Genome {
+    bits: UniquePetId,
+}
+
+impl Genome {
+    pub const fn from_upid(upid: UniquePetId) -> Self {
+        Self {
+            bits: upid & GENOME_MASK,
+        }
+    }
+
+    /// Each gene comes from one parent at random and might mutate a step either way
+    pub fn inherit(rng: &mut fastrand::Rng, a: Genome, b: Genome) -> Self {
+        let mut result = Self { bits: 0 };
+        for gene in Gene::ALL {
+            let mut value = if rng.bool() {
+                a.gene(gene)
+            } else {
+                b.gene(gene)
+            };
+            if rng.f32() < GENOME_MUTATION_ODDS {
+                value = if rng.bool() {
+                    value.saturating_add(1).min(GENE_MAX)
+                } else {
+                    value.saturating_sub(1)
+                };
+            }
+            result.set_gene(gene, value);
+        }
+        result
+    }
+
+    /// Writes the genome into the genome bits of `upid`
+    pub const fn apply(self, upid: UniquePetId) -> UniquePetId {
+        (upid & !GENOME_MASK) | self.bits
+    }
+
+    pub const fn gene(&self, gene: Gene) -> u8 {
+        ((self.bits >> gene.shift()) as u8) & GENE_MAX
+    }
+
+    fn set_gene(&mut self, gene: Gene, value: u8) {
+        self.bits &= !((GENE_MAX as UniquePetId) << gene.shift());
+        self.bits |= ((value & GENE_MAX) as UniquePetId) << gene.shift();
+    }
+
+    // -1 to 1 with the middle of the gene's range being 0
+    fn spread(&self, gene: Gene) -> f32 {
+        self.gene(gene) as f32 / GENE_MAX as f32 * 2. - 1.
+    }
+
+    pub fn stomach_multiplier(&self) -> f32 {
+        1. + self.spread(Gene::Stomach) * GENOME_STOMACH_SWING
+    }
+
+    pub fn weight_multiplier(&self) -> f32 {
+        1. + self.spread(Gene::Weight) * GENOME_WEIGHT_SWING
+    }
+
+    pub fn explore_bonus(&self) -> ExploreSkill {
+        libm::roundf(self.spread(Gene::Explore) * GENOME_EXPLORE_SWING) as ExploreSkill
+    }
+
+    /// Scales the odds of falling ill, lower is hardier
+    pub fn illness_multiplier(&self) -> f32 {
+        1. - self.spread(Gene::Resistance) * GENOME_RESISTANCE_SWING
+    }
+
+    pub fn favourite_food(&self) -> &'static Food {
+        FOODS[self.gene(Gene::Food) as usize % FOODS.len()]
+    }
+
+    /// The evolution of `definition` this pet leans towards
+    pub fn favoured_evolution(&self, definition: &PetDefinition) -> Option<usize> {
+        if definition.evolutions.is_empty() {
+            None
+        } else {
+            Some(self.gene(Gene::Evolution) as usize % definition.evolutions.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pet::gen_pid;
+
+    #[test]
+    fn test_inherit() {
+        let mut rng = fastrand::Rng::with_seed(0);
+        let a = Genome::from_upid(0);
+        let b = Genome::from_upid(GENOME_MASK);
+
+        for _ in 0..100 {
+            let child = Genome::inherit(&mut rng, a, b);
+            for gene in Gene::ALL {
+                let value = child.gene(gene);
+                assert!(value <= 1 || value >= GENE_MAX - 1, "{gene:?} {value}");
+            }
+        }
+
+        let twin = Genome::inherit(&mut rng, a, a);
+        assert!(Gene::ALL.iter().all(|gene| twin.gene(*gene) <= 1));
+    }
+
+    #[test]
+    fn test_apply_keeps_id_bits() {
+        let mut rng = fastrand::Rng::with_seed(1);
+        let upid = gen_pid(&mut rng);
+        let genome = Genome::from_upid(GENOME_MASK);
+        let applied = genome.apply(upid);
+        assert_eq!(applied & !GENOME_MASK, upid & !GENOME_MASK);
+        assert_eq!(Genome::from_upid(applied), genome);
+        assert!((0.5..1.5).contains(&genome.stomach_multiplier()));
+    }
+
+    #[test]
+    fn test_explore_bonus_centered() {
+        let swing = GENOME_EXPLORE_SWING as ExploreSkill;
+        assert_eq!(Genome::from_upid(0).explore_bonus(), -swing);
+        assert_eq!(Genome::from_upid(GENOME_MASK).explore_bonus(), swing);
+    }
+}
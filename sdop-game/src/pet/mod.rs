@@ -3,32 +3,36 @@ use core::time::Duration;
 use bincode::{Decode, Encode};
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use fastrand::Rng;
-use heapless::Vec;
 use strum::EnumCount;
 
 use crate::{
     Timestamp,
     book::BookHistory,
-    death::{DeathCause, get_threshold_odds, passed_threshold_chance},
+    death::{
+        DeathCause, get_threshold_odds, passed_sim_threshold_chance, passed_threshold_chance,
+        sim_odds,
+    },
     explore::{ExploreHistory, ExploreSkill},
     food::{FOOD_COFFEE, Food, FoodHistory},
-    furniture::{HomeFurnitureKind, HomeLayout},
     game_consts::{
         BREED_ODDS_THRESHOLD, COFFEE_POOP_MODIFER, DEATH_BY_HYPOTHERMIA_THRESHOLD,
         DEATH_BY_ILLNESS_THRESHOLD, DEATH_BY_LIGHTING_STRIKE_ODDS, DEATH_CHECK_INTERVERAL,
         DEATH_STARVE_THRESHOLDS, DEATH_TOXIC_SHOCK_THRESHOLD, EVOLVE_CHECK_INTERVERAL,
         HEALING_COST_RANGE, HUNGER_LOSS_PER_SECOND, ILLNESS_AUTO_HEAL_ODDS_ADULT,
         ILLNESS_AUTO_HEAL_ODDS_BABY, ILLNESS_AUTO_HEAL_ODDS_CHILD, ILLNESS_BABY_ODDS,
-        ILLNESS_BASE_ODDS, ILLNESS_CHILD_ODDS, ILLNESS_SINCE_ODDS, ILLNESS_STARVING_ODDS,
-        OLD_AGE_THRESHOLD, RANDOM_NAMES, SPLACE_LOCATIONS,
+        ILLNESS_BASE_ODDS, ILLNESS_CHILD_ODDS, ILLNESS_CONTAGION_ODDS, ILLNESS_SINCE_ODDS,
+        ILLNESS_SPOILED_FOOD_ODDS, ILLNESS_STARVING_ODDS, OLD_AGE_THRESHOLD, RANDOM_NAMES,
+        SPLACE_LOCATIONS,
     },
-    items::{Inventory, ItemKind},
+    items::Inventory,
     money::Money,
-    pet::definition::{
-        PET_BALLOTEE_ID, PET_BEACH_UNBRELLA_ID, PET_BEERIE_ID, PET_BRAINO_ID, PET_CKCS_ID,
-        PET_COMPUTIE_ID, PET_COUNT, PET_DEVIL_ID, PET_HUMBIE_ID, PET_ICE_CUBE_ID,
-        PET_PAWN_WHITE_ID, PET_SICKO_ID, PET_SNOWMAN_ID, PET_WAS_GAURD_ID, PetAnimationSet,
-        PetDefinition, PetDefinitionId,
+    pet::{
+        definition::{
+            FoodTaste, PET_SICKO_ID, PET_SNOWMAN_ID, PetAnimationSet, PetDefinition,
+            PetDefinitionId,
+        },
+        evolve::choose_evolution,
+        genome::Genome,
     },
     poop::{Poop, poop_count},
     scene::home_scene,
@@ -36,6 +40,8 @@ use crate::{
 };
 
 pub mod definition;
+pub mod evolve;
+pub mod genome;
 pub mod record;
 pub mod render;
 pub use sdop_common::LifeStage;
@@ -164,7 +170,9 @@ pub type UniquePetId = u64;
 pub fn combine_pid(a: UniquePetId, b: UniquePetId) -> UniquePetId {
     const EVEN: UniquePetId = 0b1010101010101010101010101010101010101010101010101010101010101010;
     let combined = (a & EVEN) | (b & !EVEN);
-    gen_pid(&mut fastrand::Rng::with_seed(combined))
+    let mut rng = fastrand::Rng::with_seed(combined);
+    let upid = gen_pid(&mut rng);
+    Genome::inherit(&mut rng, Genome::from_upid(a), Genome::from_upid(b)).apply(upid)
 }
 
 pub fn gen_pid(rng: &mut fastrand::Rng) -> UniquePetId {
@@ -180,7 +188,7 @@ pub fn planet_location_from_upid(upid: UniquePetId) -> (&'static str, u8) {
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Default)]
-struct PetIllness {
+pub(crate) struct PetIllness {
     since_illness: Duration,
     with_illness: Duration,
     #[cfg_attr(feature = "serde", serde(default))]
@@ -205,20 +213,20 @@ pub struct PetInstance {
     pub since_death_check: Duration,
     pub since_evolve_check: Duration,
     pub should_die: Option<DeathCause>,
-    should_evolve: Option<PetDefinitionId>,
+    pub(crate) should_evolve: Option<PetDefinitionId>,
     pub parents: Option<PetParents>,
     pub life_stage_history: LifeStageHistory,
-    mood: Mood,
-    should_breed: bool,
+    pub(crate) mood: Mood,
+    pub(crate) should_breed: bool,
     pub book_history: BookHistory,
-    illness: PetIllness,
-    total_cold_for: Duration,
-    cold_for: Duration,
-    total_hot_for: Duration,
+    pub(crate) illness: PetIllness,
+    pub(crate) total_cold_for: Duration,
+    pub(crate) cold_for: Duration,
+    pub(crate) total_hot_for: Duration,
     pub seen_alien: bool,
     pub explore: ExploreHistory,
     pub food_history: FoodHistory,
-    is_sleeping: bool,
+    pub(crate) is_sleeping: bool,
 }
 
 impl Default for PetInstance {
@@ -265,23 +273,79 @@ impl PetInstance {
         PetDefinition::get_by_id(self.def_id)
     }
 
+    pub fn genome(&self) -> Genome {
+        Genome::from_upid(self.upid)
+    }
+
+    pub fn stomach_size(&self) -> f32 {
+        self.definition().stomach_size * self.genome().stomach_multiplier()
+    }
+
+    pub fn base_weight(&self) -> f32 {
+        self.definition().base_weight * self.genome().weight_multiplier()
+    }
+
     pub fn food_fill_percent(&self) -> f32 {
-        (self.stomach_filled / self.definition().stomach_size).min(1.)
+        (self.stomach_filled / self.stomach_size()).min(1.)
     }
 
     pub fn food_fill(&self, food: &Food) -> f32 {
         food.fill_factor * self.definition().food_multiplier(food)
     }
 
-    pub fn eat(&mut self, food: &Food, now: Timestamp) {
-        self.stomach_filled += self.food_fill(food);
-        let extra = self.stomach_filled - self.definition().stomach_size;
+    /// The species' taste unless it's the food this pet was born liking
+    pub fn food_taste(&self, food: &Food) -> FoodTaste {
+        match self.definition().food_taste(food) {
+            FoodTaste::Refused => FoodTaste::Refused,
+            _ if self.genome().favourite_food() == food => FoodTaste::Liked,
+            taste => taste,
+        }
+    }
+
+    /// Returns false if the pet refused the food
+    pub fn eat(&mut self, food: &Food, now: Timestamp) -> bool {
+        self.eat_serving(food, 1., now)
+    }
+
+    /// Eats `portion` times the usual fill, returns false if the pet refused the food
+    pub fn eat_serving(&mut self, food: &Food, portion: f32, now: Timestamp) -> bool {
+        if self.food_taste(food) == FoodTaste::Refused {
+            return false;
+        }
+
+        self.stomach_filled += self.food_fill(food) * portion;
+        let extra = self.stomach_filled - self.stomach_size();
         if extra > 0. {
-            self.stomach_filled = self.definition().stomach_size;
+            self.stomach_filled = self.stomach_size();
             self.extra_weight += extra;
         }
 
         self.food_history.add(food, now);
+
+        true
+    }
+
+    // Liked or disliked food affects the mood until it wears off
+    fn food_mood(&self, now: Timestamp) -> Option<Mood> {
+        let mut result = None;
+        let favourite = self.genome().favourite_food();
+        let foods = self
+            .definition()
+            .food_preferences
+            .iter()
+            .map(|preference| preference.food)
+            .chain(core::iter::once(favourite));
+        for food in foods {
+            if !self.food_history.ate_since_time(food, now) {
+                continue;
+            }
+            match self.food_taste(food) {
+                FoodTaste::Disliked => return Some(Mood::Sad),
+                FoodTaste::Liked => result = Some(Mood::Happy),
+                _ => {}
+            }
+        }
+        result
     }
 
     pub fn tick_age(&mut self, delta: Duration) {
@@ -332,9 +396,10 @@ impl PetInstance {
         self.until_poop = self.until_poop.checked_sub(delta).unwrap_or_default();
     }
 
-    pub fn tick_since_game(&mut self, delta: Duration, sleep: bool) {
+    pub fn tick_since_game(&mut self, delta: Duration, sleep: bool, company: bool) {
         if !sleep {
-            self.since_game += delta;
+            // Playing with a housemate keeps them busy
+            self.since_game += if company { delta / 2 } else { delta };
         }
     }
 
@@ -351,7 +416,7 @@ impl PetInstance {
 
         self.since_death_check += delta;
 
-        if self.since_death_check > DEATH_CHECK_INTERVERAL {
+        if self.since_death_check >= DEATH_CHECK_INTERVERAL {
             // Random death
             if rng.f32() < DEATH_BY_LIGHTING_STRIKE_ODDS {
                 self.should_die = Some(DeathCause::LightingStrike);
@@ -450,74 +515,24 @@ impl PetInstance {
 
         self.since_evolve_check = Duration::ZERO;
 
-        match self.definition().life_stage {
-            LifeStage::Baby => {
-                if self.life_stage_age < Duration::from_days(1) {
-                    return;
-                }
-            }
-            LifeStage::Child => {
-                if self.life_stage_age < Duration::from_days(2) {
-                    return;
-                }
-            }
-            LifeStage::Adult => {
-                return;
-            }
+        let definition = self.definition();
+        match definition.evolve_after {
+            Some(evolve_after) if self.life_stage_age >= evolve_after => {}
+            _ => return,
         }
 
         let mut rng = fastrand::Rng::with_seed(self.upid);
 
-        let mut possible = Vec::<PetDefinitionId, PET_COUNT>::new();
-        match self.definition().life_stage {
-            LifeStage::Baby => {
-                let _ = possible.push(PET_HUMBIE_ID);
-                let _ = possible.push(PET_PAWN_WHITE_ID);
-                let _ = possible.push(PET_DEVIL_ID);
-                if self.total_cold_for > Duration::ZERO {
-                    let _ = possible.push(PET_ICE_CUBE_ID);
-                }
-            }
-            LifeStage::Child => {
-                let _ = possible.push(PET_BEERIE_ID);
-                let _ = possible.push(PET_WAS_GAURD_ID);
-                if self
-                    .book_history
-                    .get_read(ItemKind::BookNevileWran)
-                    .completed()
-                    || self
-                        .book_history
-                        .get_read(ItemKind::BookVic19811992)
-                        .completed()
-                {
-                    let _ = possible.push(PET_BALLOTEE_ID);
-                }
-                if inv.has_item(ItemKind::PersonalComputer)
-                    && inv.has_item(ItemKind::Screen)
-                    && inv.has_item(ItemKind::Keyboard)
-                {
-                    let _ = possible.push(PET_COMPUTIE_ID);
-                }
-                if self.extra_weight > 50. {
-                    let _ = possible.push(PET_CKCS_ID);
-                }
-                if self.is_ill() {
-                    let _ = possible.push(PET_SICKO_ID);
-                }
-                if self.book_history.completed_count() >= 3 {
-                    let _ = possible.push(PET_BRAINO_ID);
-                }
-                if self.total_cold_for > Duration::from_hours(1) {
-                    let _ = possible.push(PET_SNOWMAN_ID);
-                }
-                if self.total_hot_for > Duration::from_hours(1) {
-                    let _ = possible.push(PET_BEACH_UNBRELLA_ID);
-                }
-            }
-            LifeStage::Adult => {}
-        };
+        let possible = definition
+            .evolutions
+            .iter()
+            .filter(|evolution| evolution.possible(self, inv));
+        let favoured = self
+            .genome()
+            .favoured_evolution(definition)
+            .map(|index| definition.evolutions[index].target);
 
-        self.should_evolve = rng.choice(possible.iter()).cloned();
+        self.should_evolve = choose_evolution(&mut rng, possible, favoured);
     }
 
     pub fn should_evolve(&self) -> Option<PetDefinitionId> {
@@ -543,28 +558,41 @@ impl PetInstance {
         &mut self,
         poops: &[Option<Poop>],
         temperature: TemperatureLevel,
-        layout: &HomeLayout,
+        now: Timestamp,
+        lights_on: bool,
+        treated: bool,
     ) {
-        self.mood = self.calc_mood(poops, temperature, layout);
+        self.mood = self.calc_mood(poops, temperature, now, lights_on, treated);
     }
 
     fn calc_mood(
         &self,
         poops: &[Option<Poop>],
         temperature: TemperatureLevel,
-        layout: &HomeLayout,
+        now: Timestamp,
+        lights_on: bool,
+        treated: bool,
     ) -> Mood {
         let is_starved = matches!(self.stomach_mood, StomachMood::Starving { elapsed: _ });
 
         if is_starved
             || poop_count(poops) > 0
             || self.is_ill()
-            || (temperature.is_hot() && !layout.furniture_present(HomeFurnitureKind::AirCon))
-            || (temperature.is_cold() && !layout.furniture_present(HomeFurnitureKind::SpaceHeater))
+            || (self.is_sleeping && lights_on)
+            || temperature.is_hot()
+            || temperature.is_cold()
         {
             return Mood::Sad;
         }
 
+        if treated {
+            return Mood::Happy;
+        }
+
+        if let Some(mood) = self.food_mood(now) {
+            return mood;
+        }
+
         let tummy_full = matches!(self.stomach_mood, StomachMood::Full { elapsed: _ });
 
         if tummy_full {
@@ -575,7 +603,7 @@ impl PetInstance {
     }
 
     pub fn weight(&self) -> f32 {
-        self.extra_weight + self.definition().base_weight
+        self.extra_weight + self.base_weight()
     }
 
     fn can_breed(&self) -> bool {
@@ -590,7 +618,7 @@ impl PetInstance {
         self.should_breed
     }
 
-    pub fn tick_breed(&mut self, rng: &mut fastrand::Rng, egg_exists: bool) {
+    pub fn tick_breed(&mut self, rng: &mut fastrand::Rng, delta: Duration, egg_exists: bool) {
         if egg_exists {
             self.should_breed = false;
             return;
@@ -600,18 +628,13 @@ impl PetInstance {
             return;
         }
 
-        if passed_threshold_chance(rng, BREED_ODDS_THRESHOLD, self.life_stage_age) {
+        if passed_sim_threshold_chance(rng, BREED_ODDS_THRESHOLD, self.life_stage_age, delta) {
             self.should_breed = true;
         }
     }
 
-    pub fn tick_tempeture(
-        &mut self,
-        delta: Duration,
-        temperature: TemperatureLevel,
-        home_layout: HomeLayout,
-    ) {
-        if temperature.is_cold() && !home_layout.furniture_present(HomeFurnitureKind::SpaceHeater) {
+    pub fn tick_tempeture(&mut self, delta: Duration, temperature: TemperatureLevel) {
+        if temperature.is_cold() {
             let to_add = delta
                 * if matches!(temperature, TemperatureLevel::VeryCold) {
                     2
@@ -624,7 +647,7 @@ impl PetInstance {
             self.cold_for = Duration::ZERO;
         }
 
-        if temperature.is_hot() && !home_layout.furniture_present(HomeFurnitureKind::AirCon) {
+        if temperature.is_hot() {
             self.total_hot_for += delta;
         }
     }
@@ -645,7 +668,7 @@ impl PetInstance {
         self.illness.with_illness = Duration::ZERO;
     }
 
-    pub fn tick_illness(&mut self, rng: &mut fastrand::Rng, delta: Duration) {
+    pub fn tick_illness(&mut self, rng: &mut fastrand::Rng, delta: Duration, upset: bool) {
         if self.illness.with_illness > Duration::ZERO {
             self.illness.since_illness = Duration::ZERO;
             self.illness.with_illness += delta;
@@ -654,7 +677,7 @@ impl PetInstance {
                 LifeStage::Child => ILLNESS_AUTO_HEAL_ODDS_CHILD,
                 LifeStage::Adult => ILLNESS_AUTO_HEAL_ODDS_ADULT,
             };
-            if rng.f32() < odds {
+            if rng.f32() < sim_odds(odds, delta) {
                 self.cure();
             }
         } else {
@@ -673,24 +696,53 @@ impl PetInstance {
 
             odds += get_threshold_odds(ILLNESS_SINCE_ODDS, self.illness.since_illness);
 
+            if upset {
+                odds += ILLNESS_SPOILED_FOOD_ODDS;
+            }
+
             if self.def_id == PET_SICKO_ID {
                 odds *= 0.5;
             }
+            odds *= self.genome().illness_multiplier();
 
-            if rng.f32() < odds {
-                self.illness.cost = (rng.i32(HEALING_COST_RANGE) as f32
-                    * match self.definition().life_stage {
-                        LifeStage::Baby => 0.5,
-                        LifeStage::Child => 0.7,
-                        LifeStage::Adult => 1.0,
-                    }) as Money;
-                self.illness.with_illness = delta;
+            if rng.f32() < sim_odds(odds, delta) {
+                self.fall_ill(rng, delta);
             }
         }
     }
 
+    /// Catching something off an ill housemate
+    pub fn catch_illness(
+        &mut self,
+        rng: &mut fastrand::Rng,
+        delta: Duration,
+        ill_housemates: usize,
+    ) {
+        if !self.is_ill()
+            && rng.f32()
+                < sim_odds(
+                    ILLNESS_CONTAGION_ODDS
+                        * ill_housemates as f32
+                        * self.genome().illness_multiplier(),
+                    delta,
+                )
+        {
+            self.fall_ill(rng, delta);
+        }
+    }
+
+    pub fn fall_ill(&mut self, rng: &mut fastrand::Rng, delta: Duration) {
+        self.illness.cost = (rng.i32(HEALING_COST_RANGE) as f32
+            * match self.definition().life_stage {
+                LifeStage::Baby => 0.5,
+                LifeStage::Child => 0.7,
+                LifeStage::Adult => 1.0,
+            }) as Money;
+        self.illness.with_illness = delta;
+    }
+
     pub fn explore_skill(&self) -> ExploreSkill {
-        self.definition().explore_skill() + self.explore.bonus_skill
+        self.definition().explore_skill() + self.explore.bonus_skill + self.genome().explore_bonus()
     }
 
     pub fn is_sleeping(&self) -> bool {
@@ -699,8 +751,7 @@ impl PetInstance {
 
     pub fn should_be_sleeping(&self, timestamp: &Timestamp) -> bool {
         self.definition()
-            .should_be_sleeping(timestamp, self.food_history.sick_of(&FOOD_COFFEE));
-        false
+            .should_be_sleeping(timestamp, self.food_history.sick_of(&FOOD_COFFEE))
     }
 
     pub fn tick_sleeping(&mut self, timestamp: &Timestamp) {
@@ -720,6 +771,14 @@ pub enum Mood {
 }
 
 impl Mood {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Mood::Normal => "NORMAL",
+            Mood::Sad => "SAD",
+            Mood::Happy => "HAPPY",
+        }
+    }
+
     pub fn anime_set(&self) -> PetAnimationSet {
         match self {
             Mood::Normal => PetAnimationSet::Normal,
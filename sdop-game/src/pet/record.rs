@@ -8,6 +8,7 @@ use crate::{
     pet::{
         LifeStageHistory, PetInstance, PetName, PetParents, UniquePetId,
         definition::{PetDefinition, PetDefinitionId},
+        genome::Genome,
     },
 };
 
@@ -49,42 +50,123 @@ impl PetRecord {
     }
 
     pub fn weight(&self) -> f32 {
-        self.extra_weight + PetDefinition::get_by_id(self.def_id).base_weight
+        self.extra_weight
+            + PetDefinition::get_by_id(self.def_id).base_weight
+                * Genome::from_upid(self.upid).weight_multiplier()
+    }
+
+    pub fn species(&self) -> &'static str {
+        PetDefinition::get_by_id(self.def_id).name
     }
 }
 
 pub const PET_HISTORY_ENTRIES: usize = 20;
 
+/// Totals over every pet ever recorded, kept after their records roll off
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default)]
+pub struct PetHistoryStats {
+    pub raised: u32,
+    pub died_of: [u32; DeathCause::COUNT],
+    pub longest_lived: Option<PetRecord>,
+}
+
+impl PetHistoryStats {
+    fn record(&mut self, entry: &PetRecord) {
+        self.raised = self.raised.saturating_add(1);
+        self.died_of[entry.died_of as usize] =
+            self.died_of[entry.died_of as usize].saturating_add(1);
+        if self
+            .longest_lived
+            .is_none_or(|longest| entry.age() > longest.age())
+        {
+            self.longest_lived = Some(*entry);
+        }
+    }
+
+    pub fn died_of(&self, cause: DeathCause) -> u32 {
+        self.died_of[cause as usize]
+    }
+}
+
+/// The last `PET_HISTORY_ENTRIES` records, once full the oldest is overwritten
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Default)]
 pub struct PetHistory {
+    // Total ever added, the next record goes in at `top % PET_HISTORY_ENTRIES`
     top: usize,
     entires: [Option<PetRecord>; PET_HISTORY_ENTRIES],
+    stats: PetHistoryStats,
 }
 
 impl PetHistory {
     pub fn add(&mut self, entry: PetRecord) {
-        self.entires[self.top] = Some(entry);
+        self.stats.record(&entry);
+        self.entires[self.top % PET_HISTORY_ENTRIES] = Some(entry);
         self.top += 1;
     }
 
+    /// Oldest kept record is index 0
     pub fn get_by_index(&self, index: usize) -> Option<&PetRecord> {
-        self.entires.get(index)?.as_ref()
+        if index >= self.count() {
+            return None;
+        }
+        let oldest = self.top - self.count();
+        self.entires[(oldest + index) % PET_HISTORY_ENTRIES].as_ref()
     }
 
     pub fn get_by_upid(&self, upid: UniquePetId) -> Option<&PetRecord> {
-        for entry in &self.entires {
-            if let Some(entry) = entry {
-                if entry.upid == upid {
-                    return Some(entry);
-                }
-            }
-        }
+        self.iter().find(|entry| entry.upid == upid)
+    }
 
-        None
+    /// Kept records from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &PetRecord> {
+        (0..self.count()).filter_map(|index| self.get_by_index(index))
     }
 
+    /// How many records are kept, see `stats` for every pet ever
     pub fn count(&self) -> usize {
-        self.top
+        self.top.min(PET_HISTORY_ENTRIES)
+    }
+
+    pub fn stats(&self) -> &PetHistoryStats {
+        &self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(upid: UniquePetId, age_days: u64) -> PetRecord {
+        let pet = PetInstance {
+            upid,
+            ..Default::default()
+        };
+        let death = pet.born + Duration::from_days(age_days);
+        PetRecord::from_pet_instance(&pet, death, DeathCause::OldAge)
+    }
+
+    #[test]
+    fn test_rolls_over() {
+        let mut history = PetHistory::default();
+        for upid in 0..(PET_HISTORY_ENTRIES as UniquePetId + 5) {
+            history.add(record(upid, if upid == 2 { 30 } else { 1 }));
+        }
+
+        assert_eq!(history.count(), PET_HISTORY_ENTRIES);
+        assert_eq!(history.get_by_index(0).unwrap().upid, 5);
+        assert_eq!(
+            history.get_by_index(PET_HISTORY_ENTRIES - 1).unwrap().upid,
+            PET_HISTORY_ENTRIES as UniquePetId + 4
+        );
+        assert!(history.get_by_index(PET_HISTORY_ENTRIES).is_none());
+        assert!(history.get_by_upid(2).is_none());
+        assert_eq!(history.iter().count(), PET_HISTORY_ENTRIES);
+
+        let stats = history.stats();
+        assert_eq!(stats.raised, PET_HISTORY_ENTRIES as u32 + 5);
+        assert_eq!(stats.died_of(DeathCause::OldAge), stats.raised);
+        assert_eq!(stats.longest_lived.unwrap().upid, 2);
     }
 }
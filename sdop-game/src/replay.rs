@@ -0,0 +1,274 @@
+extern crate std;
+
+use core::time::Duration;
+use std::vec::Vec;
+
+use bincode::{
+    Decode, Encode,
+    error::{DecodeError, EncodeError},
+};
+
+use crate::{ButtonState, ButtonStates, Game, SaveFile, Timestamp, input::Input};
+
+const REPLAY_MAGIC: [u8; 4] = *b"SDRP";
+const REPLAY_VERSION: u16 = 1;
+const REPLAY_HEADER_SIZE: usize = REPLAY_MAGIC.len() + size_of::<u16>();
+
+const PLAYING_SONG_BIT: u8 = 1 << 6;
+
+fn pack_buttons(states: &ButtonStates) -> u8 {
+    states
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, state)| match state {
+            ButtonState::Down => acc | (1 << i),
+            ButtonState::Up => acc,
+        })
+}
+
+fn unpack_buttons(bits: u8) -> ButtonStates {
+    core::array::from_fn(|i| {
+        if bits & (1 << i) != 0 {
+            ButtonState::Down
+        } else {
+            ButtonState::Up
+        }
+    })
+}
+
+/// Everything fed into a single `Game::tick`
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq)]
+pub struct ReplayFrame {
+    delta_nanos: u64,
+    // Current buttons in bits 0-2, last frame's in 3-5, then if a song is playing
+    flags: u8,
+    // Only set when changed since the last frame
+    temperature: Option<f32>,
+    time_scale: Option<f32>,
+}
+
+impl ReplayFrame {
+    pub fn delta(&self) -> Duration {
+        Duration::from_nanos(self.delta_nanos)
+    }
+
+    pub fn states(&self) -> ButtonStates {
+        unpack_buttons(self.flags)
+    }
+
+    fn last_states(&self) -> ButtonStates {
+        unpack_buttons(self.flags >> 3)
+    }
+
+    fn playing_song(&self) -> bool {
+        self.flags & PLAYING_SONG_BIT != 0
+    }
+}
+
+/// A recorded session, the starting state plus every tick after it
+#[derive(Encode, Decode, Clone, Default)]
+pub struct Replay {
+    pub start: Timestamp,
+    pub save: Option<Vec<u8>>,
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl Replay {
+    pub fn new(start: Timestamp, save: Option<&SaveFile>) -> Result<Self, EncodeError> {
+        Ok(Self {
+            start,
+            save: save.map(|save| save.to_bytes()).transpose()?.map(Vec::from),
+            frames: Vec::new(),
+        })
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(ReplayFrame::delta).sum()
+    }
+
+    /// The game as it was when recording started
+    pub fn new_game(&self) -> Result<Game, DecodeError> {
+        let save = self.save.as_deref().map(SaveFile::from_bytes).transpose()?;
+        Ok(Game::start(self.start, save))
+    }
+
+    /// Plays every frame, returning the game at the end of the recording
+    pub fn play(&self) -> Result<Game, DecodeError> {
+        let mut game = self.new_game()?;
+        for frame in &self.frames {
+            game.replay_frame(frame);
+        }
+        Ok(game)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut result = Vec::from(REPLAY_MAGIC);
+        result.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        result.extend(bincode::encode_to_vec(self, bincode::config::standard())?);
+        Ok(result)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < REPLAY_HEADER_SIZE || bytes[..REPLAY_MAGIC.len()] != REPLAY_MAGIC {
+            return Err(DecodeError::Other("not a replay"));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != REPLAY_VERSION {
+            return Err(DecodeError::Other("unsupported replay version"));
+        }
+
+        let (result, _) =
+            bincode::decode_from_slice(&bytes[REPLAY_HEADER_SIZE..], bincode::config::standard())?;
+        Ok(result)
+    }
+}
+
+pub(crate) struct Recorder {
+    replay: Replay,
+    temperature: Option<f32>,
+    time_scale: Option<f32>,
+}
+
+impl Recorder {
+    fn push(&mut self, delta: Duration, input: &Input, time_scale: f32, playing_song: bool) {
+        let temperature = input.temperature();
+        let mut flags = pack_buttons(&input.states()) | (pack_buttons(&input.last_states()) << 3);
+        if playing_song {
+            flags |= PLAYING_SONG_BIT;
+        }
+
+        self.replay.frames.push(ReplayFrame {
+            delta_nanos: delta.as_nanos() as u64,
+            flags,
+            temperature: temperature.filter(|_| self.temperature != temperature),
+            time_scale: (self.time_scale != Some(time_scale)).then_some(time_scale),
+        });
+        self.temperature = temperature;
+        self.time_scale = Some(time_scale);
+    }
+}
+
+impl Game {
+    /// Same as `Game::start` but every tick is recorded so the session can be replayed
+    pub fn recorded(start: Timestamp, save: Option<SaveFile>) -> Result<Self, EncodeError> {
+        let replay = Replay::new(start, save.as_ref())?;
+        let mut result = Game::start(start, save);
+        result.recorder = Some(Recorder {
+            replay,
+            temperature: None,
+            time_scale: None,
+        });
+        Ok(result)
+    }
+
+    pub fn recording(&self) -> Option<&Replay> {
+        self.recorder.as_ref().map(|recorder| &recorder.replay)
+    }
+
+    pub(crate) fn record_tick(&mut self, delta: Duration) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push(
+                delta,
+                &self.input,
+                self.time_scale,
+                self.game_ctx.sound_system.get_playing(),
+            );
+        }
+    }
+
+    /// Feeds a recorded frame through `tick` and `refresh_display`
+    pub fn replay_frame(&mut self, frame: &ReplayFrame) {
+        if let Some(temperature) = frame.temperature {
+            self.input.update_temperature(temperature);
+        }
+        if let Some(time_scale) = frame.time_scale {
+            self.time_scale = time_scale;
+        }
+        self.set_playing_song(frame.playing_song());
+        self.input.update_state(frame.last_states());
+        self.input.update_state(frame.states());
+
+        self.tick(frame.delta());
+        self.refresh_display(frame.delta());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Button;
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    const START: Timestamp = Timestamp::new(NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+        NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+    ));
+
+    fn drive(game: &mut Game) {
+        let delta = Duration::from_millis(33);
+        for frame in 0..2000 {
+            let mut states = [ButtonState::Up; 3];
+            // Mash through the new pet name entry then poke around home
+            if frame % 7 == 0 {
+                states[[Button::Middle, Button::Left, Button::Right][frame / 7 % 3].index()] =
+                    ButtonState::Down;
+            }
+            if frame == 500 {
+                game.update_temperature(30.);
+                game.set_sim_time_scale(20.);
+            }
+            game.update_input_states(states);
+            game.tick(delta);
+            game.refresh_display(delta);
+        }
+    }
+
+    #[test]
+    fn test_replay_matches_recording() {
+        let mut game = Game::recorded(START, None).unwrap();
+        drive(&mut game);
+        let bytes = game.recording().unwrap().to_bytes().unwrap();
+
+        let replay = Replay::from_bytes(&bytes).unwrap();
+        assert_eq!(replay.frames.len(), 2000);
+        let replayed = replay.play().unwrap();
+
+        assert_eq!(replayed.get_time(), game.get_time());
+        assert_eq!(replayed.get_display_bmp(), game.get_display_bmp());
+        assert_eq!(
+            SaveFile::generate(game.get_time(), &replayed.game_ctx)
+                .to_bytes()
+                .unwrap(),
+            SaveFile::generate(game.get_time(), &game.game_ctx)
+                .to_bytes()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_replay_from_save() {
+        let mut source = Game::blank(Some(START));
+        drive(&mut source);
+        let save = SaveFile::generate(source.get_time(), &source.game_ctx);
+
+        let later = source.get_time() + Duration::from_hours(3);
+        let mut game = Game::recorded(later, Some(save)).unwrap();
+        drive(&mut game);
+
+        let replayed = game.recording().unwrap().play().unwrap();
+        assert_eq!(replayed.get_display_bmp(), game.get_display_bmp());
+        assert_eq!(
+            SaveFile::generate(game.get_time(), &replayed.game_ctx)
+                .to_bytes()
+                .unwrap(),
+            SaveFile::generate(game.get_time(), &game.game_ctx)
+                .to_bytes()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rejects_garbage() {
+        assert!(Replay::from_bytes(b"SDOP\x01\x00").is_err());
+    }
+}
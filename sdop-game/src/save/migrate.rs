@@ -0,0 +1,1442 @@
+use core::time::Duration;
+
+use bincode::{Decode, error::DecodeError};
+
+use crate::{
+    Timestamp,
+    achievements::Achievements,
+    alarm::AlarmConfig,
+    book::BookHistory,
+    cooking::Kitchen,
+    death::DeathCause,
+    egg::SavedEgg,
+    explore::{
+        DailyWins, ExploreHistory, ExploreSkill, ExploreSystemSave, LOCATION_COUNT, Loadout,
+        LocationHistory, MAX_EXPLORE_STAGES, StageEvent,
+    },
+    fish_tank::HomeFishTank,
+    food::{FOOD_COUNT, FoodHistory},
+    furniture::HomeLayout,
+    game_consts::{STARTING_FOOD_STOCK, VITALS_SAMPLE_INTERVAL},
+    household::{HouseholdPetSave, HouseholdSave, HouseholdTimers, MAX_OTHERS},
+    items::{Inventory, InventoryEntry, ItemKind},
+    lights::Lights,
+    money::{Ledger, Money},
+    pantry::{FoodStock, Pantry},
+    pet::{
+        LifeStageHistory, Mood, PetIllness, PetInstance, PetName, PetParents, StomachMood,
+        UniquePetId,
+        definition::PetDefinitionId,
+        record::{PET_HISTORY_ENTRIES, PetHistory, PetRecord},
+    },
+    poop::{MAX_POOPS, Poop},
+    save::{BINCODE_CONFIG, SaveFile},
+    shop::Shop,
+    sounds::SoundOptions,
+    suiter::SuiterSystem,
+    vitals::{VITALS_ENTRIES, VitalsLog, VitalsSample},
+};
+
+// Every layout that has shipped keeps an arm here. When the layout changes bump
+// SAVE_VERSION, freeze the old layout as its own struct, convert it forward into
+// the next version and add an upgrade step so old saves walk up to the current one.
+pub(super) fn decode(version: u16, payload: &[u8]) -> Result<SaveFile, DecodeError> {
+    match version {
+        // Version 0 is the bare payload from before the envelope, the layout is otherwise unchanged
+        0 | 1 => decode_payload(payload).map(upgrade_v1),
+        2 => decode_payload(payload).map(upgrade_v2),
+        3 => decode_payload(payload).map(upgrade_v3),
+        4 => decode_payload(payload).map(upgrade_v4),
+        5 => decode_payload(payload).map(upgrade_v5),
+        6 => decode_payload(payload).map(upgrade_v6),
+        7 => decode_payload(payload).map(upgrade_v7),
+        8 => decode_payload(payload).map(upgrade_v8),
+        9 => decode_payload(payload).map(upgrade_v9),
+        10 => decode_payload(payload).map(upgrade_v10),
+        11 => decode_payload(payload).map(upgrade_v11),
+        12 => decode_payload(payload).map(upgrade_v12),
+        13 => decode_payload(payload).map(upgrade_v13),
+        14 => decode_payload(payload).map(upgrade_v14),
+        15 => decode_payload(payload).map(upgrade_v15),
+        16 => decode_payload(payload).map(upgrade_v16),
+        17 => decode_payload(payload),
+        _ => Err(DecodeError::Other("save is from a newer game")),
+    }
+}
+
+fn decode_payload<T: Decode<()>>(payload: &[u8]) -> Result<T, DecodeError> {
+    let (save, _): (T, usize) = bincode::decode_from_slice(payload, BINCODE_CONFIG)?;
+    Ok(save)
+}
+
+// A step per layout keeps only a couple of them on the stack at once while walking up
+fn upgrade_v1(save: SaveFileV1) -> SaveFile {
+    upgrade_v2(save.into())
+}
+
+fn upgrade_v2(save: SaveFileV2) -> SaveFile {
+    upgrade_v3(save.into())
+}
+
+fn upgrade_v3(save: SaveFileV3) -> SaveFile {
+    upgrade_v4(save.into())
+}
+
+fn upgrade_v4(save: SaveFileV4) -> SaveFile {
+    upgrade_v5(save.into())
+}
+
+fn upgrade_v5(save: SaveFileV5) -> SaveFile {
+    upgrade_v6(save.into())
+}
+
+fn upgrade_v6(save: SaveFileV6) -> SaveFile {
+    upgrade_v7(save.into())
+}
+
+fn upgrade_v7(save: SaveFileV7) -> SaveFile {
+    upgrade_v8(save.into())
+}
+
+fn upgrade_v8(save: SaveFileV8) -> SaveFile {
+    upgrade_v9(save.into())
+}
+
+fn upgrade_v9(save: SaveFileV9) -> SaveFile {
+    upgrade_v10(save.into())
+}
+
+fn upgrade_v10(save: SaveFileV10) -> SaveFile {
+    upgrade_v11(save.into())
+}
+
+fn upgrade_v11(save: SaveFileV11) -> SaveFile {
+    upgrade_v12(save.into())
+}
+
+fn upgrade_v12(save: SaveFileV12) -> SaveFile {
+    upgrade_v13(save.into())
+}
+
+fn upgrade_v13(save: SaveFileV13) -> SaveFile {
+    upgrade_v14(save.into())
+}
+
+fn upgrade_v14(save: SaveFileV14) -> SaveFile {
+    upgrade_v15(save.into())
+}
+
+fn upgrade_v15(save: SaveFileV15) -> SaveFile {
+    upgrade_v16(save.into())
+}
+
+fn upgrade_v16(save: SaveFileV16) -> SaveFile {
+    save.into()
+}
+
+#[derive(Decode)]
+struct SaveFileV1 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistoryV2,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+}
+
+// Version 2 added the room lights
+impl From<SaveFileV1> for SaveFileV2 {
+    fn from(value: SaveFileV1) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: Default::default(),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct PetHistoryV2 {
+    top: usize,
+    entires: [Option<PetRecord>; PET_HISTORY_ENTRIES],
+}
+
+// Version 3 made the history a ring buffer with lifetime stats
+impl From<PetHistoryV2> for PetHistory {
+    fn from(value: PetHistoryV2) -> Self {
+        let mut result = PetHistory::default();
+        for entry in value.entires.iter().take(value.top).flatten() {
+            result.add(*entry);
+        }
+        result
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV2 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistoryV2,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+}
+
+impl From<SaveFileV2> for SaveFileV3 {
+    fn from(value: SaveFileV2) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records.into(),
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV3 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+}
+
+// Version 4 added the rest of the household
+impl From<SaveFileV3> for SaveFileV4 {
+    fn from(value: SaveFileV3) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: HouseholdSaveV9 {
+                pets: Default::default(),
+            },
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV4 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+    household: HouseholdSaveV9,
+}
+
+// Version 5 added achievements
+impl From<SaveFileV4> for SaveFileV5 {
+    fn from(value: SaveFileV4) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: Default::default(),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV5 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+    household: HouseholdSaveV9,
+    achievements: Achievements,
+}
+
+// Version 6 added the vitals log
+impl From<SaveFileV5> for SaveFileV6 {
+    fn from(value: SaveFileV5) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: Default::default(),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV6 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+    household: HouseholdSaveV9,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+}
+
+// Version 7 added the money ledger
+impl From<SaveFileV6> for SaveFileV7 {
+    fn from(value: SaveFileV6) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: Default::default(),
+        }
+    }
+}
+
+// Items up to version 7, the stove went in before the maps pushing them and
+// the recipes along one
+const ITEM_COUNT_V7: usize = 78;
+const FIRST_MOVED_ITEM_V7: usize = 46;
+
+#[derive(Decode)]
+struct InventoryV7 {
+    contents: [InventoryEntry; ITEM_COUNT_V7],
+}
+
+impl From<InventoryV7> for InventoryV8 {
+    fn from(value: InventoryV7) -> Self {
+        let mut contents = [InventoryEntry::default(); ITEM_COUNT_V8];
+        for (index, entry) in value.contents.into_iter().enumerate() {
+            let index = if index < FIRST_MOVED_ITEM_V7 {
+                index
+            } else {
+                index + 1
+            };
+            contents[index] = entry;
+        }
+        Self { contents }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV7 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV7,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+    household: HouseholdSaveV9,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+    ledger: Ledger,
+}
+
+// Version 8 added the stove and the kitchen
+impl From<SaveFileV7> for SaveFileV8 {
+    fn from(value: SaveFileV7) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory.into(),
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: Default::default(),
+        }
+    }
+}
+
+// Items up to version 8, the fridge went in after the stove
+const ITEM_COUNT_V8: usize = 79;
+const FIRST_MOVED_ITEM_V8: usize = 47;
+
+#[derive(Decode)]
+struct InventoryV8 {
+    contents: [InventoryEntry; ITEM_COUNT_V8],
+}
+
+impl From<InventoryV8> for InventoryV15 {
+    fn from(value: InventoryV8) -> Self {
+        let mut contents = [InventoryEntry::default(); ITEM_COUNT_V15];
+        for (index, entry) in value.contents.into_iter().enumerate() {
+            let index = if index < FIRST_MOVED_ITEM_V8 {
+                index
+            } else {
+                index + 1
+            };
+            contents[index] = entry;
+        }
+        Self { contents }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV8 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV8,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+    household: HouseholdSaveV9,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+    ledger: Ledger,
+    kitchen: KitchenV13,
+}
+
+// Version 9 added the pantry, stocked with what is on the menu so nobody goes hungry
+impl From<SaveFileV8> for SaveFileV9 {
+    fn from(value: SaveFileV8) -> Self {
+        let inventory: InventoryV15 = value.inventory.into();
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            pantry: Pantry::stocked(&inventory.into(), STARTING_FOOD_STOCK).into(),
+            inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct ExploreSystemSaveV9 {
+    current: Option<usize>,
+    elapsed: Duration,
+    passes: u32,
+}
+
+impl From<ExploreSystemSaveV9> for ExploreSystemSaveV10 {
+    fn from(value: ExploreSystemSaveV9) -> Self {
+        Self {
+            current: value.current,
+            elapsed: value.elapsed,
+            passes: value.passes,
+            stage: None,
+            events: Default::default(),
+            injured: None,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct HouseholdPetSaveV9 {
+    pet: PetInstanceV15,
+    explore: ExploreSystemSaveV9,
+}
+
+#[derive(Decode)]
+struct HouseholdSaveV9 {
+    pets: [Option<HouseholdPetSaveV9>; MAX_OTHERS],
+}
+
+impl From<HouseholdSaveV9> for HouseholdSaveV10 {
+    fn from(value: HouseholdSaveV9) -> Self {
+        Self {
+            pets: value.pets.map(|saved| {
+                saved.map(|saved| HouseholdPetSaveV10 {
+                    pet: saved.pet,
+                    explore: saved.explore.into(),
+                })
+            }),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV9 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV9,
+    lights: Lights,
+    household: HouseholdSaveV9,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+    ledger: Ledger,
+    kitchen: KitchenV13,
+    pantry: PantryV14,
+}
+
+// Version 10 added expedition stages and events
+impl From<SaveFileV9> for SaveFileV10 {
+    fn from(value: SaveFileV9) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save.into(),
+            lights: value.lights,
+            household: value.household.into(),
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct ExploreSystemSaveV10 {
+    current: Option<usize>,
+    elapsed: Duration,
+    passes: u32,
+    stage: Option<u8>,
+    events: [Option<StageEvent>; MAX_EXPLORE_STAGES],
+    injured: Option<(UniquePetId, Timestamp)>,
+}
+
+impl From<ExploreSystemSaveV10> for ExploreSystemSaveV11 {
+    fn from(value: ExploreSystemSaveV10) -> Self {
+        Self {
+            current: value.current,
+            elapsed: value.elapsed,
+            passes: value.passes,
+            stage: value.stage,
+            events: value.events,
+            injured: value.injured,
+            wins: Default::default(),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct HouseholdPetSaveV10 {
+    pet: PetInstanceV15,
+    explore: ExploreSystemSaveV10,
+}
+
+#[derive(Decode)]
+struct HouseholdSaveV10 {
+    pets: [Option<HouseholdPetSaveV10>; MAX_OTHERS],
+}
+
+impl From<HouseholdSaveV10> for HouseholdSaveV11 {
+    fn from(value: HouseholdSaveV10) -> Self {
+        Self {
+            pets: value.pets.map(|saved| {
+                saved.map(|saved| HouseholdPetSaveV11 {
+                    pet: saved.pet,
+                    explore: saved.explore.into(),
+                })
+            }),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV10 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV10,
+    lights: Lights,
+    household: HouseholdSaveV10,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+    ledger: Ledger,
+    kitchen: KitchenV13,
+    pantry: PantryV14,
+}
+
+// Version 11 added daily wins for each location
+impl From<SaveFileV10> for SaveFileV11 {
+    fn from(value: SaveFileV10) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save.into(),
+            lights: value.lights,
+            household: value.household.into(),
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct ExploreSystemSaveV11 {
+    current: Option<usize>,
+    elapsed: Duration,
+    passes: u32,
+    stage: Option<u8>,
+    events: [Option<StageEvent>; MAX_EXPLORE_STAGES],
+    injured: Option<(UniquePetId, Timestamp)>,
+    wins: [DailyWins; LOCATION_COUNT_V15],
+}
+
+impl From<ExploreSystemSaveV11> for ExploreSystemSaveV15 {
+    fn from(value: ExploreSystemSaveV11) -> Self {
+        Self {
+            current: value.current,
+            elapsed: value.elapsed,
+            passes: value.passes,
+            stage: value.stage,
+            events: value.events,
+            injured: value.injured,
+            wins: value.wins,
+            loadout: Default::default(),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct HouseholdPetSaveV11 {
+    pet: PetInstanceV15,
+    explore: ExploreSystemSaveV11,
+}
+
+#[derive(Decode)]
+struct HouseholdSaveV11 {
+    pets: [Option<HouseholdPetSaveV11>; MAX_OTHERS],
+}
+
+impl From<HouseholdSaveV11> for HouseholdSaveV15 {
+    fn from(value: HouseholdSaveV11) -> Self {
+        Self {
+            pets: value.pets.map(|saved| {
+                saved.map(|saved| HouseholdPetSaveV15 {
+                    pet: saved.pet,
+                    explore: saved.explore.into(),
+                })
+            }),
+        }
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV11 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV11,
+    lights: Lights,
+    household: HouseholdSaveV11,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+    ledger: Ledger,
+    kitchen: KitchenV13,
+    pantry: PantryV14,
+}
+
+// Version 12 added packing a loadout for expeditions
+impl From<SaveFileV11> for SaveFileV12 {
+    fn from(value: SaveFileV11) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save.into(),
+            lights: value.lights,
+            household: value.household.into(),
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry,
+        }
+    }
+}
+
+// Before version 13 only the newest sample's time was kept
+#[derive(Decode)]
+struct VitalsLogV12 {
+    top: usize,
+    entries: [VitalsSample; VITALS_ENTRIES],
+    last_sample: Timestamp,
+    since_sample: Duration,
+}
+
+impl Default for VitalsLogV12 {
+    fn default() -> Self {
+        Self {
+            top: 0,
+            entries: [VitalsSample::default(); VITALS_ENTRIES],
+            last_sample: Timestamp::default(),
+            since_sample: Duration::ZERO,
+        }
+    }
+}
+
+impl VitalsLogV12 {
+    fn into_log(self, upid: UniquePetId) -> VitalsLog {
+        let count = self.top.min(VITALS_ENTRIES);
+        let oldest = self.top - count;
+        let samples = (0..count).map(|index| {
+            let from_latest = (count - 1 - index) as u32;
+            (
+                self.last_sample - VITALS_SAMPLE_INTERVAL * from_latest,
+                self.entries[(oldest + index) % VITALS_ENTRIES],
+            )
+        });
+        VitalsLog::from_samples(upid, self.since_sample, samples)
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV12 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV15,
+    lights: Lights,
+    household: HouseholdSaveV15,
+    achievements: Achievements,
+    vitals: VitalsLogV12,
+    ledger: Ledger,
+    kitchen: KitchenV13,
+    pantry: PantryV14,
+}
+
+// Version 13 kept the time of every vitals sample and which pet they belong to
+impl From<SaveFileV12> for SaveFileV13 {
+    fn from(value: SaveFileV12) -> Self {
+        let upid = value.pet.upid;
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: value.vitals.into_log(upid),
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry,
+        }
+    }
+}
+
+// Before version 14 only the last pet served had a treat
+#[derive(Decode, Default)]
+struct KitchenV13 {
+    cooked: [u16; FOOD_COUNT],
+    treat: Option<(UniquePetId, Timestamp)>,
+}
+
+impl From<KitchenV13> for Kitchen {
+    fn from(value: KitchenV13) -> Self {
+        let mut treats = HouseholdTimers::default();
+        if let Some((upid, until)) = value.treat {
+            treats.start(upid, until);
+        }
+        Kitchen::new(value.cooked, treats)
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV13 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV15,
+    lights: Lights,
+    household: HouseholdSaveV15,
+    achievements: Achievements,
+    vitals: VitalsLog,
+    ledger: Ledger,
+    kitchen: KitchenV13,
+    pantry: PantryV14,
+}
+
+// Version 14 gave each pet in the household their own cooked treat
+impl From<SaveFileV13> for SaveFileV14 {
+    fn from(value: SaveFileV13) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen.into(),
+            pantry: value.pantry,
+        }
+    }
+}
+
+// Before version 15 only the last pet to eat spoiled food was upset
+#[derive(Decode)]
+struct PantryV14 {
+    stock: [FoodStock; FOOD_COUNT],
+    upset: Option<(UniquePetId, Timestamp)>,
+}
+
+impl From<Pantry> for PantryV14 {
+    fn from(value: Pantry) -> Self {
+        Self {
+            stock: value.stock,
+            upset: None,
+        }
+    }
+}
+
+impl From<PantryV14> for Pantry {
+    fn from(value: PantryV14) -> Self {
+        let mut upset = HouseholdTimers::default();
+        if let Some((upid, until)) = value.upset {
+            upset.start(upid, until);
+        }
+        Pantry::new(value.stock, upset)
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV14 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV15,
+    lights: Lights,
+    household: HouseholdSaveV15,
+    achievements: Achievements,
+    vitals: VitalsLog,
+    ledger: Ledger,
+    kitchen: Kitchen,
+    pantry: PantryV14,
+}
+
+// Version 15 gave each pet in the household their own upset stomach
+impl From<SaveFileV14> for SaveFileV15 {
+    fn from(value: SaveFileV14) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household,
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry.into(),
+        }
+    }
+}
+
+// Up to version 15 there was room for the locations before the hidden ones
+const LOCATION_COUNT_V15: usize = 14;
+
+#[derive(Decode)]
+struct ExploreHistoryV15 {
+    location_history: [LocationHistory; LOCATION_COUNT_V15],
+    bonus_skill: ExploreSkill,
+}
+
+impl From<ExploreHistoryV15> for ExploreHistory {
+    fn from(value: ExploreHistoryV15) -> Self {
+        let mut location_history = [LocationHistory::default(); LOCATION_COUNT];
+        location_history[..LOCATION_COUNT_V15].copy_from_slice(&value.location_history);
+        Self {
+            location_history,
+            bonus_skill: value.bonus_skill,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct PetInstanceV15 {
+    upid: UniquePetId,
+    def_id: PetDefinitionId,
+    name: PetName,
+    born: Timestamp,
+    age: Duration,
+    life_stage_age: Duration,
+    stomach_mood: StomachMood,
+    total_starve_time: Duration,
+    stomach_filled: f32,
+    extra_weight: f32,
+    until_poop: Duration,
+    since_game: Duration,
+    since_death_check: Duration,
+    since_evolve_check: Duration,
+    should_die: Option<DeathCause>,
+    should_evolve: Option<PetDefinitionId>,
+    parents: Option<PetParents>,
+    life_stage_history: LifeStageHistory,
+    mood: Mood,
+    should_breed: bool,
+    book_history: BookHistory,
+    illness: PetIllness,
+    total_cold_for: Duration,
+    cold_for: Duration,
+    total_hot_for: Duration,
+    seen_alien: bool,
+    explore: ExploreHistoryV15,
+    food_history: FoodHistory,
+    is_sleeping: bool,
+}
+
+impl From<PetInstanceV15> for PetInstance {
+    fn from(value: PetInstanceV15) -> Self {
+        Self {
+            upid: value.upid,
+            def_id: value.def_id,
+            name: value.name,
+            born: value.born,
+            age: value.age,
+            life_stage_age: value.life_stage_age,
+            stomach_mood: value.stomach_mood,
+            total_starve_time: value.total_starve_time,
+            stomach_filled: value.stomach_filled,
+            extra_weight: value.extra_weight,
+            until_poop: value.until_poop,
+            since_game: value.since_game,
+            since_death_check: value.since_death_check,
+            since_evolve_check: value.since_evolve_check,
+            should_die: value.should_die,
+            should_evolve: value.should_evolve,
+            parents: value.parents,
+            life_stage_history: value.life_stage_history,
+            mood: value.mood,
+            should_breed: value.should_breed,
+            book_history: value.book_history,
+            illness: value.illness,
+            total_cold_for: value.total_cold_for,
+            cold_for: value.cold_for,
+            total_hot_for: value.total_hot_for,
+            seen_alien: value.seen_alien,
+            explore: value.explore.into(),
+            food_history: value.food_history,
+            is_sleeping: value.is_sleeping,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct ExploreSystemSaveV15 {
+    current: Option<usize>,
+    elapsed: Duration,
+    passes: u32,
+    stage: Option<u8>,
+    events: [Option<StageEvent>; MAX_EXPLORE_STAGES],
+    injured: Option<(UniquePetId, Timestamp)>,
+    wins: [DailyWins; LOCATION_COUNT_V15],
+    loadout: Loadout,
+}
+
+impl From<ExploreSystemSaveV15> for ExploreSystemSave {
+    fn from(value: ExploreSystemSaveV15) -> Self {
+        let mut wins = [DailyWins::default(); LOCATION_COUNT];
+        wins[..LOCATION_COUNT_V15].copy_from_slice(&value.wins);
+        ExploreSystemSave::new(value.current, value.elapsed, value.passes)
+            .with_stages(value.stage, value.events)
+            .with_injured(value.injured)
+            .with_wins(wins)
+            .with_loadout(value.loadout)
+    }
+}
+
+#[derive(Decode)]
+struct HouseholdPetSaveV15 {
+    pet: PetInstanceV15,
+    explore: ExploreSystemSaveV15,
+}
+
+#[derive(Decode)]
+struct HouseholdSaveV15 {
+    pets: [Option<HouseholdPetSaveV15>; MAX_OTHERS],
+}
+
+impl From<HouseholdSaveV15> for HouseholdSaveV16 {
+    fn from(value: HouseholdSaveV15) -> Self {
+        Self {
+            pets: value.pets.map(|saved| {
+                saved.map(|saved| HouseholdPetSaveV16 {
+                    pet: saved.pet.into(),
+                    explore: saved.explore.into(),
+                })
+            }),
+        }
+    }
+}
+
+// Items up to version 15, the hidden maps went in after the other maps pushing
+// the recipes along two
+const ITEM_COUNT_V15: usize = 80;
+const FIRST_MOVED_ITEM_V15: usize = 61;
+
+#[derive(Decode, Clone, Copy)]
+struct InventoryV15 {
+    contents: [InventoryEntry; ITEM_COUNT_V15],
+}
+
+impl From<InventoryV15> for Inventory {
+    fn from(value: InventoryV15) -> Self {
+        let mut result = Inventory::default();
+        for (index, entry) in value.contents.into_iter().enumerate() {
+            let index = if index < FIRST_MOVED_ITEM_V15 {
+                index
+            } else {
+                index + 2
+            };
+            if let Some(item) = ItemKind::from_repr(index) {
+                *result.get_entry_mut(item) = entry;
+            }
+        }
+        result
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV15 {
+    pet: PetInstanceV15,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: InventoryV15,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSaveV15,
+    lights: Lights,
+    household: HouseholdSaveV15,
+    achievements: Achievements,
+    vitals: VitalsLog,
+    ledger: Ledger,
+    kitchen: Kitchen,
+    pantry: Pantry,
+}
+
+// Version 16 added the hidden locations along with their maps
+impl From<SaveFileV15> for SaveFileV16 {
+    fn from(value: SaveFileV15) -> Self {
+        Self {
+            pet: value.pet.into(),
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory.into(),
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save.into(),
+            lights: value.lights,
+            household: value.household.into(),
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry,
+        }
+    }
+}
+
+#[derive(Decode)]
+struct HouseholdPetSaveV16 {
+    pet: PetInstance,
+    explore: ExploreSystemSave,
+}
+
+#[derive(Decode)]
+struct HouseholdSaveV16 {
+    pets: [Option<HouseholdPetSaveV16>; MAX_OTHERS],
+}
+
+impl From<HouseholdSaveV16> for HouseholdSave {
+    fn from(value: HouseholdSaveV16) -> Self {
+        HouseholdSave::new(value.pets.map(|saved| {
+            saved.map(|saved| HouseholdPetSave::new(saved.pet, saved.explore, VitalsLog::default()))
+        }))
+    }
+}
+
+#[derive(Decode)]
+struct SaveFileV16 {
+    pet: PetInstance,
+    poops: [Option<Poop>; MAX_POOPS],
+    money: Money,
+    inventory: Inventory,
+    shop: Shop,
+    pet_records: PetHistory,
+    fish_tank: HomeFishTank,
+    home_layout: HomeLayout,
+    last_timestamp: Timestamp,
+    egg: Option<SavedEgg>,
+    suiter_system: SuiterSystem,
+    sim_rng_seed: u64,
+    alarm: AlarmConfig,
+    sound: SoundOptions,
+    explore_save: ExploreSystemSave,
+    lights: Lights,
+    household: HouseholdSaveV16,
+    achievements: Achievements,
+    vitals: VitalsLog,
+    ledger: Ledger,
+    kitchen: Kitchen,
+    pantry: Pantry,
+}
+
+// Version 17 gave every pet in the household its own vitals log
+impl From<SaveFileV16> for SaveFile {
+    fn from(value: SaveFileV16) -> Self {
+        Self {
+            pet: value.pet,
+            poops: value.poops,
+            money: value.money,
+            inventory: value.inventory,
+            shop: value.shop,
+            pet_records: value.pet_records,
+            fish_tank: value.fish_tank,
+            home_layout: value.home_layout,
+            last_timestamp: value.last_timestamp,
+            egg: value.egg,
+            suiter_system: value.suiter_system,
+            sim_rng_seed: value.sim_rng_seed,
+            alarm: value.alarm,
+            sound: value.sound,
+            explore_save: value.explore_save,
+            lights: value.lights,
+            household: value.household.into(),
+            achievements: value.achievements,
+            vitals: value.vitals,
+            ledger: value.ledger,
+            kitchen: value.kitchen,
+            pantry: value.pantry,
+        }
+    }
+}
@@ -0,0 +1,428 @@
+use bincode::{
+    Decode, Encode,
+    error::{DecodeError, EncodeError},
+};
+
+use crate::{
+    Game, Timestamp,
+    achievements::Achievements,
+    alarm::{AlarmConfig, AlarmState},
+    cooking::Kitchen,
+    egg::SavedEgg,
+    explore::ExploreSystemSave,
+    fish_tank::HomeFishTank,
+    furniture::HomeLayout,
+    game_context::GameContext,
+    household::HouseholdSave,
+    items::Inventory,
+    lights::Lights,
+    money::{Ledger, Money, Wallet},
+    pantry::Pantry,
+    pet::{PetInstance, record::PetHistory},
+    poop::{MAX_POOPS, Poop},
+    shop::Shop,
+    sounds::SoundOptions,
+    suiter::SuiterSystem,
+    vitals::VitalsLog,
+};
+
+mod migrate;
+pub mod storage;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Default)]
+pub struct SaveFile {
+    pub pet: PetInstance,
+    pub poops: [Option<Poop>; MAX_POOPS],
+    pub money: Money,
+    pub inventory: Inventory,
+    pub shop: Shop,
+    pub pet_records: PetHistory,
+    pub fish_tank: HomeFishTank,
+    pub home_layout: HomeLayout,
+    pub last_timestamp: Timestamp,
+    pub egg: Option<SavedEgg>,
+    pub suiter_system: SuiterSystem,
+    pub sim_rng_seed: u64,
+    pub alarm: AlarmConfig,
+    pub sound: SoundOptions,
+    pub explore_save: ExploreSystemSave,
+    pub lights: Lights,
+    pub household: HouseholdSave,
+    pub achievements: Achievements,
+    pub vitals: VitalsLog,
+    pub ledger: Ledger,
+    pub kitchen: Kitchen,
+    pub pantry: Pantry,
+}
+
+const BINCODE_CONFIG: bincode::config::Configuration = bincode::config::standard();
+
+pub const SAVE_MAGIC: [u8; 4] = *b"SDOP";
+pub const SAVE_VERSION: u16 = 17;
+const SAVE_HEADER_SIZE: usize = SAVE_MAGIC.len() + size_of::<u16>();
+
+pub const SAVE_SIZE: usize = SAVE_HEADER_SIZE + size_of::<SaveFile>();
+
+impl SaveFile {
+    pub fn generate(timestamp: Timestamp, game_ctx: &GameContext) -> Self {
+        Self {
+            pet: game_ctx.pet,
+            poops: game_ctx.poops,
+            money: game_ctx.wallet.balance(),
+            inventory: game_ctx.inventory,
+            shop: game_ctx.shop,
+            pet_records: game_ctx.pet_history,
+            fish_tank: game_ctx.home_fish_tank,
+            home_layout: game_ctx.home_layout,
+            egg: game_ctx.egg,
+            suiter_system: game_ctx.suiter_system,
+            last_timestamp: timestamp,
+            sim_rng_seed: game_ctx.sim_rng.get_seed(),
+            alarm: *game_ctx.alarm.config(),
+            sound: *game_ctx.sound_system.sound_options(),
+            explore_save: game_ctx.explore_system.save(),
+            lights: game_ctx.lights,
+            household: game_ctx.household.save(),
+            achievements: game_ctx.achievements,
+            vitals: game_ctx.vitals,
+            ledger: *game_ctx.wallet.ledger(),
+            kitchen: game_ctx.kitchen,
+            pantry: game_ctx.pantry,
+        }
+    }
+
+    pub fn load(self, game_ctx: &mut GameContext) {
+        game_ctx.pet = self.pet;
+        game_ctx.wallet = Wallet::new(self.money, self.ledger);
+        game_ctx.poops = self.poops;
+        game_ctx.inventory = self.inventory;
+        game_ctx.shop = self.shop;
+        game_ctx.pet_history = self.pet_records;
+        game_ctx.home_fish_tank = self.fish_tank;
+        game_ctx.home_layout = self.home_layout;
+        game_ctx.suiter_system = self.suiter_system;
+        game_ctx.egg = self.egg;
+        game_ctx.sim_rng = fastrand::Rng::with_seed(self.sim_rng_seed);
+        game_ctx.alarm = AlarmState::new(self.alarm);
+        game_ctx.sound_system.set_sound_options(self.sound);
+        game_ctx.explore_system = self.explore_save.into();
+        game_ctx.lights = self.lights;
+        game_ctx.household = self.household.into();
+        game_ctx.achievements = self.achievements;
+        game_ctx.vitals = self.vitals;
+        game_ctx.kitchen = self.kitchen;
+        game_ctx.pantry = self.pantry;
+    }
+
+    pub const fn size() -> usize {
+        SAVE_SIZE
+    }
+
+    /// Saves written before the envelope existed have no magic, they are treated as version 0
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (version, payload) = match bytes.strip_prefix(&SAVE_MAGIC) {
+            Some([lo, hi, payload @ ..]) => (u16::from_le_bytes([*lo, *hi]), payload),
+            Some(_) => return Err(DecodeError::UnexpectedEnd { additional: 2 }),
+            None => (0, bytes),
+        };
+
+        migrate::decode(version, payload)
+    }
+
+    pub fn load_from_bytes(
+        bytes: &[u8],
+        timestamp: Timestamp,
+        game: &mut Game,
+    ) -> Result<(), DecodeError> {
+        let save = Self::from_bytes(bytes)?;
+        game.load_save(timestamp, save);
+        Ok(())
+    }
+
+    fn write_to_slice(&self, bytes: &mut [u8]) -> Result<usize, EncodeError> {
+        if bytes.len() < SAVE_HEADER_SIZE {
+            return Err(EncodeError::UnexpectedEnd);
+        }
+
+        let (header, payload) = bytes.split_at_mut(SAVE_HEADER_SIZE);
+        header[..SAVE_MAGIC.len()].copy_from_slice(&SAVE_MAGIC);
+        header[SAVE_MAGIC.len()..].copy_from_slice(&SAVE_VERSION.to_le_bytes());
+        let written = bincode::encode_into_slice(self, payload, BINCODE_CONFIG)?;
+        Ok(SAVE_HEADER_SIZE + written)
+    }
+
+    pub fn to_bytes(&self) -> Result<[u8; SAVE_SIZE], EncodeError> {
+        let mut result = [0; SAVE_SIZE];
+        self.write_to_slice(&mut result)?;
+        Ok(result)
+    }
+
+    fn save_to_bytes(
+        bytes: &mut [u8],
+        timestamp: Timestamp,
+        game: &Game,
+    ) -> Result<(), EncodeError> {
+        let save_file = Self::generate(timestamp, &game.game_ctx);
+        save_file.write_to_slice(bytes)?;
+        Ok(())
+    }
+
+    pub fn gen_save_bytes(
+        timestamp: Timestamp,
+        game: &Game,
+    ) -> Option<Result<[u8; Self::size()], EncodeError>> {
+        if !game.game_ctx.should_save {
+            return None;
+        }
+
+        let mut bytes = [0; Self::size()];
+        match Self::save_to_bytes(&mut bytes, timestamp, game) {
+            Ok(it) => it,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(Ok(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        explore::{ExploreSystem, LOCATIONS},
+        food::FOOD_BISCUIT,
+        game_consts::STARTING_FOOD_STOCK,
+        household::Household,
+        items::ItemKind,
+    };
+
+    const FIXTURE_V0: &[u8] = include_bytes!("../../fixtures/saves/v0.sav");
+    const FIXTURE_V1: &[u8] = include_bytes!("../../fixtures/saves/v1.sav");
+    const FIXTURE_V2: &[u8] = include_bytes!("../../fixtures/saves/v2.sav");
+    const FIXTURE_V3: &[u8] = include_bytes!("../../fixtures/saves/v3.sav");
+    const FIXTURE_V4: &[u8] = include_bytes!("../../fixtures/saves/v4.sav");
+    const FIXTURE_V5: &[u8] = include_bytes!("../../fixtures/saves/v5.sav");
+    const FIXTURE_V6: &[u8] = include_bytes!("../../fixtures/saves/v6.sav");
+    const FIXTURE_V7: &[u8] = include_bytes!("../../fixtures/saves/v7.sav");
+    const FIXTURE_V8: &[u8] = include_bytes!("../../fixtures/saves/v8.sav");
+    const FIXTURE_V9: &[u8] = include_bytes!("../../fixtures/saves/v9.sav");
+    const FIXTURE_V10: &[u8] = include_bytes!("../../fixtures/saves/v10.sav");
+    const FIXTURE_V11: &[u8] = include_bytes!("../../fixtures/saves/v11.sav");
+    const FIXTURE_V12: &[u8] = include_bytes!("../../fixtures/saves/v12.sav");
+    const FIXTURE_V13: &[u8] = include_bytes!("../../fixtures/saves/v13.sav");
+    const FIXTURE_V14: &[u8] = include_bytes!("../../fixtures/saves/v14.sav");
+    const FIXTURE_V15: &[u8] = include_bytes!("../../fixtures/saves/v15.sav");
+    const FIXTURE_V16: &[u8] = include_bytes!("../../fixtures/saves/v16.sav");
+    const FIXTURE_V17: &[u8] = include_bytes!("../../fixtures/saves/v17.sav");
+
+    #[test]
+    fn test_load_v0() {
+        let save = SaveFile::from_bytes(FIXTURE_V0).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.money, 1000);
+    }
+
+    #[test]
+    fn test_load_v1() {
+        assert_eq!(&FIXTURE_V1[..SAVE_MAGIC.len()], &SAVE_MAGIC);
+
+        let save = SaveFile::from_bytes(FIXTURE_V1).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.money, 1000);
+    }
+
+    #[test]
+    fn test_load_v2() {
+        let save = SaveFile::from_bytes(FIXTURE_V2).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert!(save.lights.is_on());
+    }
+
+    #[test]
+    fn test_load_v3() {
+        let save = SaveFile::from_bytes(FIXTURE_V3).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(
+            save.pet_records.stats().raised as usize,
+            save.pet_records.count()
+        );
+    }
+
+    #[test]
+    fn test_load_v4() {
+        let save = SaveFile::from_bytes(FIXTURE_V4).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(Household::from(save.household).len(), 1);
+    }
+
+    #[test]
+    fn test_load_v5() {
+        let save = SaveFile::from_bytes(FIXTURE_V5).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.achievements.unlocked_count(), 0);
+    }
+
+    #[test]
+    fn test_load_v6() {
+        let save = SaveFile::from_bytes(FIXTURE_V6).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.vitals.count(), 0);
+    }
+
+    #[test]
+    fn test_load_v7() {
+        let save = SaveFile::from_bytes(FIXTURE_V7).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.money, 1000);
+        assert_eq!(save.ledger.count(), 0);
+    }
+
+    #[test]
+    fn test_load_v8() {
+        let save = SaveFile::from_bytes(FIXTURE_V8).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.kitchen.total_cooked(), 0);
+        assert!(!save.inventory.has_item(ItemKind::Stove));
+        assert!(save.inventory.has_item(ItemKind::RecipeBiscuit));
+    }
+
+    #[test]
+    fn test_load_v9() {
+        let save = SaveFile::from_bytes(FIXTURE_V9).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert!(!save.inventory.has_item(ItemKind::Fridge));
+        assert!(save.inventory.has_item(ItemKind::RecipeBiscuit));
+        assert_eq!(save.pantry.count(&FOOD_BISCUIT), STARTING_FOOD_STOCK);
+    }
+
+    #[test]
+    fn test_load_v10() {
+        let save = SaveFile::from_bytes(FIXTURE_V10).unwrap();
+        let explore = ExploreSystem::from(save.explore_save);
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert!(!explore.currently_exploring());
+        assert!(explore.pending_event().is_none());
+    }
+
+    #[test]
+    fn test_load_v11() {
+        let save = SaveFile::from_bytes(FIXTURE_V11).unwrap();
+        let explore = ExploreSystem::from(save.explore_save);
+        let location = LOCATIONS[0];
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(
+            explore.wins_left(location, save.last_timestamp),
+            location.daily_wins
+        );
+    }
+
+    #[test]
+    fn test_load_v12() {
+        let save = SaveFile::from_bytes(FIXTURE_V12).unwrap();
+        let explore = ExploreSystem::from(save.explore_save);
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert!(explore.loadout().food().is_none());
+        assert!(explore.loadout().item().is_none());
+    }
+
+    #[test]
+    fn test_load_v13() {
+        let save = SaveFile::from_bytes(FIXTURE_V13).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.vitals.upid(), save.pet.upid);
+    }
+
+    #[test]
+    fn test_load_v14() {
+        let save = SaveFile::from_bytes(FIXTURE_V14).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert!(!save.kitchen.treating(save.pet.upid, save.last_timestamp));
+    }
+
+    #[test]
+    fn test_load_v15() {
+        let save = SaveFile::from_bytes(FIXTURE_V15).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert!(!save.pantry.upset(save.pet.upid, save.last_timestamp));
+    }
+
+    #[test]
+    fn test_load_v16() {
+        let save = SaveFile::from_bytes(FIXTURE_V16).unwrap();
+
+        assert!(save.inventory.has_item(ItemKind::RecipeBiscuit));
+        assert!(!save.inventory.has_item(ItemKind::MapTheBackLanes));
+    }
+
+    #[test]
+    fn test_load_v17() {
+        let save = SaveFile::from_bytes(FIXTURE_V17).unwrap();
+
+        assert_eq!(save.pet.upid, 0x2a6ef7347d);
+        assert_eq!(save.vitals.upid(), save.pet.upid);
+    }
+
+    #[test]
+    fn test_migrated_saves_match_current() {
+        // One at a time so only a single save is walked up on the stack
+        for fixture in [
+            FIXTURE_V0,
+            FIXTURE_V1,
+            FIXTURE_V2,
+            FIXTURE_V3,
+            FIXTURE_V4,
+            FIXTURE_V5,
+            FIXTURE_V6,
+            FIXTURE_V7,
+            FIXTURE_V8,
+            FIXTURE_V9,
+            FIXTURE_V10,
+            FIXTURE_V11,
+            FIXTURE_V12,
+            FIXTURE_V13,
+            FIXTURE_V14,
+            FIXTURE_V15,
+            FIXTURE_V16,
+        ] {
+            let migrated = SaveFile::from_bytes(fixture).unwrap().to_bytes().unwrap();
+            assert_eq!(&migrated[..], FIXTURE_V17);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let save = SaveFile::default();
+        let bytes = save.to_bytes().unwrap();
+
+        assert_eq!(&bytes[..SAVE_MAGIC.len()], &SAVE_MAGIC);
+        assert_eq!(
+            SaveFile::from_bytes(&bytes).unwrap().to_bytes().unwrap(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_rejects_newer_version() {
+        let mut bytes = SaveFile::default().to_bytes().unwrap();
+        bytes[SAVE_MAGIC.len()..SAVE_HEADER_SIZE]
+            .copy_from_slice(&(SAVE_VERSION + 1).to_le_bytes());
+
+        assert!(SaveFile::from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,378 @@
+use bincode::error::EncodeError;
+
+use crate::save::{SAVE_SIZE, SaveFile};
+
+// sequence, length, crc
+const SLOT_HEADER_SIZE: usize = size_of::<u32>() * 3;
+pub const SLOT_SIZE: usize = SLOT_HEADER_SIZE + SAVE_SIZE;
+pub const SLOT_COUNT: usize = 2;
+pub const STORAGE_SIZE: usize = SLOT_SIZE * SLOT_COUNT;
+
+/// Backing memory for saves, offsets are relative to the start of the save area
+pub trait SaveStorage {
+    type Error;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    fn write(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<T: SaveStorage + ?Sized> SaveStorage for &mut T {
+    type Error = T::Error;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).read(offset, buf)
+    }
+
+    fn write(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error> {
+        (**self).write(offset, data)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds;
+
+impl SaveStorage for [u8] {
+    type Error = OutOfBounds;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let src = self.get(offset..offset + buf.len()).ok_or(OutOfBounds)?;
+        buf.copy_from_slice(src);
+        Ok(())
+    }
+
+    fn write(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error> {
+        let dst = self
+            .get_mut(offset..offset + data.len())
+            .ok_or(OutOfBounds)?;
+        dst.copy_from_slice(data);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum StorageError<E> {
+    Storage(E),
+    Encode(EncodeError),
+}
+
+impl<E> From<EncodeError> for StorageError<E> {
+    fn from(value: EncodeError) -> Self {
+        Self::Encode(value)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct SlotHeader {
+    sequence: u32,
+    length: u32,
+    crc: u32,
+}
+
+impl SlotHeader {
+    fn from_bytes(bytes: &[u8; SLOT_HEADER_SIZE]) -> Self {
+        let word = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            sequence: word(0),
+            length: word(1),
+            crc: word(2),
+        }
+    }
+
+    fn to_bytes(self) -> [u8; SLOT_HEADER_SIZE] {
+        let mut result = [0; SLOT_HEADER_SIZE];
+        result[0..4].copy_from_slice(&self.sequence.to_le_bytes());
+        result[4..8].copy_from_slice(&self.length.to_le_bytes());
+        result[8..12].copy_from_slice(&self.crc.to_le_bytes());
+        result
+    }
+
+    fn checksum(sequence: u32, payload: &[u8]) -> u32 {
+        let crc = crc32_update(CRC32_INIT, &sequence.to_le_bytes());
+        let crc = crc32_update(crc, &(payload.len() as u32).to_le_bytes());
+        !crc32_update(crc, payload)
+    }
+}
+
+// A bare save from before slots existed sits over slot 0, so the first slot written
+// has to be the other one or a torn write would lose both
+const FIRST_SLOT: usize = 1;
+
+const fn slot_offset(slot: usize) -> usize {
+    slot * SLOT_SIZE
+}
+
+// Newer wins even once the sequence wraps around
+fn is_newer(a: u32, b: u32) -> bool {
+    (a.wrapping_sub(b) as i32) > 0
+}
+
+/// Writes saves alternately into two slots so a torn write only ever loses the
+/// slot being written, the other still holds the previous save.
+pub struct SlotStorage<S> {
+    storage: S,
+    sequences: [Option<u32>; SLOT_COUNT],
+}
+
+impl<S: SaveStorage> SlotStorage<S> {
+    pub fn open(storage: S) -> Result<Self, S::Error> {
+        let mut result = Self {
+            storage,
+            sequences: [None; SLOT_COUNT],
+        };
+
+        let mut buf = [0; SAVE_SIZE];
+        for slot in 0..SLOT_COUNT {
+            result.sequences[slot] = result.read_slot(slot, &mut buf)?.map(|(seq, _)| seq);
+        }
+
+        Ok(result)
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+
+    fn read_slot(
+        &mut self,
+        slot: usize,
+        buf: &mut [u8; SAVE_SIZE],
+    ) -> Result<Option<(u32, usize)>, S::Error> {
+        let mut header = [0; SLOT_HEADER_SIZE];
+        self.storage.read(slot_offset(slot), &mut header)?;
+        let header = SlotHeader::from_bytes(&header);
+
+        let length = header.length as usize;
+        if length == 0 || length > SAVE_SIZE {
+            return Ok(None);
+        }
+
+        self.storage
+            .read(slot_offset(slot) + SLOT_HEADER_SIZE, &mut buf[..length])?;
+        if SlotHeader::checksum(header.sequence, &buf[..length]) != header.crc {
+            return Ok(None);
+        }
+
+        Ok(Some((header.sequence, length)))
+    }
+
+    fn slots_newest_first(&self) -> [usize; SLOT_COUNT] {
+        match self.sequences {
+            [Some(a), Some(b)] if is_newer(b, a) => [1, 0],
+            [None, Some(_)] => [1, 0],
+            _ => [0, 1],
+        }
+    }
+
+    /// Loads the newest slot that passes its checksum, falling back to the older one.
+    /// Storage written before slots existed holds a bare save at the start, that is
+    /// only tried when neither slot is valid.
+    pub fn load(&mut self) -> Result<Option<SaveFile>, StorageError<S::Error>> {
+        let mut buf = [0; SAVE_SIZE];
+
+        for slot in self.slots_newest_first() {
+            if self.sequences[slot].is_none() {
+                continue;
+            }
+
+            let Some((_, length)) = self
+                .read_slot(slot, &mut buf)
+                .map_err(StorageError::Storage)?
+            else {
+                self.sequences[slot] = None;
+                continue;
+            };
+
+            match SaveFile::from_bytes(&buf[..length]) {
+                Ok(save) => return Ok(Some(save)),
+                Err(_) => self.sequences[slot] = None,
+            }
+        }
+
+        self.storage
+            .read(0, &mut buf)
+            .map_err(StorageError::Storage)?;
+        Ok(SaveFile::from_bytes(&buf).ok())
+    }
+
+    pub fn save(&mut self, save: &SaveFile) -> Result<(), StorageError<S::Error>> {
+        let [newest, oldest] = self.slots_newest_first();
+        let sequence = self.sequences[newest].map_or(0, |seq| seq.wrapping_add(1));
+        let slot = if self.sequences[newest].is_some() {
+            oldest
+        } else {
+            FIRST_SLOT
+        };
+
+        let mut buf = [0; SAVE_SIZE];
+        let length = save.write_to_slice(&mut buf)?;
+        let header = SlotHeader {
+            sequence,
+            length: length as u32,
+            crc: SlotHeader::checksum(sequence, &buf[..length]),
+        };
+
+        // Forget the slot until both writes land so a failed write is never picked as newest
+        self.sequences[slot] = None;
+        self.storage
+            .write(slot_offset(slot) + SLOT_HEADER_SIZE, &buf[..length])
+            .map_err(StorageError::Storage)?;
+        self.storage
+            .write(slot_offset(slot), &header.to_bytes())
+            .map_err(StorageError::Storage)?;
+        self.sequences[slot] = Some(sequence);
+
+        Ok(())
+    }
+}
+
+const CRC32_INIT: u32 = 0xFFFFFFFF;
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB88320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(crc, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_V0: &[u8] = include_bytes!("../../fixtures/saves/v0.sav");
+
+    fn save_with_money(money: i32) -> SaveFile {
+        SaveFile {
+            money,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(!crc32_update(CRC32_INIT, b"123456789"), 0xCBF43926);
+    }
+
+    #[test]
+    fn test_empty_storage() {
+        let mut memory = [0u8; STORAGE_SIZE];
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+
+        assert!(slots.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_loads_newest() {
+        let mut memory = [0u8; STORAGE_SIZE];
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        for money in 1..=3 {
+            slots.save(&save_with_money(money)).unwrap();
+        }
+
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        assert_eq!(slots.load().unwrap().unwrap().money, 3);
+    }
+
+    #[test]
+    fn test_falls_back_on_corrupt_slot() {
+        let mut memory = [0u8; STORAGE_SIZE];
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        slots.save(&save_with_money(1)).unwrap();
+        slots.save(&save_with_money(2)).unwrap();
+
+        // First save went into slot B so the second is in slot A, tear it
+        memory[slot_offset(0) + SLOT_HEADER_SIZE + 10] ^= 0xFF;
+
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        assert_eq!(slots.load().unwrap().unwrap().money, 1);
+
+        // The torn slot is the one that gets rewritten
+        slots.save(&save_with_money(3)).unwrap();
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        assert_eq!(slots.load().unwrap().unwrap().money, 3);
+        assert_eq!(slots.sequences, [Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn test_sequence_wraps() {
+        assert!(is_newer(0, u32::MAX));
+        assert!(!is_newer(u32::MAX, 0));
+    }
+
+    struct FailAfter<'a> {
+        memory: &'a mut [u8],
+        writes_left: usize,
+    }
+
+    impl SaveStorage for FailAfter<'_> {
+        type Error = OutOfBounds;
+
+        fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+            self.memory.read(offset, buf)
+        }
+
+        fn write(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error> {
+            if self.writes_left == 0 {
+                return Err(OutOfBounds);
+            }
+            self.writes_left -= 1;
+            self.memory.write(offset, data)
+        }
+    }
+
+    #[test]
+    fn test_torn_first_save_keeps_legacy() {
+        let mut memory = [0u8; STORAGE_SIZE];
+        memory[..FIXTURE_V0.len()].copy_from_slice(FIXTURE_V0);
+
+        // Payload lands, header write fails
+        let mut slots = SlotStorage::open(FailAfter {
+            memory: &mut memory[..],
+            writes_left: 1,
+        })
+        .unwrap();
+        assert!(slots.save(&save_with_money(1)).is_err());
+
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        assert_eq!(slots.load().unwrap().unwrap().money, 1000);
+
+        // Once a slot is valid the legacy area is free to reuse
+        slots.save(&save_with_money(2)).unwrap();
+        slots.save(&save_with_money(3)).unwrap();
+        assert_eq!(slots.sequences, [Some(1), Some(0)]);
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        assert_eq!(slots.load().unwrap().unwrap().money, 3);
+    }
+
+    #[test]
+    fn test_loads_legacy_bare_save() {
+        let mut memory = [0u8; STORAGE_SIZE];
+        memory[..FIXTURE_V0.len()].copy_from_slice(FIXTURE_V0);
+
+        let mut slots = SlotStorage::open(&mut memory[..]).unwrap();
+        assert_eq!(slots.load().unwrap().unwrap().money, 1000);
+    }
+}
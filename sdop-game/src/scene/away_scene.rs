@@ -0,0 +1,104 @@
+use fixedstr::str_format;
+use glam::IVec2;
+
+use crate::{
+    away::AwaySummary,
+    display::{CENTER_X_I32, ComplexRenderOption, GameDisplay},
+    fonts::FONT_VARIABLE_SMALL,
+    scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
+};
+
+pub struct AwayScene {
+    summary: AwaySummary,
+}
+
+impl AwayScene {
+    pub fn new(summary: AwaySummary) -> Self {
+        Self { summary }
+    }
+}
+
+impl Scene for AwayScene {
+    fn setup(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn teardown(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
+        if args.input.any_pressed() {
+            output.set_home();
+        }
+    }
+
+    fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        let summary = &self.summary;
+        let options = ComplexRenderOption::new()
+            .with_white()
+            .with_center()
+            .with_font(&FONT_VARIABLE_SMALL);
+
+        let mut y = 2;
+        let mut line = |text: &str| {
+            display.render_text_complex(&IVec2::new(CENTER_X_I32, y), text, options);
+            y += 7;
+        };
+
+        let hours = summary.away.as_secs() / 3600;
+        line(&str_format!(
+            fixedstr::str24,
+            "AWAY {}d{}h",
+            hours / 24,
+            hours % 24
+        ));
+        if !summary.skipped.is_zero() {
+            let hours = summary.skipped.as_secs() / 3600;
+            line(&str_format!(
+                fixedstr::str24,
+                "LOST {}d{}h",
+                hours / 24,
+                hours % 24
+            ));
+        }
+        line("");
+
+        if summary.poops > 0 {
+            line(&str_format!(fixedstr::str24, "{} POOPS", summary.poops));
+        }
+        if summary.starved {
+            line("STARVED");
+        } else if summary.lowest_food_percent < 0.5 {
+            line(&str_format!(
+                fixedstr::str24,
+                "HUNGRY {}%",
+                libm::roundf(summary.lowest_food_percent * 100.) as i32
+            ));
+        }
+        if summary.got_sick {
+            line("GOT SICK");
+        }
+        if summary.recovered {
+            line("GOT BETTER");
+        }
+        if let Some((completed, earnings)) = summary.explore {
+            line("EXPLORED");
+            if completed {
+                line(&str_format!(fixedstr::str24, "found ${}", earnings));
+            } else {
+                line("FAILED");
+            }
+        }
+        if summary.suiters > 0 {
+            line(&str_format!(fixedstr::str24, "{} SUITERS", summary.suiters));
+        }
+        if summary.egg_ready {
+            line("EGG READY");
+        }
+        if let Some(cause) = summary.died {
+            line(&str_format!(
+                fixedstr::str24,
+                "{} DIED",
+                args.game_ctx.pet.name.trim()
+            ));
+            line(cause.name());
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
         CENTER_X, CENTER_Y, ComplexRenderOption, GameDisplay, HEIGHT_F32, WIDTH_F32, WIDTH_I32,
     },
     egg::{EggRender, SavedEgg},
+    events::GameEvent,
     geo::RectIVec2,
     pet::{ParentInfo, PetParents, combine_pid, definition::PetAnimationSet, render::PetRender},
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
@@ -71,6 +72,7 @@ impl Scene for BreedScene {
             Some(PetParents::new([self.left, self.right])),
             args.timestamp,
         ));
+        args.game_ctx.events.push(GameEvent::EggLaid);
     }
 
     fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
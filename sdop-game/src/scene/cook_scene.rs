@@ -0,0 +1,256 @@
+use core::time::Duration;
+
+use fixedstr::str_format;
+use glam::IVec2;
+
+use crate::{
+    Button,
+    assets::IMAGE_STOVE,
+    cooking::{RECIPES, Recipe},
+    display::{CENTER_X_I32, ComplexRenderOption, GameDisplay, WIDTH_I32},
+    fonts::FONT_VARIABLE_SMALL,
+    geo::RectIVec2,
+    items::Inventory,
+    scene::{RenderArgs, Scene, SceneEnum, SceneOutput, SceneTickArgs, eat_scene::EatScene},
+    sounds::{SONG_ERROR, SongPlayOptions},
+};
+
+// Time for the marker to cross the pan once
+const SWEEP_TIME: Duration = Duration::from_millis(1200);
+// Left on the heat this long it burns
+const BURN_TIME: Duration = Duration::from_millis(1200 * 6);
+// Share of the pan that cooks the dish right
+const SWEET_SPOT: f32 = 0.2;
+const PAN: RectIVec2 = RectIVec2::new_top_left(IVec2::new(10, 90), IVec2::new(WIDTH_I32 - 20, 10));
+
+enum State {
+    Pick,
+    // If any of the ingredients had spoiled
+    Cooking(&'static Recipe, bool),
+    Burnt(&'static Recipe),
+}
+
+pub struct CookScene {
+    selected: usize,
+    state: State,
+    state_elapsed: Duration,
+    sweet_start: f32,
+}
+
+impl Default for CookScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CookScene {
+    pub fn new() -> Self {
+        Self {
+            selected: 0,
+            state: State::Pick,
+            state_elapsed: Duration::ZERO,
+            sweet_start: 0.,
+        }
+    }
+
+    /// How far across the pan the marker is, bouncing between the ends
+    fn marker(&self) -> f32 {
+        let passes = self.state_elapsed.as_secs_f32() / SWEEP_TIME.as_secs_f32();
+        let progress = passes % 2.;
+        if progress > 1. {
+            2. - progress
+        } else {
+            progress
+        }
+    }
+
+    pub fn cooking(&self) -> bool {
+        matches!(self.state, State::Cooking(..))
+    }
+
+    pub fn in_sweet_spot(&self) -> bool {
+        self.cooking()
+            && (self.sweet_start..=self.sweet_start + SWEET_SPOT).contains(&self.marker())
+    }
+}
+
+fn known_recipes(inventory: &Inventory) -> impl Iterator<Item = &'static Recipe> {
+    RECIPES.iter().filter(|recipe| recipe.known(inventory))
+}
+
+impl Scene for CookScene {
+    fn setup(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn teardown(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
+        self.state_elapsed += args.delta;
+
+        match self.state {
+            State::Pick => {
+                let count = known_recipes(&args.game_ctx.inventory).count();
+
+                if args.input.pressed(Button::Left) {
+                    if self.selected == 0 {
+                        output.set_home();
+                        return;
+                    }
+                    self.selected -= 1;
+                }
+
+                if args.input.pressed(Button::Right) && count > 0 {
+                    self.selected = (self.selected + 1) % count;
+                }
+
+                if args.input.pressed(Button::Middle) {
+                    let Some(recipe) = known_recipes(&args.game_ctx.inventory).nth(self.selected)
+                    else {
+                        output.set_home();
+                        return;
+                    };
+
+                    let spoiled = if args.game_ctx.pet.food_history.sick_of(recipe.food) {
+                        None
+                    } else {
+                        recipe.use_ingredients(
+                            &mut args.game_ctx.inventory,
+                            &mut args.game_ctx.pantry,
+                        )
+                    };
+                    match spoiled {
+                        Some(spoiled) => {
+                            self.sweet_start = args.game_ctx.rng.f32() * (1. - SWEET_SPOT);
+                            self.state = State::Cooking(recipe, spoiled);
+                            self.state_elapsed = Duration::ZERO;
+                        }
+                        None => {
+                            args.game_ctx
+                                .sound_system
+                                .push_song(SONG_ERROR, SongPlayOptions::new().with_effect());
+                        }
+                    }
+                }
+            }
+            State::Cooking(recipe, spoiled) => {
+                if args.input.pressed(Button::Middle) {
+                    if self.in_sweet_spot() {
+                        args.game_ctx.kitchen.record_cooked(recipe.food);
+                        output.set(SceneEnum::Eat(
+                            EatScene::new(recipe.food, &args.game_ctx.pet)
+                                .with_cooked()
+                                .with_spoiled(spoiled),
+                        ));
+                        return;
+                    }
+                    self.state_elapsed = BURN_TIME;
+                }
+
+                if self.state_elapsed >= BURN_TIME {
+                    args.game_ctx
+                        .sound_system
+                        .push_song(SONG_ERROR, SongPlayOptions::new().with_effect());
+                    self.state = State::Burnt(recipe);
+                    self.state_elapsed = Duration::ZERO;
+                }
+            }
+            State::Burnt(_) => {
+                if self.state_elapsed > Duration::from_secs(2) {
+                    output.set_home();
+                }
+            }
+        }
+    }
+
+    fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        let center = ComplexRenderOption::new()
+            .with_white()
+            .with_center()
+            .with_font(&FONT_VARIABLE_SMALL);
+        let left = ComplexRenderOption::new()
+            .with_white()
+            .with_font(&FONT_VARIABLE_SMALL);
+
+        let inventory = &args.game_ctx.inventory;
+        let recipe = match self.state {
+            State::Pick => known_recipes(inventory).nth(self.selected),
+            State::Cooking(recipe, _) | State::Burnt(recipe) => Some(recipe),
+        };
+        let Some(recipe) = recipe else {
+            display.render_text_complex(&IVec2::new(CENTER_X_I32, 40), "NO RECIPES", center);
+            return;
+        };
+
+        display.render_text_complex(&IVec2::new(CENTER_X_I32, 10), recipe.food.name, center);
+        display.render_image_center(CENTER_X_I32, 30, recipe.food.image);
+
+        match self.state {
+            State::Pick => {
+                display.render_text_complex(
+                    &IVec2::new(CENTER_X_I32, 3),
+                    &str_format!(
+                        fixedstr::str12,
+                        "{}/{}",
+                        self.selected + 1,
+                        known_recipes(inventory).count()
+                    ),
+                    center,
+                );
+                display.render_text_complex(
+                    &IVec2::new(CENTER_X_I32, 45),
+                    &str_format!(
+                        fixedstr::str24,
+                        "MADE {}",
+                        args.game_ctx.kitchen.cooked_count(recipe.food)
+                    ),
+                    center,
+                );
+
+                let mut y = 56;
+                for ingredient in recipe.ingredients {
+                    display.render_text_complex(&IVec2::new(1, y), ingredient.name(), left);
+                    display.render_text_complex(
+                        &IVec2::new(WIDTH_I32 - 16, y),
+                        if ingredient.available(inventory, &args.game_ctx.pantry) {
+                            "OK"
+                        } else {
+                            "NO"
+                        },
+                        left,
+                    );
+                    y += 7;
+                }
+
+                let hint = if args.game_ctx.pet.food_history.sick_of(recipe.food) {
+                    "SICK OF IT"
+                } else if recipe.can_cook(inventory, &args.game_ctx.pantry) {
+                    "MID TO COOK"
+                } else {
+                    "MISSING"
+                };
+                display.render_text_complex(&IVec2::new(CENTER_X_I32, 114), hint, center);
+            }
+            State::Cooking(..) => {
+                display.render_image_center(CENTER_X_I32, 70, &IMAGE_STOVE);
+
+                display.render_rect_outline(&PAN, true);
+                let sweet = RectIVec2::new_top_left(
+                    PAN.pos_top_left()
+                        + IVec2::new((PAN.size.x as f32 * self.sweet_start) as i32, 0),
+                    IVec2::new((PAN.size.x as f32 * SWEET_SPOT) as i32, PAN.size.y / 2),
+                );
+                display.render_rect_solid(&sweet, true);
+                let marker = RectIVec2::new_top_left(
+                    PAN.pos_top_left()
+                        + IVec2::new((PAN.size.x as f32 * self.marker()) as i32 - 1, -2),
+                    IVec2::new(3, PAN.size.y + 4),
+                );
+                display.render_rect_outline(&marker, true);
+
+                display.render_text_complex(&IVec2::new(CENTER_X_I32, 110), "MID TO SERVE", center);
+            }
+            State::Burnt(_) => {
+                display.render_text_complex(&IVec2::new(CENTER_X_I32, 70), "BURNT!", center);
+            }
+        }
+    }
+}
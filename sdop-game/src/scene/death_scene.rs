@@ -215,6 +215,7 @@ impl Scene for DeathScene {
             args.timestamp,
             self.cause,
         ));
+        args.game_ctx.promote_next_pet();
     }
 
     fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
@@ -374,6 +375,10 @@ impl Scene for DeathScene {
                 self.pet_render.set_animation(PetAnimationSet::Sad);
 
                 if args.input.any_pressed() {
+                    if !args.game_ctx.household.others().is_empty() {
+                        output.set_home();
+                        return;
+                    }
                     output.set(SceneEnum::NewPet(NewPetScene::new(
                         args.game_ctx.rng.choice(PET_BABIES).unwrap(),
                         false,
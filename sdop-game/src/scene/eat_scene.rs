@@ -6,12 +6,14 @@ use crate::{
     assets::{DynamicImage, IMAGE_STOMACH_MASK},
     display::{CENTER_VEC, CENTER_X, GameDisplay},
     food::Food,
+    game_consts::COOKED_FILL_MULTIPLIER,
     pet::{
-        definition::{PetAnimationSet, PetDefinition, PetDefinitionId},
+        PetInstance,
+        definition::{FoodTaste, PetAnimationSet, PetDefinitionId},
         render::PetRender,
     },
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
-    sounds::{SONG_EATING, SONG_FAN_FARE, SongPlayOptions},
+    sounds::{SONG_EATING, SONG_ERROR, SONG_FAN_FARE, SongPlayOptions},
     stomach::StomachRender,
 };
 
@@ -20,30 +22,59 @@ enum EatSceneState {
     Intro,
     Eating,
     Finished,
+    Refused,
 }
 
 pub struct EatScene {
     food: &'static Food,
     pet_render: PetRender,
     pet_def_id: PetDefinitionId,
+    taste: FoodTaste,
+    stomach_size: f32,
     food_texture: DynamicImage<500>,
     last_end: usize,
     state: EatSceneState,
     fill_factor: f32,
     state_elapsed: Duration,
+    cooked: bool,
+    spoiled: bool,
 }
 
 impl EatScene {
-    pub fn new(food: &'static Food, pet_def_id: PetDefinitionId) -> Self {
+    pub fn new(food: &'static Food, pet: &PetInstance) -> Self {
         Self {
             food,
-            pet_def_id,
+            pet_def_id: pet.def_id,
+            taste: pet.food_taste(food),
+            stomach_size: pet.stomach_size(),
             pet_render: PetRender::default(),
             food_texture: DynamicImage::default(),
             state_elapsed: Duration::ZERO,
             last_end: 0,
             state: EatSceneState::Intro,
             fill_factor: 0.,
+            cooked: false,
+            spoiled: false,
+        }
+    }
+
+    /// Served from the stove rather than the shop
+    pub fn with_cooked(mut self) -> Self {
+        self.cooked = true;
+        self
+    }
+
+    /// Made with food that had gone off
+    pub fn with_spoiled(mut self, spoiled: bool) -> Self {
+        self.spoiled = spoiled;
+        self
+    }
+
+    fn portion(&self) -> f32 {
+        if self.cooked {
+            COOKED_FILL_MULTIPLIER
+        } else {
+            1.
         }
     }
 }
@@ -58,11 +89,36 @@ impl Scene for EatScene {
         // Copy food texture into dynamic texture
         self.food_texture.duplcaite(self.food.image);
         self.fill_factor = args.game_ctx.pet.stomach_filled;
+
+        if self.taste == FoodTaste::Refused {
+            self.pet_render.set_animation(PetAnimationSet::Sad);
+            args.game_ctx
+                .sound_system
+                .push_song(SONG_ERROR, SongPlayOptions::new().with_effect());
+            self.state = EatSceneState::Refused;
+        }
     }
 
     fn teardown(&mut self, args: &mut SceneTickArgs) {
         args.game_ctx.sound_system.clear_song();
-        args.game_ctx.pet.eat(self.food, args.timestamp);
+        if self.state == EatSceneState::Refused
+            || !args
+                .game_ctx
+                .pet
+                .eat_serving(self.food, self.portion(), args.timestamp)
+        {
+            return;
+        }
+
+        let upid = args.game_ctx.pet.upid;
+        if self.cooked {
+            args.game_ctx.kitchen.serve(upid, args.timestamp);
+        } else {
+            args.game_ctx.pantry.take(self.food);
+        }
+        if self.spoiled {
+            args.game_ctx.pantry.ate_spoiled(upid, args.timestamp);
+        }
     }
 
     fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
@@ -100,38 +156,59 @@ impl Scene for EatScene {
                     }
                     self.last_end = new_end;
                     self.fill_factor = (pet.stomach_filled
-                        + ((self.food.fill_factor * pet.definition().food_multiplier(self.food))
-                            * complete_percent))
-                        .min(pet.definition().stomach_size)
+                        + (pet.food_fill(self.food) * self.portion() * complete_percent))
+                        .min(self.stomach_size)
                 }
 
                 if self.state_elapsed > eat_duration {
-                    args.game_ctx
-                        .sound_system
-                        .push_song(SONG_FAN_FARE, SongPlayOptions::new().with_effect());
+                    if self.taste != FoodTaste::Disliked {
+                        args.game_ctx
+                            .sound_system
+                            .push_song(SONG_FAN_FARE, SongPlayOptions::new().with_effect());
+                    }
                     self.state = EatSceneState::Finished;
                     self.state_elapsed = Duration::ZERO;
                 }
             }
             EatSceneState::Finished => {
-                self.pet_render.set_animation(PetAnimationSet::Happy);
+                match self.taste {
+                    FoodTaste::Disliked => self.pet_render.set_animation(PetAnimationSet::Sad),
+                    FoodTaste::Liked => {
+                        // Bounce with delight
+                        self.pet_render.set_animation(PetAnimationSet::Happy);
+                        self.pet_render.pos.y = CENTER_VEC.y
+                            - libm::fabsf(libm::sinf(self.state_elapsed.as_secs_f32() * 10.)) * 4.;
+                    }
+                    _ => self.pet_render.set_animation(PetAnimationSet::Happy),
+                }
                 if self.state_elapsed > Duration::from_secs_f32(2.5) {
                     output.set_home();
                     return;
                 }
             }
+            EatSceneState::Refused => {
+                self.pet_render.pos.x = CENTER_VEC.x
+                    + 15.
+                    + if self.state_elapsed < Duration::from_millis(600) {
+                        args.game_ctx.rng.i32(-2..=2) as f32
+                    } else {
+                        0.
+                    };
+                if self.state_elapsed > Duration::from_secs(2) {
+                    output.set_home();
+                }
+            }
         }
     }
 
     fn render(&self, display: &mut GameDisplay, _args: &mut RenderArgs) {
         const EAT_Y: i32 = 50;
-        let def = PetDefinition::get_by_id(self.pet_def_id);
         display.render_sprite(&self.pet_render);
         if self.state != EatSceneState::Finished {
             display.render_image_center(CENTER_X as i32 - 10, EAT_Y, &self.food_texture);
         }
 
-        let total_filled = (self.fill_factor / def.stomach_size).min(1.);
+        let total_filled = (self.fill_factor / self.stomach_size).min(1.);
         display.render_complex(&StomachRender {
             pos_center: Vec2::new(CENTER_X, IMAGE_STOMACH_MASK.size.y as f32 + 10.),
             filled: total_filled,
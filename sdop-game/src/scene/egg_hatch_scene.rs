@@ -7,6 +7,7 @@ use crate::{
     death::DeathCause,
     display::{CENTER_X, CENTER_Y, ComplexRenderOption, GameDisplay, WIDTH_F32, WIDTH_I32},
     egg::{EggRender, SavedEgg},
+    events::GameEvent,
     geo::RectIVec2,
     pet::{
         definition::{PET_BABIES, PET_BLOB_ID, PetAnimationSet, PetDefinitionId},
@@ -75,6 +76,7 @@ impl Scene for EggHatchScene {
     fn teardown(&mut self, args: &mut SceneTickArgs) {
         args.game_ctx.sound_system.clear_song();
         args.game_ctx.egg = None;
+        args.game_ctx.events.push(GameEvent::EggHatched);
         args.game_ctx.pet_history.add(PetRecord::from_pet_instance(
             &args.game_ctx.pet,
             args.timestamp,
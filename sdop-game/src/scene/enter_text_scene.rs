@@ -16,7 +16,7 @@ enum State {
     SelectChar,
 }
 
-const ENTERABLE_CHARS: &str = "abcdefghijklmnopqrstuvwxyz0123456789$ ";
+pub const ENTERABLE_CHARS: &str = "abcdefghijklmnopqrstuvwxyz0123456789$ ";
 
 pub type EnterTextStr = fixedstr::str32;
 
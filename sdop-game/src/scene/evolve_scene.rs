@@ -5,6 +5,7 @@ use glam::Vec2;
 use crate::{
     Timestamp, assets,
     display::{CENTER_VEC, GameDisplay},
+    events::GameEvent,
     pet::{definition::PetDefinitionId, render::PetRender},
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
     sounds::{SONG_FAN_FARE_LONG, SongPlayOptions},
@@ -128,6 +129,10 @@ impl Scene for EvolveScene {
 
     fn teardown(&mut self, args: &mut SceneTickArgs) {
         args.game_ctx.sound_system.clear_song();
+        args.game_ctx.events.push(GameEvent::Evolved {
+            from: args.game_ctx.pet.def_id,
+            to: self.to_pet_render.def_id(),
+        });
         args.game_ctx
             .pet
             .evolve(self.to_pet_render.def_id(), args.timestamp);
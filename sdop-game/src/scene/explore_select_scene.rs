@@ -6,8 +6,12 @@ use crate::{
     Button, Timestamp, assets,
     date_utils::DurationExt,
     display::{CENTER_X_I32, ComplexRenderOption, GameDisplay, Rotation, WIDTH_I32, WrappingMode},
-    explore::{Location, LocationHistoryIter, get_location},
+    explore::{Loadout, Location, LocationHistoryIter, PackBonus, get_location},
     fonts::FONT_VARIABLE_SMALL,
+    food::{FOODS, Food},
+    game_context::GameContext,
+    items::ItemKind,
+    pantry::Pantry,
     pet::{definition::PetAnimationSet, render::PetRender},
     scene::{
         RenderArgs, Scene, SceneEnum, SceneOutput, SceneTickArgs,
@@ -18,10 +22,14 @@ use crate::{
 };
 
 const SIGN_SHAKE_DURATION: Duration = Duration::from_millis(200);
+const PACK_ROW_FOOD: usize = 0;
+const PACK_ROW_GEAR: usize = 1;
+const PACK_ROW_GO: usize = 2;
 
 enum State {
     Cooldown,
     Selecting,
+    Packing,
 }
 
 pub struct ExploreSelectScene {
@@ -31,6 +39,9 @@ pub struct ExploreSelectScene {
     next_explore_time: Timestamp,
     state: State,
     pet_render: PetRender,
+    pack_row: usize,
+    pack_food: Option<usize>,
+    pack_item: Option<ItemKind>,
 }
 
 impl ExploreSelectScene {
@@ -42,30 +53,60 @@ impl ExploreSelectScene {
             next_explore_time: Timestamp::default(),
             state: State::Selecting,
             pet_render: PetRender::new(0),
+            pack_row: PACK_ROW_FOOD,
+            pack_food: None,
+            pack_item: None,
         }
     }
 
     pub fn location(&self) -> &'static Location {
         get_location(self.selected_location)
     }
+
+    fn pack_food(&self) -> Option<&'static Food> {
+        FOODS.get(self.pack_food?).copied()
+    }
+
+    fn pack_bonus(&self) -> Option<&'static PackBonus> {
+        self.location().pack_bonus(self.pack_item?)
+    }
+}
+
+fn stocked_foods(pantry: &Pantry) -> impl Iterator<Item = usize> {
+    FOODS
+        .iter()
+        .filter(|food| pantry.count(food) > 0)
+        .map(|food| food.id)
+}
+
+// Only what is at home, another pet may be away with the last copy
+fn packable_gear(
+    location: &'static Location,
+    game_ctx: &GameContext,
+) -> impl Iterator<Item = ItemKind> {
+    location
+        .packing
+        .iter()
+        .filter(|bonus| game_ctx.unpacked_count(bonus.item) > 0)
+        .map(|bonus| bonus.item)
+}
+
+// The option after `current`, going back round to packing nothing after the last
+fn next_pack<T: PartialEq + Copy>(
+    mut options: impl Iterator<Item = T>,
+    current: Option<T>,
+) -> Option<T> {
+    match current {
+        Some(current) => options.skip_while(|option| *option != current).nth(1),
+        None => options.next(),
+    }
 }
 
 impl Scene for ExploreSelectScene {
     fn setup(&mut self, args: &mut SceneTickArgs) {
-        for (i, history) in args
-            .game_ctx
-            .pet
-            .explore
-            .location_history
-            .iter()
-            .enumerate()
-        {
-            let next_run_time = history.last_ran + get_location(i).cooldown;
-            if next_run_time > self.next_explore_time {
-                self.next_explore_time = next_run_time;
-                self.selected_location = i;
-            }
-        }
+        let history = &args.game_ctx.pet.explore;
+        self.next_explore_time = history.rested_at();
+        self.selected_location = history.last_location();
 
         if args.timestamp < self.next_explore_time {
             self.state = State::Cooldown;
@@ -158,12 +199,21 @@ impl Scene for ExploreSelectScene {
                             .pet
                             .should_be_sleeping(&(args.timestamp + self.location().length))
                         && !args.game_ctx.pet.is_ill()
-                    {
-                        args.game_ctx
+                        && !args
+                            .game_ctx
                             .explore_system
-                            .start_exploring(self.selected_location);
-                        output.set_home();
-                        return;
+                            .injured(args.game_ctx.pet.upid, args.timestamp)
+                        && args.timestamp >= args.game_ctx.pet.explore.ready_at(self.location())
+                        && args
+                            .game_ctx
+                            .explore_system
+                            .wins_left(self.location(), args.timestamp)
+                            > 0
+                    {
+                        self.state = State::Packing;
+                        self.pack_row = PACK_ROW_FOOD;
+                        self.pack_food = None;
+                        self.pack_item = None;
                     } else {
                         args.game_ctx
                             .sound_system
@@ -172,17 +222,125 @@ impl Scene for ExploreSelectScene {
                     }
                 }
             }
+            State::Packing => {
+                if args.input.pressed(Button::Left) {
+                    if self.pack_row == PACK_ROW_FOOD {
+                        self.state = State::Selecting;
+                        return;
+                    }
+                    self.pack_row -= 1;
+                }
+
+                if args.input.pressed(Button::Right) {
+                    match self.pack_row {
+                        PACK_ROW_FOOD => {
+                            self.pack_food =
+                                next_pack(stocked_foods(&args.game_ctx.pantry), self.pack_food);
+                        }
+                        PACK_ROW_GEAR => {
+                            self.pack_item = next_pack(
+                                packable_gear(self.location(), args.game_ctx),
+                                self.pack_item,
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+
+                if args.input.pressed(Button::Middle) {
+                    if self.pack_row < PACK_ROW_GO {
+                        self.pack_row += 1;
+                        return;
+                    }
+
+                    let upid = args.game_ctx.pet.upid;
+                    // Spoiled food gets eaten on the way all the same
+                    let food =
+                        self.pack_food()
+                            .filter(|food| match args.game_ctx.pantry.take(food) {
+                                Some(true) => {
+                                    args.game_ctx.pantry.ate_spoiled(upid, args.timestamp);
+                                    false
+                                }
+                                Some(false) => true,
+                                None => false,
+                            });
+                    args.game_ctx.start_exploring(
+                        self.selected_location,
+                        Loadout::new(food, self.pack_item),
+                    );
+                    output.set_home();
+                }
+            }
         }
     }
 
     fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
         match self.state {
+            State::Packing => {
+                let center = ComplexRenderOption::new()
+                    .with_white()
+                    .with_center()
+                    .with_font(&FONT_VARIABLE_SMALL);
+                let left = ComplexRenderOption::new()
+                    .with_white()
+                    .with_font(&FONT_VARIABLE_SMALL);
+                let location = self.location();
+
+                display.render_text_complex(&IVec2::new(CENTER_X_I32, 3), "PACKING", center);
+                display.render_image_complex(
+                    0,
+                    10,
+                    &location.cover,
+                    ComplexRenderOption::new().with_black().with_white(),
+                );
+
+                let mut y = location.cover.isize.y + 12;
+                let food = match self.pack_food() {
+                    Some(food) if args.game_ctx.pantry.next_spoiled(food) => {
+                        fixedstr::str_format!(fixedstr::str24, "{} OFF", food.name)
+                    }
+                    Some(food) => fixedstr::str24::from(food.name),
+                    None => fixedstr::str24::from("NONE"),
+                };
+                let gear = match self.pack_bonus() {
+                    Some(bonus) => fixedstr::str_format!(
+                        fixedstr::str24,
+                        "{} +{}",
+                        bonus.item.name(),
+                        bonus.skill
+                    ),
+                    None => fixedstr::str24::from("NONE"),
+                };
+                for (row, label, value) in [
+                    (PACK_ROW_FOOD, "FOOD", Some(food)),
+                    (PACK_ROW_GEAR, "GEAR", Some(gear)),
+                    (PACK_ROW_GO, "GO", None),
+                ] {
+                    if row == self.pack_row {
+                        display.render_text_complex(&IVec2::new(1, y), ">", left);
+                    }
+                    display.render_text_complex(&IVec2::new(6, y), label, left);
+                    y += 7;
+                    if let Some(value) = value {
+                        display.render_text_complex(&IVec2::new(6, y), &value, left);
+                        y += 8;
+                    }
+                }
+
+                let loadout = Loadout::new(self.pack_food(), self.pack_item);
+                display.render_text_complex(
+                    &IVec2::new(CENTER_X_I32, y + 2),
+                    &fixedstr::str_format!(fixedstr::str24, "SKILL +{}", loadout.skill(location)),
+                    center,
+                );
+            }
             State::Cooldown => {
                 let mut y = 3;
 
                 display.render_text_complex(
                     &IVec2::new(CENTER_X_I32, y),
-                    "COOLING OFF",
+                    "TIRED OUT",
                     ComplexRenderOption::new()
                         .with_white()
                         .with_center()
@@ -245,12 +403,24 @@ impl Scene for ExploreSelectScene {
                     .pet
                     .should_be_sleeping(&(args.timestamp + self.location().length));
                 let is_ill = args.game_ctx.pet.is_ill();
+                let injured = args
+                    .game_ctx
+                    .explore_system
+                    .injured(args.game_ctx.pet.upid, args.timestamp);
+                let ready_at = args.game_ctx.pet.explore.ready_at(location);
+                let cooling = args.timestamp < ready_at;
+                let wins_left = args
+                    .game_ctx
+                    .explore_system
+                    .wins_left(location, args.timestamp);
                 let unlocked = args.game_ctx.inventory.has_item(location.item)
                     && right_life_stage
                     && !bed_soon
-                    && !is_ill;
+                    && !is_ill
+                    && !injured
+                    && !cooling
+                    && wins_left > 0;
 
-                // Gotta handle cooldown here
                 display.render_image_complex(
                     0,
                     y,
@@ -351,42 +521,53 @@ impl Scene for ExploreSelectScene {
                         y += assets::IMAGE_SKILL_SYMBOL.isize.y + 1;
                     }
 
-                    y += 5;
+                    let str = fixedstr::str_format!(
+                        fixedstr::str24,
+                        "TODAY {}/{} WINS",
+                        location.daily_wins - wins_left,
+                        location.daily_wins
+                    );
+                    display.render_text_complex(
+                        &IVec2::new(CENTER_X_I32, y + 3),
+                        &str,
+                        ComplexRenderOption::new()
+                            .with_white()
+                            .with_center()
+                            .with_font(&FONT_VARIABLE_SMALL),
+                    );
+                    y += assets::IMAGE_SKILL_SYMBOL.isize.y + 1;
+
+                    y += 2;
                 } else {
-                    if !right_life_stage {
-                        let text_area = display.render_text_complex(
-                            &IVec2::new(CENTER_X_I32, y + 5),
-                            "NOT RIGHT LIFE STAGE",
-                            ComplexRenderOption::new()
-                                .with_white()
-                                .with_center()
-                                .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 2))
-                                .with_font(&FONT_VARIABLE_SMALL),
-                        );
-                        y += (text_area.y - y) + 10;
+                    let reason = if !right_life_stage {
+                        fixedstr::str24::from("NOT RIGHT LIFE STAGE")
                     } else if bed_soon {
-                        let text_area = display.render_text_complex(
-                            &IVec2::new(CENTER_X_I32, y + 5),
-                            "BEDTIME BEFORE BACK",
-                            ComplexRenderOption::new()
-                                .with_white()
-                                .with_center()
-                                .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 2))
-                                .with_font(&FONT_VARIABLE_SMALL),
-                        );
-                        y += (text_area.y - y) + 10;
+                        fixedstr::str24::from("BEDTIME BEFORE BACK")
+                    } else if injured {
+                        fixedstr::str24::from("DOP IS HURT")
+                    } else if is_ill {
+                        fixedstr::str24::from("DOP IS SICK")
+                    } else if cooling {
+                        let mins = (ready_at - args.timestamp).as_mins() as i32 + 1;
+                        fixedstr::str_format!(
+                            fixedstr::str24,
+                            "READY IN {}h{}m",
+                            mins / 60,
+                            mins % 60
+                        )
                     } else {
-                        let text_area = display.render_text_complex(
-                            &IVec2::new(CENTER_X_I32, y + 5),
-                            "DOP IS SICK",
-                            ComplexRenderOption::new()
-                                .with_white()
-                                .with_center()
-                                .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 2))
-                                .with_font(&FONT_VARIABLE_SMALL),
-                        );
-                        y += (text_area.y - y) + 10;
-                    }
+                        fixedstr::str24::from("DONE FOR TODAY")
+                    };
+                    let text_area = display.render_text_complex(
+                        &IVec2::new(CENTER_X_I32, y + 5),
+                        &reason,
+                        ComplexRenderOption::new()
+                            .with_white()
+                            .with_center()
+                            .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 2))
+                            .with_font(&FONT_VARIABLE_SMALL),
+                    );
+                    y += (text_area.y - y) + 10;
                 }
 
                 display.render_image_complex(
@@ -4,22 +4,125 @@ use fixedstr::str_format;
 use glam::IVec2;
 
 use crate::{
-    assets,
+    Button, assets,
     display::{
         CENTER_X_I32, ComplexRenderOption, GameDisplay, HEIGHT_I32, WIDTH_I32, WrappingMode,
     },
     fonts::FONT_VARIABLE_SMALL,
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
+    sounds::{SONG_BUY_CHIME, SongPlayOptions},
 };
 
 pub struct ExploringPostScene {
     elapsed: Duration,
+    selected: usize,
 }
 
 impl ExploringPostScene {
     pub fn new() -> Self {
         Self {
             elapsed: Duration::ZERO,
+            selected: 0,
+        }
+    }
+
+    // Checking in while the pet is still out
+    fn tick_exploring(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
+        let Some(event) = args.game_ctx.explore_system.pending_event() else {
+            self.selected = 0;
+            if args.input.any_pressed() {
+                output.set_home();
+            }
+            return;
+        };
+
+        if args.input.pressed(Button::Left) {
+            if self.selected == 0 {
+                output.set_home();
+                return;
+            }
+            self.selected -= 1;
+        }
+
+        if args.input.pressed(Button::Right) {
+            self.selected = (self.selected + 1) % event.choices.len();
+        }
+
+        if args.input.pressed(Button::Middle) {
+            let ctx = &mut *args.game_ctx;
+            if ctx.explore_system.answer(
+                self.selected,
+                &mut ctx.rng,
+                &mut ctx.pet,
+                &mut ctx.inventory,
+                args.timestamp,
+            ) {
+                ctx.sound_system
+                    .push_song(SONG_BUY_CHIME, SongPlayOptions::new().with_effect());
+            }
+        }
+    }
+
+    fn render_exploring(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        let center = ComplexRenderOption::new()
+            .with_white()
+            .with_center()
+            .with_font(&FONT_VARIABLE_SMALL)
+            .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 2));
+        let explore = &args.game_ctx.explore_system;
+        let location = explore.current_location();
+
+        let mut y = 2;
+        let end = display.render_text_complex(&IVec2::new(CENTER_X_I32, y), location.name, center);
+        y = end.y + 3;
+        let str = str_format!(
+            fixedstr::str24,
+            "STAGE {}/{}",
+            explore.stage_index() + 1,
+            location.stage_count()
+        );
+        display.render_text_complex(&IVec2::new(CENTER_X_I32, y), &str, center);
+        y += 7;
+        if let Some(stage) = explore.current_stage() {
+            let end = display.render_text_complex(&IVec2::new(CENTER_X_I32, y), stage.name, center);
+            y = end.y + 3;
+        }
+        y += 6;
+
+        if let Some(event) = explore.pending_event() {
+            let end = display.render_text_complex(&IVec2::new(CENTER_X_I32, y), event.name, center);
+            y = end.y + 6;
+            for (i, choice) in event.choices.iter().enumerate() {
+                let end = display.render_text_complex(
+                    &IVec2::new(6, y),
+                    choice.text,
+                    ComplexRenderOption::new()
+                        .with_white()
+                        .with_font(&FONT_VARIABLE_SMALL)
+                        .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 8)),
+                );
+                if i == self.selected {
+                    display.render_text_complex(
+                        &IVec2::new(1, y),
+                        ">",
+                        ComplexRenderOption::new()
+                            .with_white()
+                            .with_font(&FONT_VARIABLE_SMALL),
+                    );
+                }
+                y = end.y + 3;
+            }
+            display.render_text_complex(&IVec2::new(CENTER_X_I32, y + 6), "MID TO ANSWER", center);
+        } else if let Some(choice) = explore.answered() {
+            display.render_text_complex(&IVec2::new(CENTER_X_I32, y), "CHOSE TO", center);
+            y += 7;
+            display.render_text_complex(&IVec2::new(CENTER_X_I32, y), choice.text, center);
+        } else {
+            display.render_text_complex(
+                &IVec2::new(CENTER_X_I32, y),
+                explore.current_activity(),
+                center,
+            );
         }
     }
 }
@@ -32,6 +135,11 @@ impl Scene for ExploringPostScene {
     fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
         self.elapsed += args.delta;
 
+        if args.game_ctx.explore_system.currently_exploring() {
+            self.tick_exploring(args, output);
+            return;
+        }
+
         if args.input.any_pressed() || self.elapsed > Duration::from_hours(1) {
             output.set_home();
             return;
@@ -39,6 +147,11 @@ impl Scene for ExploringPostScene {
     }
 
     fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        if args.game_ctx.explore_system.currently_exploring() {
+            self.render_exploring(display, args);
+            return;
+        }
+
         let result = args.game_ctx.explore_system.last_result();
 
         let mut y = 2;
@@ -154,6 +267,32 @@ impl Scene for ExploringPostScene {
                     .with_center()
                     .with_font(&FONT_VARIABLE_SMALL),
             );
+            y += 25;
+        }
+
+        for (event, choice) in &result.events {
+            let str = str_format!(fixedstr::str64, "{}: {}", event.name, choice.text);
+            let end = display.render_text_complex(
+                &IVec2::new(1, y),
+                &str,
+                ComplexRenderOption::new()
+                    .with_white()
+                    .with_font(&FONT_VARIABLE_SMALL)
+                    .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 1)),
+            );
+            y = end.y + 2;
+        }
+
+        if let Some(item) = result.lost {
+            let str = str_format!(fixedstr::str64, "LOST {}", item.name());
+            display.render_text_complex(
+                &IVec2::new(1, y),
+                &str,
+                ComplexRenderOption::new()
+                    .with_white()
+                    .with_font(&FONT_VARIABLE_SMALL)
+                    .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 1)),
+            );
         }
 
         display.render_image_complex(
@@ -16,7 +16,7 @@ use crate::{
     geo::RectIVec2,
     input::random_button,
     items::{FISHING_ITEM_ODDS, ItemKind, pick_item_from_set},
-    money::Money,
+    money::{Money, MoneyCategory},
     pet::{definition::PetAnimationSet, render::PetRender},
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
     sounds::{SONG_FAN_FARE, SONG_FISHING_IDLE, SONG_FISHING_PULLING, SONG_LOST, SongPlayOptions},
@@ -290,7 +290,13 @@ impl Scene for FishingScene {
                             Winning::Item(item_kind) => {
                                 args.game_ctx.inventory.add_item(item_kind, 1);
                             }
-                            Winning::Money(money) => args.game_ctx.money += money,
+                            Winning::Money(money) => {
+                                args.game_ctx.wallet.earn(
+                                    MoneyCategory::Fishing,
+                                    money,
+                                    args.timestamp,
+                                );
+                            }
                         }
                     }
 
@@ -382,7 +388,7 @@ impl Scene for FishingScene {
                             );
                         }
                         Winning::Money(money) => {
-                            let total = str_format!(str32, "${}", args.game_ctx.money);
+                            let total = str_format!(str32, "${}", args.game_ctx.wallet.balance());
                             display.render_text_complex(
                                 &IVec2::new(10, 20),
                                 &total,
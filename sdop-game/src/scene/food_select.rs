@@ -36,7 +36,14 @@ const COL_HEIGHT: f32 = 30.;
 const COL_WIDTH: f32 = WIDTH_F32 / 2.;
 
 impl Scene for FoodSelectScene {
-    fn setup(&mut self, args: &mut SceneTickArgs) {}
+    fn setup(&mut self, args: &mut SceneTickArgs) {
+        if let Some(food) = FOODS
+            .iter()
+            .find(|food| args.game_ctx.pantry.count(food) > 0)
+        {
+            self.current = food;
+        }
+    }
 
     fn teardown(&mut self, _args: &mut SceneTickArgs) {}
 
@@ -46,49 +53,41 @@ impl Scene for FoodSelectScene {
             .checked_sub(args.delta)
             .unwrap_or_default();
 
-        if args.input.pressed(crate::Button::Right) {
-            loop {
-                let mut next = self.current.id + 1;
-                if next >= FOODS.len() {
-                    next = FOODS[0].id;
-                }
-
-                if args.game_ctx.inventory.has_item(FOODS[next].item) {
-                    self.current = FOODS[next];
-                    break;
-                }
+        let pantry = &args.game_ctx.pantry;
 
-                self.current = FOODS[next];
-            }
+        if args.input.pressed(crate::Button::Right) {
+            self.current = FOODS[self.current.id + 1..]
+                .iter()
+                .chain(FOODS[..self.current.id].iter())
+                .find(|food| pantry.count(food) > 0)
+                .copied()
+                .unwrap_or(self.current);
         }
 
         if args.input.pressed(crate::Button::Left) {
-            if self.current.id == 0 {
-                output.set_home();
-                return;
-            }
-
-            loop {
-                let next = match self.current.id.checked_sub(1) {
-                    Some(index) => FOODS[index],
-                    None => break,
-                };
-
-                if args.game_ctx.inventory.has_item(next.item) {
-                    self.current = next;
-                    break;
+            match FOODS[..self.current.id]
+                .iter()
+                .rev()
+                .find(|food| pantry.count(food) > 0)
+            {
+                Some(next) => self.current = next,
+                None => {
+                    output.set_home();
+                    return;
                 }
-
-                self.current = next;
             }
         }
 
         if args.input.pressed(crate::Button::Middle) {
-            if !args.game_ctx.pet.food_history.sick_of(self.current) {
-                output.set(SceneEnum::Eat(EatScene::new(
-                    self.current,
-                    args.game_ctx.pet.def_id,
-                )));
+            if args.game_ctx.pantry.count(self.current) == 0 {
+                args.game_ctx
+                    .sound_system
+                    .push_song(SONG_ERROR, SongPlayOptions::new().with_effect());
+            } else if !args.game_ctx.pet.food_history.sick_of(self.current) {
+                output.set(SceneEnum::Eat(
+                    EatScene::new(self.current, &args.game_ctx.pet)
+                        .with_spoiled(args.game_ctx.pantry.next_spoiled(self.current)),
+                ));
                 return;
             } else {
                 args.game_ctx
@@ -117,16 +116,26 @@ impl Scene for FoodSelectScene {
                 .with_font(&FONT_VARIABLE_SMALL),
         );
 
+        let pantry = &args.game_ctx.pantry;
+        if !pantry.has_any() {
+            display.render_text_complex(
+                &IVec2::new(CENTER_X_I32, 40),
+                "NO FOOD",
+                ComplexRenderOption::new()
+                    .with_white()
+                    .with_center()
+                    .with_font(&FONT_VARIABLE_SMALL),
+            );
+            return;
+        }
+
         let selected_index = FOODS
             .iter()
-            .filter(|f| args.game_ctx.inventory.has_item(f.item))
+            .filter(|f| pantry.count(f) > 0)
             .position(|f| f.id == self.current.id)
             .unwrap_or_default();
 
-        let iter = FOODS
-            .iter()
-            .filter(|f| args.game_ctx.inventory.has_item(f.item))
-            .enumerate();
+        let iter = FOODS.iter().filter(|f| pantry.count(f) > 0).enumerate();
         let mut y = Y_START;
         for (i, food) in iter {
             if selected_index != 0 && i < selected_index - 1 {
@@ -160,8 +169,8 @@ impl Scene for FoodSelectScene {
             );
 
             let fill = args.game_ctx.pet.food_fill(food);
-            let fill_percent = (args.game_ctx.pet.stomach_filled + fill)
-                / args.game_ctx.pet.definition().stomach_size;
+            let fill_percent =
+                (args.game_ctx.pet.stomach_filled + fill) / args.game_ctx.pet.stomach_size();
 
             let str =
                 fixedstr::str_format!(fixedstr::str12, "{}%", libm::roundf(fill_percent * 100.),);
@@ -192,11 +201,25 @@ impl Scene for FoodSelectScene {
 
             y += 7;
 
+            let str = if pantry.next_spoiled(food) {
+                fixedstr::str_format!(fixedstr::str12, "x{} OFF", pantry.count(food))
+            } else {
+                fixedstr::str_format!(fixedstr::str12, "x{}", pantry.count(food))
+            };
+            display.render_text_complex(
+                &IVec2::new(INFO_COL_X, y),
+                &str,
+                ComplexRenderOption::new()
+                    .with_white()
+                    .with_font(&FONT_VARIABLE_SMALL),
+            );
+
+            y += 7;
+
             {
                 let pet = &args.game_ctx.pet;
-                if pet.stomach_filled + food.fill_factor > pet.definition().stomach_size {
-                    let extra =
-                        (pet.stomach_filled + food.fill_factor) - pet.definition().stomach_size;
+                if pet.stomach_filled + food.fill_factor > pet.stomach_size() {
+                    let extra = (pet.stomach_filled + food.fill_factor) - pet.stomach_size();
                     let str = fixedstr::str_format!(fixedstr::str12, "+{}g", extra as i32);
                     display.render_text_complex(
                         &IVec2::new(INFO_COL_X, y),
@@ -282,7 +305,7 @@ impl Scene for FoodSelectScene {
 
             let y_end = y.max(select_rect_y + food.image.size.y as i32 + 7);
 
-            if (self.current.id == 0 && i == 0) || (self.current.id > 0 && i == selected_index) {
+            if i == selected_index {
                 display.render_rect_outline(
                     &RectIVec2::new_top_left(
                         IVec2::new(1, select_rect_y - 4),
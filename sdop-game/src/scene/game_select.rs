@@ -12,7 +12,8 @@ use crate::{
     },
 };
 
-enum MiniGame {
+#[derive(Clone, Copy)]
+pub enum MiniGame {
     TicTacToe,
     DogeEm,
     LinkFour,
@@ -30,6 +31,8 @@ impl MiniGame {
     }
 }
 
+pub const MINIGAME_COUNT: usize = core::mem::variant_count::<MiniGame>();
+
 const MINIGAMES: &[MiniGame] = &[
     MiniGame::TicTacToe,
     MiniGame::DogeEm,
@@ -92,7 +95,10 @@ impl Scene for GameSelectScene {
                 return;
             }
 
-            output.set(match self.active_minigames[self.selected as usize] {
+            let minigame = self.active_minigames[self.selected as usize];
+            args.game_ctx.achievements.record_minigame(minigame);
+
+            output.set(match minigame {
                 MiniGame::TicTacToe => SceneEnum::MgTicTacToe(MgTicTacToeScene::new()),
                 MiniGame::DogeEm => {
                     SceneEnum::MgDogeEm(MgDogeEmScene::new(args.game_ctx.pet.def_id))
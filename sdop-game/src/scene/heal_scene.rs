@@ -14,6 +14,7 @@ use crate::{
     },
     fonts::FONT_VARIABLE_SMALL,
     geo::RectIVec2,
+    money::MoneyCategory,
     pet::{definition::PetAnimationSet, render::PetRender},
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
     sounds::{SONG_HEAL, SongPlayOptions},
@@ -125,7 +126,13 @@ impl Scene for HealScene {
                 }
 
                 if args.input.pressed(Button::Middle) {
-                    if args.game_ctx.pet.heal_cost() > args.game_ctx.money {
+                    if !self.will_pay
+                        || !args.game_ctx.wallet.spend(
+                            MoneyCategory::Heal,
+                            args.game_ctx.pet.heal_cost(),
+                            args.timestamp,
+                        )
+                    {
                         output.set_home();
                         return;
                     } else {
@@ -194,7 +201,11 @@ impl Scene for HealScene {
 
                 display.render_sprite(&self.doctor_full);
 
-                if args.game_ctx.pet.heal_cost() > args.game_ctx.money {
+                if !args
+                    .game_ctx
+                    .wallet
+                    .can_afford(args.game_ctx.pet.heal_cost())
+                {
                     display.render_text_complex(
                         &IVec2::new(CENTER_X_I32, current_y),
                         "YOU NEED",
@@ -208,7 +219,7 @@ impl Scene for HealScene {
                     let str = str_format!(
                         fixedstr::str32,
                         "${}",
-                        args.game_ctx.pet.heal_cost() - args.game_ctx.money
+                        args.game_ctx.pet.heal_cost() - args.game_ctx.wallet.balance()
                     );
                     display.render_text_complex(
                         &IVec2::new(CENTER_X_I32, current_y),
@@ -8,25 +8,30 @@ use crate::{
     anime::Anime,
     assets::{self},
     display::{CENTER_X_I32, ComplexRender, ComplexRenderOption, GameDisplay, WIDTH_I32},
+    game_consts::ADOPT_COST,
+    game_context::GameContext,
     geo::RectIVec2,
-    items::Inventory,
-    pet::{PetInstance, record::PetHistory},
-    suiter::SuiterSystem,
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum MenuOption {
     PetInfo,
+    Vitals,
+    Household,
     FoodSelect,
     Poop,
     Breed,
     Heal,
     Shop,
+    Wallet,
+    Adopt,
     Inventory,
     PlaceFurniture,
     Explore,
     PetRecords,
+    Trophies,
     GameSelect,
+    Lights,
     Settings,
 }
 
@@ -60,15 +65,13 @@ impl Default for MenuOptions {
 
 impl MenuOptions {
     // SLOW POINT
-    pub fn refresh(
-        &mut self,
-        state: super::State,
-        suiter_system: &SuiterSystem,
-        inventory: &Inventory,
-        poop_count: usize,
-        pet_history: &PetHistory,
-        pet: &PetInstance,
-    ) {
+    pub fn refresh(&mut self, state: super::State, game_ctx: &GameContext) {
+        let suiter_system = &game_ctx.suiter_system;
+        let inventory = &game_ctx.inventory;
+        let poop_count = game_ctx.poop_count();
+        let pet = &game_ctx.pet;
+        let household = &game_ctx.household;
+
         let mut options = heapless::Vec::new();
         let _ = options.push(MenuOption::PetInfo);
         let _ = options.push(MenuOption::Settings);
@@ -92,10 +95,31 @@ impl MenuOptions {
             let _ = options.push(MenuOption::Poop);
         }
 
-        if pet_history.count() > 0 {
+        if household.len() > 1 && !matches!(state, super::State::GoneOut { outing_end_time: _ }) {
+            let _ = options.push(MenuOption::Household);
+        }
+
+        if !household.is_full()
+            && game_ctx.wallet.can_afford(ADOPT_COST)
+            && !matches!(state, super::State::GoneOut { outing_end_time: _ })
+        {
+            let _ = options.push(MenuOption::Adopt);
+        }
+
+        if game_ctx.pet_history.count() > 0 {
             let _ = options.push(MenuOption::PetRecords);
         }
 
+        let _ = options.push(MenuOption::Trophies);
+
+        if game_ctx.wallet.ledger().count() > 0 {
+            let _ = options.push(MenuOption::Wallet);
+        }
+
+        if game_ctx.vitals.count() > 1 {
+            let _ = options.push(MenuOption::Vitals);
+        }
+
         if pet.is_ill() && !matches!(state, super::State::Exploring) {
             let _ = options.push(MenuOption::Heal);
         }
@@ -113,10 +137,17 @@ impl MenuOptions {
         if !matches!(
             state,
             super::State::Exploring | super::State::GoneOut { outing_end_time: _ }
-        ) && inventory.has_any_map()
-            && !pet.is_sleeping()
-            && !pet.is_starving()
-            && poop_count == 0
+        ) {
+            let _ = options.push(MenuOption::Lights);
+        }
+
+        // While out exploring it checks in on the expedition
+        if matches!(state, super::State::Exploring)
+            || (!matches!(state, super::State::GoneOut { outing_end_time: _ })
+                && inventory.has_any_map()
+                && !pet.is_sleeping()
+                && !pet.is_starving()
+                && poop_count == 0)
         {
             let _ = options.push(MenuOption::Explore);
         }
@@ -190,9 +221,15 @@ impl ComplexRender for MenuOptions {
                 MenuOption::Inventory => &assets::IMAGE_SYMBOL_INVENTORY,
                 MenuOption::PlaceFurniture => &assets::IMAGE_SYMBOL_PLACE_FURNITURE,
                 MenuOption::PetRecords => &assets::IMAGE_SYMBOL_RECORDS,
+                MenuOption::Trophies => &assets::IMAGE_SYMBOL_TROPHY,
+                MenuOption::Vitals => &assets::IMAGE_SYMBOL_VITALS,
+                MenuOption::Wallet => &assets::IMAGE_SYMBOL_WALLET,
                 MenuOption::Heal => &assets::IMAGE_SYMBOL_HEALTHCARE,
                 MenuOption::Settings => &assets::IMAGE_SYMBOL_SETTINGS,
                 MenuOption::Explore => &assets::IMAGE_SYMBOL_EXPLORE,
+                MenuOption::Lights => &assets::IMAGE_SYMBOL_LIGHTS,
+                MenuOption::Household => &assets::IMAGE_SYMBOL_HOUSEHOLD,
+                MenuOption::Adopt => &assets::IMAGE_SYMBOL_ADOPT,
             };
             let x = if self.selected_index > 0 {
                 let x_index = i as i32 - self.selected_index as i32 + 1;
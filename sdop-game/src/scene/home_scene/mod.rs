@@ -1,5 +1,5 @@
 mod activity;
-mod menu_options;
+pub mod menu_options;
 mod weather;
 
 use core::{time::Duration, u8};
@@ -28,14 +28,20 @@ use crate::{
     food::FOOD_COFFEE,
     furniture::{HomeFurnitureKind, HomeFurnitureLocation, HomeFurnitureRender},
     geo::{RectIVec2, RectVec2, vec2_direction, vec2_distance},
+    household::{HouseholdPet, MAX_HOUSEHOLD},
     items::ItemKind,
+    link::LinkPartner,
     night_sky::generate_night_sky_image,
     particle_system::{
         ParticleSpawnArgs, ParticleSystem, ParticleTemplate, SpawnTrigger, Spawner,
         TemplateCullTatic,
     },
     pc::{PcKind, PcRender},
-    pet::{Mood, definition::PetAnimationSet, render::PetRender},
+    pet::{
+        Mood,
+        definition::{PET_BABIES, PetAnimationSet},
+        render::PetRender,
+    },
     poop::{MAX_POOPS, PoopRender, poop_count, update_poop_renders},
     scene::{
         RenderArgs, Scene, SceneEnum, SceneOutput, SceneTickArgs,
@@ -52,6 +58,7 @@ use crate::{
             menu_options::{MenuOption, MenuOptions},
         },
         inventory_scene::InventoryScene,
+        new_pet_scene::NewPetScene,
         nye_scene::NyeScene,
         pet_info_scene::PetInfoScene,
         pet_records_scene::PetRecordsScene,
@@ -60,6 +67,9 @@ use crate::{
         settings_scene::SettingsScene,
         shop_scene::ShopScene,
         suiters_scene::SuitersScene,
+        trophy_scene::TrophyScene,
+        vitals_scene::VitalsScene,
+        wallet_scene::WalletScene,
     },
     sounds::{SONG_ALARM, SONG_HUNGRY, SONG_POOPED, SONG_SICK, SongPlayOptions},
     sprite::{BasicAnimeSprite, Sprite},
@@ -130,6 +140,10 @@ struct Word {
 
 pub struct HomeSceneData {
     pet_render: PetRender,
+    // The rest of the household, `None` when they aren't home
+    housemate_renders: [Option<PetRender>; MAX_HOUSEHOLD - 1],
+    // A linked friend's pet over for a visit
+    visitor_render: Option<PetRender>,
     target: Vec2,
     options: MenuOptions,
     sleeping_z: BasicAnimeSprite,
@@ -161,6 +175,8 @@ impl Default for HomeSceneData {
     fn default() -> Self {
         Self {
             pet_render: PetRender::default(),
+            housemate_renders: Default::default(),
+            visitor_render: None,
             poops: [None; MAX_POOPS],
             target: Vec2::default(),
             options: MenuOptions::default(),
@@ -214,6 +230,10 @@ impl HomeSceneData {
         )
     }
 
+    pub fn options(&self) -> &MenuOptions {
+        &self.options
+    }
+
     pub fn change_state(&mut self, new_state: State) {
         if self.state == new_state {
             return;
@@ -385,18 +405,16 @@ impl Scene for HomeScene {
 
     fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
         if args.game_ctx.home.state_elapsed == Duration::ZERO || args.frames % 10 == 0 {
-            args.game_ctx.home.options.refresh(
-                args.game_ctx.home.state,
-                &args.game_ctx.suiter_system,
-                &args.game_ctx.inventory,
-                args.game_ctx.poop_count(),
-                &args.game_ctx.pet_history,
-                &args.game_ctx.pet,
-            );
+            // Taken out while refreshing as it reads from the rest of the context
+            let mut options = core::mem::take(&mut args.game_ctx.home.options);
+            options.refresh(args.game_ctx.home.state, args.game_ctx);
+            args.game_ctx.home.options = options;
         }
 
         args.game_ctx.home.state_elapsed += args.delta;
 
+        args.game_ctx.select_pet_needing_attention();
+
         args.game_ctx
             .home
             .pet_render
@@ -415,10 +433,22 @@ impl Scene for HomeScene {
 
         args.game_ctx.home.options.tick(args.delta);
         args.game_ctx.home.pet_render.tick(args.delta);
+        update_housemate_renders(
+            &mut args.game_ctx.home.housemate_renders,
+            args.game_ctx.household.others(),
+            args.delta,
+        );
+        update_visitor_render(
+            &mut args.game_ctx.home.visitor_render,
+            args.game_ctx.link.partner(),
+            args.delta,
+        );
         tick_all_anime(&mut args.game_ctx.home.poops, args.delta);
 
         if args.game_ctx.explore_system.currently_exploring() {
             args.game_ctx.home.change_state(State::Exploring);
+        } else if matches!(args.game_ctx.home.state, State::Exploring) {
+            args.game_ctx.home.change_state(State::Wondering);
         } else {
             if args.game_ctx.pet.is_sleeping()
                 && !matches!(args.game_ctx.home.state, State::Sleeping)
@@ -544,10 +574,13 @@ impl Scene for HomeScene {
             self.skull.anime().tick(args.delta);
         }
 
+        let outdoor = args
+            .game_ctx
+            .outdoor_temperature(args.input, args.timestamp);
         args.game_ctx.home.weather.tick(
             args.delta,
             &mut args.game_ctx.rng,
-            TemperatureLevel::from(args.input.temperature()),
+            TemperatureLevel::from(outdoor),
             &mut args.game_ctx.home.particle_system,
         );
 
@@ -937,6 +970,15 @@ impl Scene for HomeScene {
                     *args.game_ctx.home.options.current().get_song(),
                     SongPlayOptions::new().with_effect(),
                 );
+                if *args.game_ctx.home.options.current() == MenuOption::Lights {
+                    args.game_ctx.lights.toggle();
+                    return;
+                }
+                if *args.game_ctx.home.options.current() == MenuOption::Household {
+                    args.game_ctx.select_next_pet();
+                    args.game_ctx.home.change_state(State::Wondering);
+                    return;
+                }
                 output.set(match *args.game_ctx.home.options.current() {
                     MenuOption::Breed => SceneEnum::Suiters(SuitersScene::new(
                         args.game_ctx.suiter_system.suiter.unwrap_or_default(),
@@ -951,9 +993,22 @@ impl Scene for HomeScene {
                         SceneEnum::PlaceFurniture(PlaceFurnitureScene::new())
                     }
                     MenuOption::PetRecords => SceneEnum::PetRecords(PetRecordsScene::new()),
+                    MenuOption::Trophies => SceneEnum::Trophies(TrophyScene::new()),
+                    MenuOption::Vitals => SceneEnum::Vitals(VitalsScene::new()),
+                    MenuOption::Wallet => SceneEnum::Wallet(WalletScene::new()),
                     MenuOption::Heal => SceneEnum::Heal(HealScene::new()),
                     MenuOption::Settings => SceneEnum::Settings(SettingsScene::new()),
-                    MenuOption::Explore => SceneEnum::ExploreSelect(ExploreSelectScene::new()),
+                    MenuOption::Explore => {
+                        if args.game_ctx.explore_system.currently_exploring() {
+                            SceneEnum::ExploringPost(ExploringPostScene::new())
+                        } else {
+                            SceneEnum::ExploreSelect(ExploreSelectScene::new())
+                        }
+                    }
+                    MenuOption::Adopt => SceneEnum::NewPet(NewPetScene::adopt(
+                        args.game_ctx.rng.choice(PET_BABIES).unwrap(),
+                    )),
+                    MenuOption::Lights | MenuOption::Household => unreachable!(),
                 });
                 return;
             }
@@ -973,6 +1028,22 @@ impl Scene for HomeScene {
             display.render_complex(&self.right_render);
         }
 
+        if matches!(
+            args.game_ctx.home.state,
+            State::Wondering | State::Sleeping | State::Exploring
+        ) {
+            for render in args.game_ctx.home.housemate_renders.iter().flatten() {
+                display.render_sprite(render);
+            }
+        }
+
+        if let (State::Wondering, Some(visitor)) = (
+            &args.game_ctx.home.state,
+            &args.game_ctx.home.visitor_render,
+        ) {
+            display.render_sprite(visitor);
+        }
+
         match args.game_ctx.home.state {
             State::Wondering => {
                 display.render_sprite(&args.game_ctx.home.pet_render);
@@ -985,6 +1056,16 @@ impl Scene for HomeScene {
                 }
 
                 display.render_sprite(&args.game_ctx.home.pet_render);
+
+                // Nag to turn off the lights
+                if args.game_ctx.lights.is_on() && args.frames % 30 < 15 {
+                    display.render_image_complex(
+                        2,
+                        HOME_SCENE_TOP_BORDER_RECT.pos.y + BORDER_HEIGHT + 2,
+                        &assets::IMAGE_SYMBOL_LIGHTS,
+                        ComplexRenderOption::new().with_white(),
+                    );
+                }
             }
             State::WatchingTv {
                 last_checked: _,
@@ -1238,7 +1319,7 @@ impl Scene for HomeScene {
 
             display.render_rect_solid(&HOME_SCENE_TOP_AREA_RECT, false);
 
-            let total_filled = pet.stomach_filled / pet.definition().stomach_size;
+            let total_filled = pet.stomach_filled / pet.stomach_size();
             display.render_complex(&StomachRender {
                 pos_center: Vec2::new(
                     9. + if total_filled < 0.05 && args.frames % 5 == 0 {
@@ -1265,7 +1346,7 @@ impl Scene for HomeScene {
                     .with_font(&FONT_VARIABLE_SMALL),
             );
 
-            let money_str = fixedstr::str_format!(str32, "${}", args.game_ctx.money);
+            let money_str = fixedstr::str_format!(str32, "${}", args.game_ctx.wallet.balance());
             display.render_text_complex(
                 &IVec2::new(STOMACH_END_X, 10),
                 &money_str,
@@ -1301,3 +1382,54 @@ impl Scene for HomeScene {
         display.render_complex(&args.game_ctx.home.options);
     }
 }
+
+fn update_housemate_renders(
+    renders: &mut [Option<PetRender>; MAX_HOUSEHOLD - 1],
+    others: &[HouseholdPet],
+    delta: Duration,
+) {
+    const SPACING: f32 = WIDTH as f32 / (MAX_HOUSEHOLD - 1) as f32;
+
+    for (i, render) in renders.iter_mut().enumerate() {
+        let Some(other) = others.get(i).filter(|other| {
+            other.pet.should_die().is_none() && !other.explore.currently_exploring()
+        }) else {
+            *render = None;
+            continue;
+        };
+
+        let render = render.get_or_insert_with(|| PetRender::new(other.pet.def_id));
+        render.set_def_id(other.pet.def_id);
+        render.set_animation(if other.pet.is_sleeping() {
+            PetAnimationSet::Sleeping
+        } else {
+            PetAnimationSet::Normal
+        });
+        render.pos = Vec2::new(
+            SPACING * (i as f32 + 0.5),
+            WONDER_RECT.y2() - render.static_image().size.y as f32 / 2.,
+        );
+        render.tick(delta);
+    }
+}
+
+fn update_visitor_render(
+    render: &mut Option<PetRender>,
+    partner: Option<&LinkPartner>,
+    delta: Duration,
+) {
+    let Some(partner) = partner else {
+        *render = None;
+        return;
+    };
+
+    let render = render.get_or_insert_with(|| PetRender::new(partner.pet.def_id()));
+    render.set_def_id(partner.pet.def_id());
+    render.set_animation(PetAnimationSet::Happy);
+    let size = render.static_image().size;
+    render.pos = Vec2::new(
+        WONDER_RECT.x2() - size.x as f32 / 2.,
+        WONDER_RECT.y() + size.y as f32 / 2.,
+    );
+    render.tick(delta);
+}
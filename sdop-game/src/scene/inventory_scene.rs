@@ -168,8 +168,8 @@ impl Scene for InventoryScene {
                 }
 
                 if args.input.pressed(Button::Middle) {
-                    if let Some(item_output) = item.use_item(args.game_ctx)
-                        && item.is_usable(args.game_ctx)
+                    if item.is_usable(args.game_ctx)
+                        && let Some(item_output) = item.use_item(args.game_ctx)
                     {
                         if let Some(scene) = item_output.new_scene {
                             output.set(scene);
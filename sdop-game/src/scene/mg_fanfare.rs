@@ -6,7 +6,7 @@ use glam::IVec2;
 use crate::{
     Timestamp,
     display::{CENTER_VEC, CENTER_X_I32, ComplexRenderOption, GameDisplay},
-    money::Money,
+    money::{Money, MoneyCategory},
     pet::{
         definition::{PetAnimationSet, PetDefinitionId},
         render::PetRender,
@@ -62,7 +62,9 @@ impl Scene for MgFanFareScene {
 
     fn teardown(&mut self, args: &mut SceneTickArgs) {
         args.game_ctx.sound_system.clear_song();
-        args.game_ctx.money += self.money;
+        args.game_ctx
+            .wallet
+            .earn(MoneyCategory::Minigame, self.money, args.timestamp);
         args.game_ctx.pet.played_game();
     }
 
@@ -103,7 +105,7 @@ impl Scene for MgFanFareScene {
 
         match self.state {
             State::Intro => {
-                let total = str_format!(str32, "${}", args.game_ctx.money);
+                let total = str_format!(str32, "${}", args.game_ctx.wallet.balance());
                 display.render_text_complex(
                     &IVec2::new(10, 10),
                     &total,
@@ -119,7 +121,8 @@ impl Scene for MgFanFareScene {
                 }
             }
             State::ShowingTotal => {
-                let winnings = str_format!(str32, "${}", args.game_ctx.money + self.money);
+                let winnings =
+                    str_format!(str32, "${}", args.game_ctx.wallet.balance() + self.money);
                 display.render_text_complex(
                     &IVec2::new(CENTER_X_I32, 10),
                     &winnings,
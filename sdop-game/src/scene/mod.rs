@@ -1,5 +1,7 @@
 pub mod alarm_set_scene;
+pub mod away_scene;
 pub mod breed_scene;
+pub mod cook_scene;
 pub mod credits_scene;
 pub mod death_scene;
 pub mod eat_scene;
@@ -30,6 +32,9 @@ pub mod settings_scene;
 pub mod shop_scene;
 pub mod star_gazing_scene;
 pub mod suiters_scene;
+pub mod trophy_scene;
+pub mod vitals_scene;
+pub mod wallet_scene;
 pub mod weekday_select_scene;
 
 use core::time::Duration;
@@ -101,6 +106,7 @@ define_scence_enum!(SceneEnum {
     NewPet(new_pet_scene::NewPetScene),
     Home(home_scene::HomeScene),
     Eat(eat_scene::EatScene),
+    Cook(cook_scene::CookScene),
     GameSelect(game_select::GameSelectScene),
     FoodSelect(food_select::FoodSelectScene),
     Evovle(evolve_scene::EvolveScene),
@@ -118,6 +124,9 @@ define_scence_enum!(SceneEnum {
     Suiters(suiters_scene::SuitersScene),
     EggHatch(egg_hatch_scene::EggHatchScene),
     PetRecords(pet_records_scene::PetRecordsScene),
+    Trophies(trophy_scene::TrophyScene),
+    Vitals(vitals_scene::VitalsScene),
+    Wallet(wallet_scene::WalletScene),
     Heal(heal_scene::HealScene),
     StarGazing(star_gazing_scene::StarGazingScene),
     AlarmSet(alarm_set_scene::AlarmSetScene),
@@ -131,6 +140,7 @@ define_scence_enum!(SceneEnum {
     MgTicTacToe(mg_tic_tac_toe::MgTicTacToeScene),
     MgTicLinkFour(mg_link_four::MgLinkFourScene),
     MgWeightLift(mg_weight_lift::MgWeightLift),
+    Away(away_scene::AwayScene),
 });
 
 impl Default for SceneEnum {
@@ -143,6 +153,7 @@ impl SceneEnum {
     pub fn should_quit_on_idle(&self) -> bool {
         match self {
             SceneEnum::Eat(_)
+            | SceneEnum::Cook(_)
             | SceneEnum::GameSelect(_)
             | SceneEnum::FoodSelect(_)
             | SceneEnum::PetInfo(_)
@@ -155,6 +166,10 @@ impl SceneEnum {
             | SceneEnum::AlarmSet(_)
             | SceneEnum::Settings(_)
             | SceneEnum::Credits(_)
+            | SceneEnum::Away(_)
+            | SceneEnum::Trophies(_)
+            | SceneEnum::Vitals(_)
+            | SceneEnum::Wallet(_)
             | SceneEnum::ExploreSelect(_) => true,
             _ => false,
         }
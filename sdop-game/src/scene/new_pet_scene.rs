@@ -5,7 +5,9 @@ use crate::{
     Timestamp,
     display::GameDisplay,
     explore::ExploreSystem,
-    game_consts::{ITEMS_CLEAR_ON_NEW_PET, STARTING_FILLED},
+    game_consts::{ADOPT_COST, ITEMS_CLEAR_ON_NEW_PET, STARTING_FILLED},
+    lights::Lights,
+    money::MoneyCategory,
     pet::{PetInstance, PetName, PetParents, UniquePetId, definition::PetDefinitionId, gen_pid},
     scene::{
         RenderArgs, Scene, SceneEnum, SceneOutput, SceneTickArgs,
@@ -28,6 +30,10 @@ pub struct NewPetScene {
     state: State,
     upid: UniquePetId,
     parents: Option<PetParents>,
+    // Joins the household instead of replacing the current pet
+    adopt: bool,
+    // Teardown runs every time the name or date is entered so only move the old pet once
+    joined: bool,
 }
 
 impl NewPetScene {
@@ -47,6 +53,15 @@ impl NewPetScene {
             need_timestamp,
             upid: upid.unwrap_or_default(),
             parents,
+            adopt: false,
+            joined: false,
+        }
+    }
+
+    pub fn adopt(def_id: PetDefinitionId) -> Self {
+        Self {
+            adopt: true,
+            ..Self::new(def_id, false, None, None)
         }
     }
 }
@@ -55,6 +70,25 @@ impl Scene for NewPetScene {
     fn setup(&mut self, args: &mut SceneTickArgs) {}
 
     fn teardown(&mut self, args: &mut SceneTickArgs) {
+        if self.adopt {
+            if !self.joined {
+                self.joined = true;
+                let explore = core::mem::take(&mut args.game_ctx.explore_system);
+                let vitals = core::mem::take(&mut args.game_ctx.vitals);
+                args.game_ctx
+                    .household
+                    .add(args.game_ctx.pet, explore, vitals);
+                args.game_ctx
+                    .wallet
+                    .spend(MoneyCategory::Adopt, ADOPT_COST, args.timestamp);
+            }
+        } else {
+            args.game_ctx.lights = Lights::default();
+            for item in ITEMS_CLEAR_ON_NEW_PET {
+                args.game_ctx.inventory.clear_item(*item);
+            }
+        }
+
         args.game_ctx.pet = PetInstance::default();
         args.game_ctx.pet.parents = self.parents;
         args.game_ctx.pet.upid = if self.upid == 0 {
@@ -76,10 +110,6 @@ impl Scene for NewPetScene {
         args.game_ctx
             .home
             .change_state(crate::scene::home_scene::State::Wondering);
-
-        for item in ITEMS_CLEAR_ON_NEW_PET {
-            args.game_ctx.inventory.clear_item(*item);
-        }
     }
 
     fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
@@ -9,7 +9,11 @@ use crate::{
     date_utils::DurationExt,
     display::{CENTER_X, CENTER_X_I32, ComplexRenderOption, GameDisplay},
     fonts,
-    pet::{LifeStage, definition::PetAnimationSet, render::PetRender},
+    pet::{
+        LifeStage,
+        definition::{PetAnimationSet, PetDefinition},
+        render::PetRender,
+    },
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
     sprite::Sprite,
 };
@@ -19,9 +23,10 @@ enum State {
     Main,
     LifeStages,
     Parents,
+    Genes,
 }
 
-const STATE_ORDER: &[State] = &[State::Main, State::LifeStages, State::Parents];
+const STATE_ORDER: &[State] = &[State::Main, State::LifeStages, State::Parents, State::Genes];
 
 pub struct PetInfoScene {
     state: State,
@@ -170,11 +175,7 @@ impl Scene for PetInfoScene {
                 }
 
                 {
-                    let str = str_format!(
-                        str32,
-                        "WT:{:.0}g",
-                        pet.definition().base_weight + pet.extra_weight
-                    );
+                    let str = str_format!(str32, "WT:{:.0}g", pet.weight());
                     display.render_text_complex(
                         &IVec2::new(TEXT_X_OFFSET, current_y),
                         &str,
@@ -335,6 +336,49 @@ impl Scene for PetInfoScene {
                     );
                 }
             }
+            State::Genes => {
+                const Y_BUFFER: i32 = 8;
+                let mut current_y = 6;
+
+                display.render_text_complex(
+                    &IVec2::new(CENTER_X_I32, current_y),
+                    "GENES",
+                    ComplexRenderOption::new()
+                        .with_white()
+                        .with_center()
+                        .with_font(&fonts::FONT_VARIABLE_SMALL),
+                );
+                current_y += Y_BUFFER;
+
+                let pet = &args.game_ctx.pet;
+                let genome = pet.genome();
+                let percent = |multiplier: f32| libm::roundf(multiplier * 100.) as i32;
+                let leans = genome
+                    .favoured_evolution(pet.definition())
+                    .map(|index| {
+                        PetDefinition::get_by_id(pet.definition().evolutions[index].target).name
+                    })
+                    .unwrap_or("-");
+
+                let lines = [
+                    str_format!(str32, "TUMMY {}%", percent(genome.stomach_multiplier())),
+                    str_format!(str32, "BUILD {}%", percent(genome.weight_multiplier())),
+                    str_format!(str32, "EXPLR {:+}", genome.explore_bonus()),
+                    str_format!(str32, "SICK {}%", percent(genome.illness_multiplier())),
+                    str_format!(str32, "LIKES {}", genome.favourite_food().name),
+                    str_format!(str32, "LEANS {}", leans),
+                ];
+                for line in &lines {
+                    display.render_text_complex(
+                        &IVec2::new(TEXT_X_OFFSET, current_y),
+                        line,
+                        ComplexRenderOption::new()
+                            .with_white()
+                            .with_font(&fonts::FONT_VARIABLE_SMALL),
+                    );
+                    current_y += Y_BUFFER;
+                }
+            }
         }
     }
 }
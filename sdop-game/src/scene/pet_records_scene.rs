@@ -9,12 +9,14 @@ use crate::{
     death::DeathCause,
     display::{CENTER_X, CENTER_X_I32, ComplexRenderOption, GameDisplay},
     fonts::FONT_VARIABLE_SMALL,
-    pet::{planet_location_from_upid, render::PetRender},
+    pet::{planet_location_from_upid, record::PetHistoryStats, render::PetRender},
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
 };
 
 enum State {
     Select,
+    // The page after the last record
+    Stats,
 }
 
 pub struct PetRecordsScene {
@@ -52,7 +54,7 @@ impl Scene for PetRecordsScene {
         }
 
         match self.state {
-            State::Select => {
+            State::Select | State::Stats => {
                 self.pet_render.pos = Vec2::new(CENTER_X, 30.);
 
                 let mut change = 0;
@@ -69,12 +71,17 @@ impl Scene for PetRecordsScene {
                 if updated < 0 {
                     output.set_home();
                     return;
-                } else if updated >= args.game_ctx.pet_history.count() as isize {
+                } else if updated > args.game_ctx.pet_history.count() as isize {
                     updated = 0;
                 }
 
                 if updated as usize != self.selected {
                     self.selected = updated as usize;
+                    self.state = if self.selected == args.game_ctx.pet_history.count() {
+                        State::Stats
+                    } else {
+                        State::Select
+                    };
                 }
             }
         }
@@ -84,8 +91,30 @@ impl Scene for PetRecordsScene {
         const TEXT_X_OFFSET: i32 = 2;
         const Y_BUFFER: i32 = 7;
 
-        if let Some(record) = args.game_ctx.pet_history.get_by_index(self.selected) {
+        let history = &args.game_ctx.pet_history;
+        let page = str_format!(
+            fixedstr::str12,
+            "{}/{}",
+            self.selected + 1,
+            history.count() + 1
+        );
+        display.render_text_complex(
+            &IVec2::new(CENTER_X_I32, 3),
+            &page,
+            ComplexRenderOption::new()
+                .with_white()
+                .with_center()
+                .with_font(&FONT_VARIABLE_SMALL),
+        );
+
+        if matches!(self.state, State::Stats) {
+            render_stats(display, history.stats());
+            return;
+        }
+
+        if let Some(record) = history.get_by_index(self.selected) {
             match self.state {
+                State::Stats => {}
                 State::Select => {
                     let str = str_format!(fixedstr::str32, "PID:{:010X}", record.upid);
 
@@ -238,3 +267,49 @@ impl Scene for PetRecordsScene {
         }
     }
 }
+
+fn render_stats(display: &mut GameDisplay, stats: &PetHistoryStats) {
+    const Y_BUFFER: i32 = 7;
+
+    let center = ComplexRenderOption::new()
+        .with_white()
+        .with_center()
+        .with_font(&FONT_VARIABLE_SMALL);
+    let mut render_pos = IVec2::new(CENTER_X_I32, 14);
+
+    display.render_text_complex(&render_pos, "RAISED", center);
+    render_pos.y += Y_BUFFER;
+    display.render_text_complex(
+        &render_pos,
+        &str_format!(fixedstr::str12, "{}", stats.raised),
+        center,
+    );
+    render_pos.y += Y_BUFFER * 2;
+
+    if let Some(longest) = &stats.longest_lived {
+        display.render_text_complex(&render_pos, "LONGEST", center);
+        render_pos.y += Y_BUFFER;
+        display.render_text_complex(&render_pos, &longest.name, center);
+        render_pos.y += Y_BUFFER;
+        let hours = longest.age().as_hours() as i32;
+        display.render_text_complex(
+            &render_pos,
+            &str_format!(fixedstr::str24, "{}d{}h", hours / 24, hours % 24),
+            center,
+        );
+        render_pos.y += Y_BUFFER * 2;
+    }
+
+    for cause in DeathCause::ALL {
+        let count = stats.died_of(cause);
+        if count == 0 {
+            continue;
+        }
+        display.render_text_complex(
+            &render_pos,
+            &str_format!(fixedstr::str24, "{} {}", cause.name(), count),
+            center,
+        );
+        render_pos.y += Y_BUFFER;
+    }
+}
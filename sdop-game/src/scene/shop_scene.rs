@@ -2,17 +2,22 @@ use core::time::Duration;
 
 use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, TimeDelta, Timelike};
 use glam::{IVec2, Vec2};
+use strum::IntoEnumIterator;
 
 use crate::{
     anime::HasAnime,
     assets::{self},
     display::{CENTER_VEC, CENTER_X_I32, ComplexRenderOption, GameDisplay},
     fonts::FONT_VARIABLE_SMALL,
+    food::{FOODS, Food},
     game_consts::SHOP_OPEN_TIMES,
-    geo::RectVec2,
+    geo::{RectIVec2, RectVec2},
+    items::{Inventory, ItemKind},
+    money::MoneyCategory,
+    pantry::FOOD_STOCK_MAX,
     particle_system::{ParticleSpawnArgs, ParticleSystem, ParticleTemplate, TemplateCullTatic},
     scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
-    shop::ShopItemSet,
+    shop::{ShopItemSet, check_sell, sell_item},
     sounds::{self, SONG_SHOP, SONG_SHOP_CLOSED, SongPlayOptions},
     sprite::BasicAnimeSprite,
 };
@@ -23,6 +28,47 @@ enum State {
     Closed,
     ShopKeeper,
     Selected(usize),
+    Selling(ItemKind),
+    // Stock for foods on the menu, after the wares
+    Grocery(&'static Food),
+}
+
+// The owned item after `current` going `forward` or back, None once it runs off the end
+fn step_owned(inventory: &Inventory, current: Option<ItemKind>, forward: bool) -> Option<ItemKind> {
+    let index = current.map(|item| item as usize);
+    if forward {
+        ItemKind::iter()
+            .skip(index.map_or(0, |index| index + 1))
+            .find(|item| inventory.has_item(*item))
+    } else {
+        ItemKind::iter()
+            .take(index.unwrap_or(0))
+            .rev()
+            .find(|item| inventory.has_item(*item))
+    }
+}
+
+// The known food after `current` going `forward` or back, None once it runs off the end
+fn step_grocery(
+    inventory: &Inventory,
+    current: Option<&'static Food>,
+    forward: bool,
+) -> Option<&'static Food> {
+    let index = current.map(|food| food.id);
+    if forward {
+        FOODS
+            .iter()
+            .skip(index.map_or(0, |index| index + 1))
+            .find(|food| inventory.has_item(food.item))
+            .copied()
+    } else {
+        FOODS
+            .iter()
+            .take(index.unwrap_or(0))
+            .rev()
+            .find(|food| inventory.has_item(food.item))
+            .copied()
+    }
 }
 
 pub struct ShopScene {
@@ -61,6 +107,26 @@ impl ShopScene {
     pub fn item_count(&self) -> usize {
         self.for_sale.iter().filter(|i| i.is_some()).count()
     }
+
+    fn spawn_money(&mut self, args: &mut SceneTickArgs) {
+        self.particle_system.run_once_spawner(
+            |particles, args| {
+                static TEMPLATE: ParticleTemplate = ParticleTemplate::new(
+                    TemplateCullTatic::Remaning(
+                        Duration::from_millis(1000)..Duration::from_millis(2000),
+                    ),
+                    RectVec2::new_top_left(Vec2::new(10., 80.), Vec2::new(40., 40.)),
+                    Vec2::new(-50.0, -50.0)..Vec2::new(50.0, 50.0),
+                    &[&assets::IMAGE_MONEY_PARTICLE],
+                );
+
+                for _ in 0..20 {
+                    particles.add(TEMPLATE.instantiate(&mut args.rng, "money"));
+                }
+            },
+            &mut ParticleSpawnArgs::new(args.delta, &mut args.game_ctx.rng, &Vec2::default()),
+        );
+    }
 }
 
 impl Scene for ShopScene {
@@ -135,43 +201,67 @@ impl Scene for ShopScene {
                     output.set_home();
                     return;
                 }
+                if args.input.pressed(crate::Button::Middle) {
+                    if let Some(item) = step_owned(&args.game_ctx.inventory, None, true) {
+                        args.game_ctx.sound_system.clear_song();
+                        self.state = State::Selling(item);
+                    } else {
+                        args.game_ctx
+                            .sound_system
+                            .push_song(sounds::SONG_ERROR, SongPlayOptions::new().with_effect());
+                    }
+                }
+            }
+            State::Selling(current) => {
+                let mut next = Some(current);
+                if args.input.pressed(crate::Button::Middle) {
+                    if sell_item(args.game_ctx, current, args.timestamp).is_ok() {
+                        args.game_ctx.sound_system.push_song(
+                            sounds::SONG_BUY_CHIME,
+                            SongPlayOptions::new().with_effect(),
+                        );
+                        self.spawn_money(args);
+                    } else {
+                        args.game_ctx
+                            .sound_system
+                            .push_song(sounds::SONG_ERROR, SongPlayOptions::new().with_effect());
+                    }
+                    self.sign_shake_remaining = SIGN_SHAKE_DURATION;
+
+                    if !args.game_ctx.inventory.has_item(current) {
+                        next = step_owned(&args.game_ctx.inventory, Some(current), true)
+                            .or_else(|| step_owned(&args.game_ctx.inventory, Some(current), false));
+                    }
+                }
+
+                if args.input.pressed(crate::Button::Right) {
+                    next = step_owned(&args.game_ctx.inventory, next, true);
+                }
+                if args.input.pressed(crate::Button::Left) {
+                    next = step_owned(&args.game_ctx.inventory, next, false);
+                }
+
+                self.state = match next {
+                    Some(item) => State::Selling(item),
+                    None => State::ShopKeeper,
+                };
             }
             State::Selected(selected) => {
                 let current = self.for_sale[selected];
                 if args.input.pressed(crate::Button::Middle) {
-                    if args.game_ctx.money > current.cost()
-                        && !(current.unique() && args.game_ctx.inventory.has_item(current))
+                    if !(current.unique() && args.game_ctx.inventory.has_item(current))
+                        && args.game_ctx.wallet.spend(
+                            MoneyCategory::Shop,
+                            current.cost(),
+                            args.timestamp,
+                        )
                     {
                         args.game_ctx.inventory.add_item(current, 1);
-                        args.game_ctx.money -= current.cost();
                         args.game_ctx.sound_system.push_song(
                             sounds::SONG_BUY_CHIME,
                             SongPlayOptions::new().with_effect(),
                         );
-                        self.particle_system.run_once_spawner(
-                            |particles, args| {
-                                static TEMPLATE: ParticleTemplate = ParticleTemplate::new(
-                                    TemplateCullTatic::Remaning(
-                                        Duration::from_millis(1000)..Duration::from_millis(2000),
-                                    ),
-                                    RectVec2::new_top_left(
-                                        Vec2::new(10., 80.),
-                                        Vec2::new(40., 40.),
-                                    ),
-                                    Vec2::new(-50.0, -50.0)..Vec2::new(50.0, 50.0),
-                                    &[&assets::IMAGE_MONEY_PARTICLE],
-                                );
-
-                                for _ in 0..20 {
-                                    particles.add(TEMPLATE.instantiate(&mut args.rng, "money"));
-                                }
-                            },
-                            &mut ParticleSpawnArgs::new(
-                                args.delta,
-                                &mut args.game_ctx.rng,
-                                &Vec2::default(),
-                            ),
-                        );
+                        self.spawn_money(args);
                         self.sign_shake_remaining = SIGN_SHAKE_DURATION;
                     } else {
                         args.game_ctx
@@ -189,12 +279,56 @@ impl Scene for ShopScene {
                     selected -= 1;
                 }
 
-                if selected < 0 || selected >= self.item_count() as isize {
+                if selected < 0 {
                     self.state = State::ShopKeeper;
+                } else if selected >= self.item_count() as isize {
+                    self.state = match step_grocery(&args.game_ctx.inventory, None, true) {
+                        Some(food) => State::Grocery(food),
+                        None => State::ShopKeeper,
+                    };
                 } else {
                     self.state = State::Selected(selected as usize)
                 }
             }
+            State::Grocery(food) => {
+                if args.input.pressed(crate::Button::Middle) {
+                    if args.game_ctx.pantry.has_room(food)
+                        && args.game_ctx.wallet.spend(
+                            MoneyCategory::Shop,
+                            food.stock_cost(),
+                            args.timestamp,
+                        )
+                    {
+                        args.game_ctx.pantry.add(food);
+                        args.game_ctx.sound_system.push_song(
+                            sounds::SONG_BUY_CHIME,
+                            SongPlayOptions::new().with_effect(),
+                        );
+                        self.spawn_money(args);
+                    } else {
+                        args.game_ctx
+                            .sound_system
+                            .push_song(sounds::SONG_ERROR, SongPlayOptions::new().with_effect());
+                    }
+                    self.sign_shake_remaining = SIGN_SHAKE_DURATION;
+                }
+
+                if args.input.pressed(crate::Button::Right) {
+                    self.state = match step_grocery(&args.game_ctx.inventory, Some(food), true) {
+                        Some(food) => State::Grocery(food),
+                        None => State::ShopKeeper,
+                    };
+                }
+                if args.input.pressed(crate::Button::Left) {
+                    self.state = match step_grocery(&args.game_ctx.inventory, Some(food), false) {
+                        Some(food) => State::Grocery(food),
+                        None => match self.item_count() {
+                            0 => State::ShopKeeper,
+                            count => State::Selected(count - 1),
+                        },
+                    };
+                }
+            }
         }
     }
 
@@ -352,6 +486,15 @@ impl Scene for ShopScene {
                         .with_white()
                         .with_font(&FONT_VARIABLE_SMALL),
                 );
+                render_pos.y += Y_BUFFER;
+                display.render_text_complex(
+                    &render_pos,
+                    "MID TO SELL",
+                    ComplexRenderOption::new()
+                        .with_center()
+                        .with_white()
+                        .with_font(&FONT_VARIABLE_SMALL),
+                );
             }
             State::Selected(selected) => {
                 const BUFFER_Y: i32 = 8;
@@ -360,7 +503,11 @@ impl Scene for ShopScene {
 
                 let mut render_pos = IVec2::new(CENTER_X_I32, y);
 
-                let str = fixedstr::str_format!(fixedstr::str12, "BANK ${}", args.game_ctx.money);
+                let str = fixedstr::str_format!(
+                    fixedstr::str12,
+                    "BANK ${}",
+                    args.game_ctx.wallet.balance()
+                );
                 display.render_text_complex(
                     &render_pos,
                     &str,
@@ -420,12 +567,12 @@ impl Scene for ShopScene {
                         &assets::IMAGE_ALREADY_OWN_SIGN,
                     );
                 } else {
-                    let too_much = args.game_ctx.money < item.cost();
+                    let too_much = !args.game_ctx.wallet.can_afford(item.cost());
                     if too_much {
                         let str = fixedstr::str_format!(
                             fixedstr::str12,
                             "NEED ${}",
-                            item.cost() - args.game_ctx.money
+                            item.cost() - args.game_ctx.wallet.balance()
                         );
                         display.render_text_complex(
                             &render_pos,
@@ -463,6 +610,122 @@ impl Scene for ShopScene {
                     };
                 }
             }
+            State::Selling(item) => {
+                const BUFFER_Y: i32 = 8;
+                let text = ComplexRenderOption::new()
+                    .with_center()
+                    .with_white()
+                    .with_font(&FONT_VARIABLE_SMALL);
+
+                let mut render_pos = IVec2::new(CENTER_X_I32, 4);
+                let str = fixedstr::str_format!(
+                    fixedstr::str12,
+                    "BANK ${}",
+                    args.game_ctx.wallet.balance()
+                );
+                display.render_text_complex(&render_pos, &str, text);
+                render_pos.y += BUFFER_Y;
+                display.render_text_complex(&render_pos, item.name(), text);
+                render_pos.y += BUFFER_Y;
+
+                let check = check_sell(args.game_ctx, item, args.timestamp);
+                if let Ok(price) = check {
+                    let str = fixedstr::str_format!(fixedstr::str12, "PAYS ${}", price);
+                    display.render_text_complex(&render_pos, &str, text);
+                }
+                display.render_image_complex(
+                    CENTER_X_I32,
+                    render_pos.y + 30,
+                    item.image(),
+                    ComplexRenderOption::new().with_white().with_center(),
+                );
+
+                render_pos.y = 80;
+                let str = fixedstr::str_format!(
+                    fixedstr::str12,
+                    "OWN {}",
+                    args.game_ctx.inventory.item_count(item)
+                );
+                display.render_text_complex(&render_pos, &str, text);
+                render_pos.y += BUFFER_Y * 2;
+
+                render_pos.x += if self.sign_shake_remaining > Duration::ZERO {
+                    args.game_ctx.rng.i32(-3..=3)
+                } else {
+                    0
+                };
+                match check {
+                    Ok(_) => {
+                        display.render_text_complex(&render_pos, "SELL", text);
+                        display.render_rect_outline(
+                            &RectIVec2::new_center(render_pos, IVec2::new(20, 10)),
+                            true,
+                        );
+                    }
+                    Err(refusal) => {
+                        display.render_text_complex(&render_pos, refusal.name(), text);
+                    }
+                }
+            }
+            State::Grocery(food) => {
+                const BUFFER_Y: i32 = 8;
+                let text = ComplexRenderOption::new()
+                    .with_center()
+                    .with_white()
+                    .with_font(&FONT_VARIABLE_SMALL);
+                let shake = if self.sign_shake_remaining > Duration::ZERO {
+                    args.game_ctx.rng.i32(-3..=3)
+                } else {
+                    0
+                };
+
+                let mut render_pos = IVec2::new(CENTER_X_I32, 4);
+                let str = fixedstr::str_format!(
+                    fixedstr::str12,
+                    "BANK ${}",
+                    args.game_ctx.wallet.balance()
+                );
+                display.render_text_complex(&render_pos, &str, text);
+                render_pos.y += BUFFER_Y;
+                display.render_text_complex(&render_pos, food.name, text);
+                render_pos.y += BUFFER_Y;
+                let str = fixedstr::str_format!(fixedstr::str12, "CST ${}", food.stock_cost());
+                display.render_text_complex(&render_pos, &str, text);
+                display.render_image_complex(
+                    CENTER_X_I32,
+                    render_pos.y + 30,
+                    food.image,
+                    ComplexRenderOption::new().with_white().with_center(),
+                );
+
+                render_pos.y = 80;
+                let pantry = &args.game_ctx.pantry;
+                let str = fixedstr::str_format!(
+                    fixedstr::str12,
+                    "STOCK {}/{}",
+                    pantry.count(food),
+                    FOOD_STOCK_MAX
+                );
+                display.render_text_complex(&render_pos, &str, text);
+                render_pos.y += BUFFER_Y;
+
+                if !pantry.has_room(food) {
+                    render_pos.y += BUFFER_Y;
+                    render_pos.x += shake;
+                    display.render_text_complex(&render_pos, "FULL", text);
+                } else {
+                    let sign = if args.game_ctx.wallet.can_afford(food.stock_cost()) {
+                        &assets::IMAGE_BUY_SIGN
+                    } else {
+                        &assets::IMAGE_CANT_BUY_SIGN
+                    };
+                    display.render_image_center(
+                        CENTER_X_I32 + shake,
+                        render_pos.y + sign.isize.y / 2,
+                        sign,
+                    );
+                }
+            }
         }
 
         display.render_complex(&self.particle_system);
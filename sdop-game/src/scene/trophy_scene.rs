@@ -0,0 +1,113 @@
+use fixedstr::str_format;
+use glam::IVec2;
+
+use crate::{
+    Button,
+    achievements::ACHIEVEMENTS,
+    assets,
+    display::{CENTER_X_I32, ComplexRenderOption, GameDisplay, WIDTH_I32, WrappingMode},
+    fonts::FONT_VARIABLE_SMALL,
+    scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
+};
+
+pub struct TrophyScene {
+    selected: usize,
+}
+
+impl Default for TrophyScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrophyScene {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+impl Scene for TrophyScene {
+    fn setup(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn teardown(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
+        let mut change = 0;
+
+        if args.input.pressed(Button::Left) {
+            change = -1;
+        }
+
+        if args.input.pressed(Button::Right) {
+            change += 1;
+        }
+
+        if args.input.pressed(Button::Middle) {
+            output.set_home();
+            return;
+        }
+
+        let updated = self.selected as isize + change;
+        if updated < 0 {
+            output.set_home();
+            return;
+        }
+
+        self.selected = updated as usize % ACHIEVEMENTS.len();
+    }
+
+    fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        const Y_BUFFER: i32 = 7;
+
+        let achievements = &args.game_ctx.achievements;
+        let center = ComplexRenderOption::new()
+            .with_white()
+            .with_center()
+            .with_font(&FONT_VARIABLE_SMALL);
+
+        let page = str_format!(
+            fixedstr::str12,
+            "{}/{}",
+            self.selected + 1,
+            ACHIEVEMENTS.len()
+        );
+        display.render_text_complex(&IVec2::new(CENTER_X_I32, 3), &page, center);
+
+        let unlocked = str_format!(
+            fixedstr::str24,
+            "GOT {}/{}",
+            achievements.unlocked_count(),
+            ACHIEVEMENTS.len()
+        );
+        display.render_text_complex(&IVec2::new(CENTER_X_I32, 10), &unlocked, center);
+
+        let achievement = ACHIEVEMENTS[self.selected];
+        let image = if achievements.is_unlocked(achievement) {
+            &assets::IMAGE_TROPHY_UNLOCKED
+        } else {
+            &assets::IMAGE_TROPHY_LOCKED
+        };
+        display.render_image_center(CENTER_X_I32, 30, image);
+
+        let mut render_pos = IVec2::new(CENTER_X_I32, 30 + image.isize.y / 2 + Y_BUFFER);
+        display.render_text_complex(&render_pos, achievement.name, center);
+
+        render_pos.y += Y_BUFFER;
+        let (done, needed) = achievements.progress(achievement);
+        display.render_text_complex(
+            &render_pos,
+            &str_format!(fixedstr::str24, "{}/{}", done, needed),
+            center,
+        );
+
+        render_pos.y += Y_BUFFER * 2;
+        display.render_text_complex(
+            &IVec2::new(5, render_pos.y),
+            achievement.desc,
+            ComplexRenderOption::new()
+                .with_white()
+                .with_font(&FONT_VARIABLE_SMALL)
+                .with_font_wrapping_x(WrappingMode::WholeWord(WIDTH_I32 - 10)),
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use fixedstr::str_format;
+use glam::{IVec2, Vec2};
+
+use crate::{
+    Button, Timestamp,
+    display::{CENTER_X_I32, ComplexRenderOption, GameDisplay, WIDTH_F32},
+    fonts::FONT_VARIABLE_SMALL,
+    game_consts::VITALS_SAMPLE_INTERVAL,
+    pet::Mood,
+    scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
+    vitals::{VITALS_ENTRIES, VitalsLog, VitalsSample},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Vital {
+    Stomach,
+    Mood,
+    Weight,
+    Ill,
+    Sleeping,
+    Temperature,
+}
+
+const VITAL_ORDER: &[Vital] = &[
+    Vital::Stomach,
+    Vital::Mood,
+    Vital::Weight,
+    Vital::Ill,
+    Vital::Sleeping,
+    Vital::Temperature,
+];
+
+impl Vital {
+    const fn name(&self) -> &'static str {
+        match self {
+            Vital::Stomach => "FOOD",
+            Vital::Mood => "MOOD",
+            Vital::Weight => "WEIGHT",
+            Vital::Ill => "SICK",
+            Vital::Sleeping => "SLEEP",
+            Vital::Temperature => "TEMP",
+        }
+    }
+
+    fn value(&self, sample: &VitalsSample) -> f32 {
+        match self {
+            Vital::Stomach => sample.stomach as f32,
+            Vital::Mood => match sample.mood {
+                Mood::Sad => 0.,
+                Mood::Normal => 1.,
+                Mood::Happy => 2.,
+            },
+            Vital::Weight => sample.weight as f32,
+            Vital::Ill => sample.ill as u8 as f32,
+            Vital::Sleeping => sample.sleeping as u8 as f32,
+            Vital::Temperature => sample.temperature as f32,
+        }
+    }
+
+    // States rather than amounts so they are drawn as steps
+    const fn stepped(&self) -> bool {
+        matches!(self, Vital::Mood | Vital::Ill | Vital::Sleeping)
+    }
+
+    fn range(&self, log: &VitalsLog) -> (f32, f32) {
+        match self {
+            Vital::Stomach => (0., 100.),
+            Vital::Mood => (0., 2.),
+            Vital::Ill | Vital::Sleeping => (0., 1.),
+            Vital::Weight | Vital::Temperature => {
+                let (min, max) = log
+                    .iter()
+                    .map(|(_, sample)| self.value(sample))
+                    .fold((f32::MAX, f32::MIN), |(min, max), value| {
+                        (min.min(value), max.max(value))
+                    });
+                if max - min < 1. {
+                    (min - 1., min + 1.)
+                } else {
+                    (min, max)
+                }
+            }
+        }
+    }
+
+    fn label(&self, value: f32) -> fixedstr::str12 {
+        match self {
+            Vital::Stomach => str_format!(fixedstr::str12, "{:.0}%", value),
+            Vital::Mood => match value as u8 {
+                0 => Mood::Sad.name().into(),
+                1 => Mood::Normal.name().into(),
+                _ => Mood::Happy.name().into(),
+            },
+            Vital::Weight => str_format!(fixedstr::str12, "{:.0}g", value),
+            Vital::Ill | Vital::Sleeping => if value > 0. { "YES" } else { "NO" }.into(),
+            Vital::Temperature => str_format!(fixedstr::str12, "{:.0}C", value),
+        }
+    }
+}
+
+const GRAPH_TOP: f32 = 20.;
+const GRAPH_BOTTOM: f32 = 100.;
+
+pub struct VitalsScene {
+    selected: usize,
+}
+
+impl Default for VitalsScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VitalsScene {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+impl Scene for VitalsScene {
+    fn setup(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn teardown(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
+        let mut change = 0;
+
+        if args.input.pressed(Button::Left) {
+            change = -1;
+        }
+
+        if args.input.pressed(Button::Right) {
+            change += 1;
+        }
+
+        if args.input.pressed(Button::Middle) {
+            output.set_home();
+            return;
+        }
+
+        let updated = self.selected as isize + change;
+        if updated < 0 {
+            output.set_home();
+            return;
+        }
+
+        self.selected = updated as usize % VITAL_ORDER.len();
+    }
+
+    fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        let log = &args.game_ctx.vitals;
+        let vital = VITAL_ORDER[self.selected];
+        let center = ComplexRenderOption::new()
+            .with_white()
+            .with_center()
+            .with_font(&FONT_VARIABLE_SMALL);
+        let left = ComplexRenderOption::new()
+            .with_white()
+            .with_font(&FONT_VARIABLE_SMALL);
+
+        display.render_text_complex(&IVec2::new(CENTER_X_I32, 3), vital.name(), center);
+
+        let (min, max) = vital.range(log);
+        display.render_text_complex(&IVec2::new(1, 11), &vital.label(max), left);
+        display.render_text_complex(
+            &IVec2::new(1, GRAPH_BOTTOM as i32 + 3),
+            &vital.label(min),
+            left,
+        );
+        display.render_line(
+            Vec2::new(0., GRAPH_BOTTOM + 1.),
+            Vec2::new(WIDTH_F32 - 1., GRAPH_BOTTOM + 1.),
+            true,
+        );
+
+        // Newest sample on the right edge so the graph fills in from the right, placed
+        // by when they were taken so time the pet was away shows as a gap
+        let Some((latest_at, _)) = log.iter().last() else {
+            return;
+        };
+        let span = VITALS_SAMPLE_INTERVAL * (VITALS_ENTRIES - 1) as u32;
+        let to_point = |at: Timestamp, value: f32| {
+            let from_latest = (latest_at - at).as_secs_f32() / span.as_secs_f32();
+            Vec2::new(
+                WIDTH_F32 - 1. - from_latest * (WIDTH_F32 - 1.),
+                GRAPH_BOTTOM - (value - min) / (max - min) * (GRAPH_BOTTOM - GRAPH_TOP),
+            )
+        };
+
+        let mut last: Option<Vec2> = None;
+        for (at, sample) in log.iter().filter(|(at, _)| latest_at - *at <= span) {
+            let point = to_point(at, vital.value(sample));
+            if let Some(last) = last {
+                if vital.stepped() {
+                    let corner = Vec2::new(point.x, last.y);
+                    display.render_line(last, corner, true);
+                    display.render_line(corner, point, true);
+                } else {
+                    display.render_line(last, point, true);
+                }
+            } else {
+                display.render_point(point.x as i32, point.y as i32, true);
+            }
+            last = Some(point);
+        }
+
+        if let Some(latest) = log.latest() {
+            let now = str_format!(fixedstr::str24, "NOW {}", vital.label(vital.value(latest)));
+            display.render_text_complex(&IVec2::new(CENTER_X_I32, 114), &now, center);
+        }
+
+        let oldest_at = log.iter().next().map_or(latest_at, |(at, _)| at);
+        let hours = ((latest_at - oldest_at).min(span) + VITALS_SAMPLE_INTERVAL).as_secs() / 3600;
+        display.render_text_complex(
+            &IVec2::new(CENTER_X_I32, 121),
+            &str_format!(fixedstr::str24, "LAST {}H", hours),
+            center,
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::Timelike;
+use fixedstr::str_format;
+use glam::IVec2;
+
+use crate::{
+    Button,
+    display::{CENTER_X_I32, ComplexRenderOption, GameDisplay},
+    fonts::FONT_VARIABLE_SMALL,
+    money::MoneyCategory,
+    scene::{RenderArgs, Scene, SceneOutput, SceneTickArgs},
+};
+
+const ROW_HEIGHT: i32 = 7;
+const TRANSACTIONS_PER_PAGE: usize = 7;
+// Income and spend come before the transaction pages
+const SUMMARY_PAGES: usize = 2;
+
+pub struct WalletScene {
+    selected: usize,
+}
+
+impl Default for WalletScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WalletScene {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+fn page_count(transactions: usize) -> usize {
+    SUMMARY_PAGES + transactions.div_ceil(TRANSACTIONS_PER_PAGE)
+}
+
+impl Scene for WalletScene {
+    fn setup(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn teardown(&mut self, _args: &mut SceneTickArgs) {}
+
+    fn tick(&mut self, args: &mut SceneTickArgs, output: &mut SceneOutput) {
+        let mut change = 0;
+
+        if args.input.pressed(Button::Left) {
+            change = -1;
+        }
+
+        if args.input.pressed(Button::Right) {
+            change += 1;
+        }
+
+        if args.input.pressed(Button::Middle) {
+            output.set_home();
+            return;
+        }
+
+        let updated = self.selected as isize + change;
+        if updated < 0 {
+            output.set_home();
+            return;
+        }
+
+        self.selected = updated as usize % page_count(args.game_ctx.wallet.ledger().count());
+    }
+
+    fn render(&self, display: &mut GameDisplay, args: &mut RenderArgs) {
+        let wallet = &args.game_ctx.wallet;
+        let ledger = wallet.ledger();
+        let center = ComplexRenderOption::new()
+            .with_white()
+            .with_center()
+            .with_font(&FONT_VARIABLE_SMALL);
+        let left = ComplexRenderOption::new()
+            .with_white()
+            .with_font(&FONT_VARIABLE_SMALL);
+
+        let page = str_format!(
+            fixedstr::str12,
+            "{}/{}",
+            self.selected + 1,
+            page_count(ledger.count())
+        );
+        display.render_text_complex(&IVec2::new(CENTER_X_I32, 3), &page, center);
+        display.render_text_complex(
+            &IVec2::new(CENTER_X_I32, 10),
+            &str_format!(fixedstr::str24, "BANK ${}", wallet.balance()),
+            center,
+        );
+
+        let mut y = 24;
+        match self.selected {
+            0 | 1 => {
+                let earning = self.selected == 0;
+                display.render_text_complex(
+                    &IVec2::new(CENTER_X_I32, y),
+                    if earning { "INCOME" } else { "SPENT" },
+                    center,
+                );
+                y += ROW_HEIGHT * 2;
+
+                for category in MoneyCategory::ALL {
+                    let amount = if earning {
+                        ledger.earned(category)
+                    } else {
+                        ledger.spent(category)
+                    };
+                    display.render_text_complex(&IVec2::new(1, y), category.name(), left);
+                    display.render_text_complex(
+                        &IVec2::new(30, y),
+                        &str_format!(fixedstr::str12, "${}", amount),
+                        left,
+                    );
+                    y += ROW_HEIGHT;
+                }
+            }
+            page => {
+                let start = (page - SUMMARY_PAGES) * TRANSACTIONS_PER_PAGE;
+                for transaction in ledger.iter().skip(start).take(TRANSACTIONS_PER_PAGE) {
+                    let time = transaction.timestamp.inner().time();
+                    display.render_text_complex(
+                        &IVec2::new(1, y),
+                        &str_format!(fixedstr::str12, "{:02}:{:02}", time.hour(), time.minute()),
+                        left,
+                    );
+                    display.render_text_complex(
+                        &IVec2::new(22, y),
+                        transaction.category.name(),
+                        left,
+                    );
+                    display.render_text_complex(
+                        &IVec2::new(CENTER_X_I32, y + ROW_HEIGHT),
+                        &str_format!(fixedstr::str12, "{:+}", transaction.amount),
+                        left,
+                    );
+                    y += ROW_HEIGHT * 2;
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use bincode::{Decode, Encode};
 use sdop_common::ItemCategory;
 
+use strum::IntoEnumIterator;
+
 use crate::{
     Timestamp,
-    items::{ItemKind, items_for_cata},
+    furniture::HomeFurnitureKind,
+    game_consts::{SELL_BACK_COMMON, SELL_BACK_DAILY_SWING, SELL_BACK_RARE},
+    game_context::GameContext,
+    items::{ItemKind, ItemRarity, items_for_cata},
+    money::{Money, MoneyCategory},
 };
 
 const MAX_SHOP_ITEMS: usize = 20;
@@ -90,3 +96,165 @@ impl Shop {
         self.cata_0 = item_count;
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SellRefusal {
+    NotOwned,
+    Unique,
+    InUse,
+    Worthless,
+}
+
+impl SellRefusal {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            SellRefusal::NotOwned => "NONE LEFT",
+            SellRefusal::Unique => "ONE OF A KIND",
+            SellRefusal::InUse => "IN USE",
+            SellRefusal::Worthless => "NO VALUE",
+        }
+    }
+}
+
+/// What the shop pays for one `item` on the day of `timestamp`
+pub fn sell_price(item: ItemKind, timestamp: Timestamp) -> Money {
+    let share = match item.rarity() {
+        ItemRarity::None => 0.,
+        ItemRarity::Common => SELL_BACK_COMMON,
+        ItemRarity::Rare => SELL_BACK_RARE,
+    };
+    let mut rng = fastrand::Rng::with_seed(timestamp.date_seed() ^ item as u64);
+    let swing = 1. + (rng.f32() * 2. - 1.) * SELL_BACK_DAILY_SWING;
+
+    libm::roundf(item.cost() as f32 * share * swing) as Money
+}
+
+// Placed furniture and a book someone is partway through keep one copy, gear keeps
+// a copy for every pet that packed it
+fn in_use(game_ctx: &GameContext, item: ItemKind) -> u32 {
+    let placed = HomeFurnitureKind::iter().any(|kind| {
+        kind != HomeFurnitureKind::None
+            && ItemKind::from(kind) == item
+            && game_ctx.home_layout.furniture_present(kind)
+    });
+
+    let reading = core::iter::once(&game_ctx.pet)
+        .chain(game_ctx.household.others().iter().map(|other| &other.pet))
+        .any(|pet| pet.book_history.get_reading_book(&game_ctx.inventory) == Some(item));
+
+    (placed || reading) as u32 + game_ctx.packed_count(item)
+}
+
+pub fn check_sell(
+    game_ctx: &GameContext,
+    item: ItemKind,
+    timestamp: Timestamp,
+) -> Result<Money, SellRefusal> {
+    let owned = game_ctx.inventory.item_count(item);
+    if owned == 0 {
+        return Err(SellRefusal::NotOwned);
+    }
+    if item.unique() {
+        return Err(SellRefusal::Unique);
+    }
+    if owned <= in_use(game_ctx, item) {
+        return Err(SellRefusal::InUse);
+    }
+    let price = sell_price(item, timestamp);
+    if price <= 0 {
+        return Err(SellRefusal::Worthless);
+    }
+
+    Ok(price)
+}
+
+/// Sells one `item` back to the shop returning what it was paid
+pub fn sell_item(
+    game_ctx: &mut GameContext,
+    item: ItemKind,
+    timestamp: Timestamp,
+) -> Result<Money, SellRefusal> {
+    let price = check_sell(game_ctx, item, timestamp)?;
+    game_ctx.inventory.add_item(item, -1);
+    Ok(game_ctx.wallet.earn(MoneyCategory::Shop, price, timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        explore::{ExploreSystem, LOCATIONS, Loadout},
+        furniture::HomeFurnitureLocation,
+        pet::PetInstance,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_sell_keeps_items_in_use() {
+        let now = Timestamp::default();
+        let mut game_ctx = GameContext::new(now);
+        game_ctx.inventory.add_item(ItemKind::AnalogueClock, 2);
+        game_ctx
+            .home_layout
+            .place(HomeFurnitureLocation::Top, HomeFurnitureKind::AnalogueClock);
+
+        let price = sell_item(&mut game_ctx, ItemKind::AnalogueClock, now).unwrap();
+        assert_eq!(game_ctx.wallet.balance(), price);
+        assert_eq!(
+            sell_item(&mut game_ctx, ItemKind::AnalogueClock, now),
+            Err(SellRefusal::InUse)
+        );
+        assert_eq!(game_ctx.inventory.item_count(ItemKind::AnalogueClock), 1);
+
+        assert_eq!(
+            check_sell(&game_ctx, ItemKind::Telescope, now),
+            Err(SellRefusal::Unique)
+        );
+        assert_eq!(
+            check_sell(&game_ctx, ItemKind::DigitalClock, now),
+            Err(SellRefusal::NotOwned)
+        );
+    }
+
+    #[test]
+    fn test_packed_gear_stays_packed() {
+        let now = Timestamp::default();
+        let mut game_ctx = GameContext::new(now);
+        let location = LOCATIONS[0];
+        let packed = Loadout::new(None, Some(ItemKind::FishingRod));
+        game_ctx.inventory.add_item(ItemKind::FishingRod, 2);
+        let mut explore = ExploreSystem::default();
+        explore.start_exploring(location.id, packed);
+        game_ctx
+            .household
+            .add(PetInstance::default(), explore, Default::default());
+
+        game_ctx.start_exploring(location.id, packed);
+        assert_eq!(game_ctx.packed_count(ItemKind::FishingRod), 2);
+        assert_eq!(
+            check_sell(&game_ctx, ItemKind::FishingRod, now),
+            Err(SellRefusal::InUse)
+        );
+        assert!(!ItemKind::FishingRod.is_usable(&mut game_ctx));
+
+        // Nothing left at home for the next one to take
+        game_ctx.inventory.add_item(ItemKind::FishingRod, -1);
+        game_ctx.explore_system = ExploreSystem::default();
+        game_ctx.start_exploring(location.id, packed);
+        assert!(game_ctx.explore_system.loadout().item().is_none());
+    }
+
+    #[test]
+    fn test_sell_price_range() {
+        let cost = ItemKind::AnalogueClock.cost() as f32;
+        let base = cost * SELL_BACK_COMMON;
+        let mut prices = [0; 30];
+        for (day, price) in prices.iter_mut().enumerate() {
+            let timestamp = Timestamp::default() + core::time::Duration::from_days(day as u64);
+            *price = sell_price(ItemKind::AnalogueClock, timestamp);
+            assert!(*price as f32 >= base * (1. - SELL_BACK_DAILY_SWING) - 1.);
+            assert!((*price as f32) < cost);
+        }
+        assert!(prices.iter().any(|price| *price != prices[0]));
+    }
+}
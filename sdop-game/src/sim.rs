@@ -1,7 +1,15 @@
 use core::time::Duration;
 
 use crate::{
-    game_consts::SIM_LENGTH_STEP, poop::add_poop, scene::SceneTickArgs,
+    Timestamp,
+    away::AwaySummary,
+    events::GameEvent,
+    explore::ExploreSystem,
+    game_consts::{CATCH_UP_STEP, SIM_LENGTH_STEP},
+    game_context::GameContext,
+    pet::PetInstance,
+    poop::add_poop,
+    scene::SceneTickArgs,
     temperature::TemperatureLevel,
 };
 
@@ -17,55 +25,252 @@ pub fn tick_sim(time_scale: f32, args: &mut SceneTickArgs) {
         runs += 1;
     }
 
-    let delta = SIM_LENGTH_STEP;
     let mut timestamp =
         args.timestamp - Duration::from_micros(runs * SIM_LENGTH_STEP.as_micros() as u64);
 
     for _ in 0..runs {
         timestamp = timestamp + SIM_LENGTH_STEP;
+        sim_step(args, timestamp, SIM_LENGTH_STEP);
+    }
+}
 
-        if args.game_ctx.pet.should_die().is_none() {
-            args.game_ctx.pet.tick_mood(
-                &args.game_ctx.poops,
-                TemperatureLevel::from(args.input.temperature()),
-                &args.game_ctx.home_layout,
-            );
+/// Advances the sim over time that passed while the game was off. Steps are
+/// `CATCH_UP_STEP` long with odds scaled to match running every `SIM_LENGTH_STEP`,
+/// whatever doesn't fit a whole step is run the normal way.
+pub fn catch_up_sim(args: &mut SceneTickArgs) -> AwaySummary {
+    let total = args.delta;
+    let runs = total.as_micros() as u64 / CATCH_UP_STEP.as_micros() as u64;
+    let left_over = total - Duration::from_micros(runs * CATCH_UP_STEP.as_micros() as u64);
+
+    let mut summary = AwaySummary {
+        away: total,
+        ..Default::default()
+    };
+
+    let end = args.timestamp;
+    let mut timestamp = end - total;
+    for _ in 0..runs {
+        timestamp = timestamp + CATCH_UP_STEP;
+        let pushed = args.game_ctx.events.pushed();
+        sim_step(args, timestamp, CATCH_UP_STEP);
+        record_step(args, pushed, &mut summary);
+    }
+
+    let pushed = args.game_ctx.events.pushed();
+    args.delta = left_over;
+    tick_sim(1., args);
+    args.delta = total;
+    record_step(args, pushed, &mut summary);
+
+    summary.egg_ready = args.game_ctx.egg.is_some_and(|egg| egg.should_hatch(end));
+
+    summary
+}
+
+fn record_step(args: &SceneTickArgs, pushed: u32, summary: &mut AwaySummary) {
+    let events = &args.game_ctx.events;
+    let count = events.pushed().wrapping_sub(pushed) as usize;
+    for event in events.latest(count) {
+        summary.record(event);
+    }
+
+    let pet = &args.game_ctx.pet;
+    if pet.should_die().is_none() {
+        summary.record_food(pet.food_fill_percent(), pet.is_starving());
+    }
+}
+
+fn sim_step(args: &mut SceneTickArgs, timestamp: Timestamp, delta: Duration) {
+    let outdoor = args.game_ctx.outdoor_temperature(args.input, timestamp);
+    args.game_ctx
+        .room_temperature
+        .sim_tick(delta, outdoor, &args.game_ctx.home_layout);
+    args.game_ctx
+        .pantry
+        .sim_tick(delta, &args.game_ctx.home_layout);
+    let temperature = args.game_ctx.room_temperature.level();
+
+    let awake_at_home = awake_at_home(&args.game_ctx.pet, &args.game_ctx.explore_system) as usize
+        + args
+            .game_ctx
+            .household
+            .others()
+            .iter()
+            .filter(|other| awake_at_home(&other.pet, &other.explore))
+            .count()
+        // A linked pet visiting keeps everyone company
+        + args.game_ctx.link.connected() as usize;
+
+    sim_pet(args, timestamp, delta, temperature, awake_at_home, true);
+    // The rest of the household takes turns being selected so they sim the same way
+    for index in 0..args.game_ctx.household.others().len() {
+        args.game_ctx.swap_selected_pet(index);
+        sim_pet(args, timestamp, delta, temperature, awake_at_home, false);
+        args.game_ctx.swap_selected_pet(index);
+    }
+
+    spread_illness(args.game_ctx, delta);
+
+    let room_temperature = args.game_ctx.room_temperature.get();
+    if args.game_ctx.pet.should_die().is_none() {
+        args.game_ctx
+            .vitals
+            .sim_tick(delta, timestamp, &args.game_ctx.pet, room_temperature);
+    }
+    for other in args.game_ctx.household.others_mut() {
+        if other.pet.should_die().is_none() {
+            other
+                .vitals
+                .sim_tick(delta, timestamp, &other.pet, room_temperature);
+        }
+    }
+}
+
+fn awake_at_home(pet: &PetInstance, explore: &ExploreSystem) -> bool {
+    pet.should_die().is_none() && !pet.is_sleeping() && !explore.currently_exploring()
+}
+
+fn spread_illness(game_ctx: &mut GameContext, delta: Duration) {
+    let at_home = |pet: &PetInstance, explore: &ExploreSystem| {
+        pet.should_die().is_none() && !explore.currently_exploring()
+    };
+
+    let ill = core::iter::once((&game_ctx.pet, &game_ctx.explore_system))
+        .chain(
+            game_ctx
+                .household
+                .others()
+                .iter()
+                .map(|other| (&other.pet, &other.explore)),
+        )
+        .filter(|(pet, explore)| at_home(pet, explore) && pet.is_ill())
+        .count();
+    if ill == 0 {
+        return;
+    }
+
+    let pets = core::iter::once((&mut game_ctx.pet, &game_ctx.explore_system)).chain(
+        game_ctx
+            .household
+            .others_mut()
+            .iter_mut()
+            .map(|other| (&mut other.pet, &other.explore)),
+    );
+    for (pet, explore) in pets {
+        if !at_home(pet, explore) || pet.is_ill() {
+            continue;
+        }
+        pet.catch_illness(&mut game_ctx.sim_rng, delta, ill);
+        if pet.is_ill() {
+            game_ctx.events.push(GameEvent::GotSick);
+        }
+    }
+}
+
+fn sim_pet(
+    args: &mut SceneTickArgs,
+    timestamp: Timestamp,
+    delta: Duration,
+    temperature: TemperatureLevel,
+    awake_at_home: usize,
+    selected: bool,
+) {
+    if args.game_ctx.pet.should_die().is_none() {
+        let company = awake_at_home > 1 && !args.game_ctx.explore_system.currently_exploring();
+        args.game_ctx.pet.tick_mood(
+            &args.game_ctx.poops,
+            temperature,
+            timestamp,
+            args.game_ctx.lights.is_on(),
             args.game_ctx
-                .pet
-                .tick_breed(&mut args.game_ctx.sim_rng, args.game_ctx.egg.is_some());
+                .kitchen
+                .treating(args.game_ctx.pet.upid, timestamp),
+        );
+        args.game_ctx.pet.tick_breed(
+            &mut args.game_ctx.sim_rng,
+            delta,
+            args.game_ctx.egg.is_some(),
+        );
 
-            let poop_count = args.game_ctx.poop_count() as u8;
-            let pet = &mut args.game_ctx.pet;
+        let poop_count = args.game_ctx.poop_count() as u8;
+        let pet = &mut args.game_ctx.pet;
+        let events = &mut args.game_ctx.events;
+        let was_sleeping = pet.is_sleeping();
+        let was_ill = pet.is_ill();
 
-            pet.tick_age(delta);
+        pet.tick_age(delta);
+        pet.tick_tempeture(delta, temperature);
 
-            pet.tick_sleeping(&timestamp);
-            let sleeping = pet.is_sleeping();
+        pet.tick_sleeping(&timestamp);
+        let sleeping = pet.is_sleeping();
 
-            pet.tick_hunger(delta, timestamp, sleeping);
-            pet.tick_poop(delta);
-            pet.tick_since_game(delta, sleeping);
-            pet.tick_death(delta, &mut args.game_ctx.sim_rng, sleeping, poop_count);
-            pet.tick_evolve(delta, &args.game_ctx.inventory);
-            pet.tick_illness(&mut args.game_ctx.sim_rng, delta);
-            if pet.should_poop(
-                &mut args.game_ctx.sim_rng,
-                sleeping,
-                args.game_ctx.home.state,
-            ) {
-                add_poop(&mut args.game_ctx.poops, timestamp);
-            }
+        pet.tick_hunger(delta, timestamp, sleeping);
+        pet.tick_poop(delta);
+        pet.tick_since_game(delta, sleeping, company);
+        pet.tick_death(delta, &mut args.game_ctx.sim_rng, sleeping, poop_count);
+        pet.tick_evolve(delta, &args.game_ctx.inventory);
+        pet.tick_illness(
+            &mut args.game_ctx.sim_rng,
+            delta,
+            args.game_ctx.pantry.upset(pet.upid, timestamp),
+        );
+        if pet.should_poop(
+            &mut args.game_ctx.sim_rng,
+            sleeping,
+            args.game_ctx.home.state,
+        ) {
+            add_poop(&mut args.game_ctx.poops, timestamp);
+            events.push(GameEvent::Pooped);
+        }
+
+        if sleeping != was_sleeping {
+            events.push(if sleeping {
+                GameEvent::FellAsleep
+            } else {
+                GameEvent::WokeUp
+            });
+        }
+        if selected {
             args.game_ctx
-                .suiter_system
-                .sim_tick(delta, &mut args.game_ctx.sim_rng, pet, sleeping);
-            args.game_ctx.explore_system.sim_tick(
+                .lights
+                .sim_tick(delta, sleeping, was_sleeping && !sleeping, events);
+        }
+        if pet.is_ill() != was_ill {
+            events.push(if was_ill {
+                GameEvent::Recovered
+            } else {
+                GameEvent::GotSick
+            });
+        }
+        if let Some(cause) = pet.should_die() {
+            events.push(GameEvent::Died(cause));
+        }
+
+        if selected {
+            args.game_ctx.suiter_system.sim_tick(
                 delta,
-                &args.timestamp,
-                &mut args.game_ctx.rng,
-                &mut args.game_ctx.pet,
-                &mut args.game_ctx.inventory,
-                &mut args.game_ctx.money,
+                &mut args.game_ctx.sim_rng,
+                pet,
+                sleeping,
+                args.game_ctx.link.breeding_partner(pet.should_breed()),
+                events,
             );
         }
+        let explore_finished = args.game_ctx.explore_system.sim_tick(
+            delta,
+            &timestamp,
+            &mut args.game_ctx.rng,
+            &mut args.game_ctx.pet,
+            &mut args.game_ctx.inventory,
+            &mut args.game_ctx.wallet,
+        );
+        if explore_finished {
+            let result = args.game_ctx.explore_system.last_result();
+            args.game_ctx.events.push(GameEvent::ExploreFinished {
+                location_id: result.location.id,
+                completed: result.completed(),
+                earnings: result.earnings,
+            });
+        }
     }
 }
@@ -0,0 +1,79 @@
+use core::time::Duration;
+
+use sdop_common::LifeStage;
+
+use crate::{
+    Timestamp,
+    game_context::GameContext,
+    money::Money,
+    pet::{Mood, PetName, definition::PetDefinitionId},
+};
+
+pub type StatusName = fixedstr::str32;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ExploreStatus {
+    pub location_id: usize,
+    pub location: StatusName,
+    pub percent_complete: f32,
+}
+
+/// Read only snapshot of the pet and household for frontends
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct GameStatus {
+    pub name: PetName,
+    pub species_id: PetDefinitionId,
+    pub species: StatusName,
+    pub life_stage: LifeStage,
+    pub age: Duration,
+    pub food_fill_percent: f32,
+    pub mood: Mood,
+    pub weight: f32,
+    pub ill: bool,
+    pub sleeping: bool,
+    pub lights_on: bool,
+    pub care_mistakes: u16,
+    pub room_temperature: f32,
+    pub poop_count: u8,
+    pub household_size: u8,
+    pub money: Money,
+    pub egg_hatch_time: Option<Timestamp>,
+    pub suiter_waiting: bool,
+    pub explore: Option<ExploreStatus>,
+}
+
+impl GameStatus {
+    pub fn new(game_ctx: &GameContext) -> Self {
+        let pet = &game_ctx.pet;
+        let definition = pet.definition();
+        let explore_system = &game_ctx.explore_system;
+
+        Self {
+            name: pet.name,
+            species_id: pet.def_id,
+            species: StatusName::from(definition.name),
+            life_stage: definition.life_stage,
+            age: pet.age,
+            food_fill_percent: pet.food_fill_percent(),
+            mood: pet.mood(),
+            weight: pet.weight(),
+            ill: pet.is_ill(),
+            sleeping: pet.is_sleeping(),
+            lights_on: game_ctx.lights.is_on(),
+            care_mistakes: game_ctx.lights.care_mistakes,
+            room_temperature: game_ctx.room_temperature.get(),
+            poop_count: game_ctx.poop_count() as u8,
+            household_size: game_ctx.household.len() as u8,
+            money: game_ctx.wallet.balance(),
+            egg_hatch_time: game_ctx.egg.map(|egg| egg.hatch_time),
+            suiter_waiting: game_ctx.suiter_system.suiter_waiting(),
+            explore: explore_system.currently_exploring().then(|| ExploreStatus {
+                location_id: explore_system.current_location().id,
+                location: StatusName::from(explore_system.current_location().name),
+                percent_complete: explore_system.percent_complete(),
+            }),
+        }
+    }
+}
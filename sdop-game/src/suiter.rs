@@ -3,10 +3,11 @@ use core::time::Duration;
 use bincode::{Decode, Encode};
 
 use crate::{
-    death::passed_threshold_chance,
+    death::{passed_sim_threshold_chance, sim_odds},
+    events::{GameEvent, GameEvents},
     game_consts::{SUITER_LEAVE_ODDS, SUITER_SHOW_UP_ODDS_THRESHOLD},
     pet::{
-        PetInstance, PetName, UniquePetId,
+        ParentInfo, PetInstance, PetName, UniquePetId,
         definition::{PET_ADULTS, PetDefinitionId},
         gen_pid, random_name,
     },
@@ -30,6 +31,16 @@ impl Suiter {
             waiting: Duration::ZERO,
         }
     }
+
+    /// A real pet from a linked device
+    pub fn from_partner(partner: &ParentInfo) -> Self {
+        Self {
+            pet_def_id: partner.def_id(),
+            upid: partner.upid(),
+            name: *partner.name(),
+            waiting: Duration::ZERO,
+        }
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -54,13 +65,41 @@ impl SuiterSystem {
         rng: &mut fastrand::Rng,
         pet: &PetInstance,
         sleeping: bool,
+        partner: Option<ParentInfo>,
+        events: &mut GameEvents,
+    ) {
+        let was_waiting = self.suiter_waiting();
+        self.tick_suiter(delta, rng, pet, sleeping, partner);
+        match (was_waiting, self.suiter_waiting()) {
+            (false, true) => events.push(GameEvent::SuiterArrived),
+            (true, false) => events.push(GameEvent::SuiterLeft),
+            _ => {}
+        }
+    }
+
+    fn tick_suiter(
+        &mut self,
+        delta: Duration,
+        rng: &mut fastrand::Rng,
+        pet: &PetInstance,
+        sleeping: bool,
+        partner: Option<ParentInfo>,
     ) {
         if let Some(suiter) = &mut self.suiter {
             suiter.waiting += delta;
         }
 
+        let partner = partner.filter(|_| pet.should_breed());
+        // A linked partner stays for as long as the link does
+        let linked = self
+            .suiter
+            .zip(partner)
+            .is_some_and(|(suiter, partner)| suiter.upid == partner.upid());
+
         if sleeping
-            || (self.suiter.is_some() && (!pet.should_breed() || rng.f32() < SUITER_LEAVE_ODDS))
+            || (self.suiter.is_some()
+                && !linked
+                && (!pet.should_breed() || rng.f32() < sim_odds(SUITER_LEAVE_ODDS, delta)))
         {
             self.suiter = None;
         }
@@ -69,10 +108,22 @@ impl SuiterSystem {
             return;
         }
 
+        // A linked partner takes the place of any stranger
+        if let Some(partner) = partner {
+            if !linked {
+                self.suiter = Some(Suiter::from_partner(&partner));
+            }
+            return;
+        }
+
         if pet.should_breed() && self.suiter.is_none() {
             self.waiting_for_suiter += delta;
-            if passed_threshold_chance(rng, SUITER_SHOW_UP_ODDS_THRESHOLD, self.waiting_for_suiter)
-            {
+            if passed_sim_threshold_chance(
+                rng,
+                SUITER_SHOW_UP_ODDS_THRESHOLD,
+                self.waiting_for_suiter,
+                delta,
+            ) {
                 self.suiter = Some(Suiter::new_random(rng));
             }
         }
@@ -1,3 +1,21 @@
+use core::{f32::consts::TAU, time::Duration};
+
+use chrono::{Datelike, Timelike};
+
+use crate::{
+    Timestamp,
+    furniture::{HomeFurnitureKind, HomeLayout},
+    game_consts::{
+        AIR_CON_TEMPERATURE, OUTDOOR_DAILY_SWING, OUTDOOR_MEAN_TEMPERATURE, OUTDOOR_SEASONAL_SWING,
+        ROOM_OUTDOOR_INFLUENCE, ROOM_TEMPERATURE_TIME_CONSTANT, ROOM_TEMPTURE,
+        SPACE_HEATER_TEMPERATURE,
+    },
+};
+
+/// Gives the outdoor temperature at a time, used when there is no sensor
+pub type OutdoorTemperatureSource = fn(Timestamp) -> f32;
+
+#[derive(Clone, Copy, PartialEq)]
 pub enum TemperatureLevel {
     VeryHot,
     Hot,
@@ -32,4 +50,109 @@ impl From<f32> for TemperatureLevel {
     }
 }
 
-impl TemperatureLevel {}
+/// Made up northern hemisphere outdoor temperature, coldest before dawn in mid January and hottest
+/// mid afternoon in mid July
+pub fn outdoor_curve(timestamp: Timestamp) -> f32 {
+    let date_time = timestamp.inner();
+    let year = (date_time.ordinal0() as f32 - 195.) / 365.;
+    let day = (date_time.num_seconds_from_midnight() as f32 / 3600. - 15.) / 24.;
+
+    OUTDOOR_MEAN_TEMPERATURE
+        + OUTDOOR_SEASONAL_SWING * libm::cosf(year * TAU)
+        + OUTDOOR_DAILY_SWING * libm::cosf(day * TAU)
+}
+
+/// The temperature inside, it drifts towards the outdoor temperature slowly
+/// unless the heater or air con hold it back
+#[derive(Default, Clone, Copy)]
+pub struct RoomTemperature {
+    current: Option<f32>,
+}
+
+impl RoomTemperature {
+    pub fn get(&self) -> f32 {
+        self.current.unwrap_or(ROOM_TEMPTURE)
+    }
+
+    pub fn level(&self) -> TemperatureLevel {
+        TemperatureLevel::from(self.get())
+    }
+
+    /// Where the room would settle given enough time
+    pub fn target(outdoor: f32, layout: &HomeLayout) -> f32 {
+        let mut target = ROOM_TEMPTURE + (outdoor - ROOM_TEMPTURE) * ROOM_OUTDOOR_INFLUENCE;
+        if layout.furniture_present(HomeFurnitureKind::SpaceHeater) {
+            target = target.max(SPACE_HEATER_TEMPERATURE);
+        }
+        if layout.furniture_present(HomeFurnitureKind::AirCon) {
+            target = target.min(AIR_CON_TEMPERATURE);
+        }
+        target
+    }
+
+    pub fn sim_tick(&mut self, delta: Duration, outdoor: f32, layout: &HomeLayout) {
+        let target = Self::target(outdoor, layout);
+        self.current = Some(match self.current {
+            // Starts settled after boot
+            None => target,
+            Some(current) => {
+                let ratio = -libm::expm1f(
+                    -delta.as_secs_f32() / ROOM_TEMPERATURE_TIME_CONSTANT.as_secs_f32(),
+                );
+                current + (target - current) * ratio
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    use super::*;
+    use crate::furniture::HomeFurnitureLocation;
+
+    fn at(month: u32, hour: u32) -> Timestamp {
+        Timestamp::new(NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2025, month, 15).unwrap(),
+            NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_outdoor_curve() {
+        assert!(outdoor_curve(at(7, 15)) > outdoor_curve(at(7, 3)));
+        assert!(outdoor_curve(at(7, 15)) > outdoor_curve(at(1, 15)));
+        let layout = HomeLayout::default();
+        assert!(
+            TemperatureLevel::from(RoomTemperature::target(outdoor_curve(at(7, 15)), &layout))
+                .is_hot()
+        );
+        assert!(
+            TemperatureLevel::from(RoomTemperature::target(outdoor_curve(at(1, 3)), &layout))
+                .is_cold()
+        );
+    }
+
+    #[test]
+    fn test_room_inertia() {
+        let mut layout = HomeLayout::default();
+        let mut room = RoomTemperature::default();
+        room.sim_tick(Duration::from_secs(1), ROOM_TEMPTURE, &layout);
+        assert_eq!(room.get(), ROOM_TEMPTURE);
+
+        room.sim_tick(Duration::from_mins(1), -20., &layout);
+        assert!(room.get() < ROOM_TEMPTURE);
+        assert!(!room.level().is_cold());
+        for _ in 0..(6 * 60) {
+            room.sim_tick(Duration::from_mins(1), -20., &layout);
+        }
+        assert!(room.level().is_cold());
+
+        layout.place(HomeFurnitureLocation::Left, HomeFurnitureKind::SpaceHeater);
+        for _ in 0..(6 * 60) {
+            room.sim_tick(Duration::from_mins(1), -20., &layout);
+        }
+        assert!(!room.level().is_cold());
+    }
+}
@@ -0,0 +1,233 @@
+use core::time::Duration;
+
+use bincode::{Decode, Encode};
+
+use crate::{
+    Timestamp,
+    game_consts::VITALS_SAMPLE_INTERVAL,
+    pet::{Mood, PetInstance, UniquePetId},
+};
+
+pub const VITALS_ENTRIES: usize = 96;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, Default, PartialEq)]
+pub struct VitalsSample {
+    // Percent of the stomach filled
+    pub stomach: u8,
+    pub mood: Mood,
+    pub weight: u16,
+    pub ill: bool,
+    pub sleeping: bool,
+    pub temperature: i8,
+}
+
+impl VitalsSample {
+    pub fn new(pet: &PetInstance, temperature: f32) -> Self {
+        Self {
+            stomach: libm::roundf(pet.food_fill_percent() * 100.) as u8,
+            mood: pet.mood(),
+            weight: libm::roundf(pet.weight()) as u16,
+            ill: pet.is_ill(),
+            sleeping: pet.is_sleeping(),
+            temperature: libm::roundf(temperature) as i8,
+        }
+    }
+}
+
+/// A pet sampled every `VITALS_SAMPLE_INTERVAL`, once full the oldest sample is
+/// overwritten. Each pet in the household keeps its own, starts over for a new pet
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Copy, Clone)]
+pub struct VitalsLog {
+    upid: UniquePetId,
+    // Total ever taken, the next sample goes in at `top % VITALS_ENTRIES`
+    top: usize,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    entries: [(Timestamp, VitalsSample); VITALS_ENTRIES],
+    since_sample: Duration,
+}
+
+impl Default for VitalsLog {
+    fn default() -> Self {
+        Self {
+            upid: 0,
+            top: 0,
+            entries: [(Timestamp::default(), VitalsSample::default()); VITALS_ENTRIES],
+            since_sample: Duration::ZERO,
+        }
+    }
+}
+
+impl VitalsLog {
+    /// Rebuilds a log for `upid` from samples listed oldest first
+    pub(crate) fn from_samples(
+        upid: UniquePetId,
+        since_sample: Duration,
+        samples: impl IntoIterator<Item = (Timestamp, VitalsSample)>,
+    ) -> Self {
+        let mut result = Self {
+            upid,
+            since_sample,
+            ..Default::default()
+        };
+        for (timestamp, sample) in samples {
+            result.push(timestamp, sample);
+        }
+        result
+    }
+
+    pub fn sim_tick(
+        &mut self,
+        delta: Duration,
+        timestamp: Timestamp,
+        pet: &PetInstance,
+        temperature: f32,
+    ) {
+        if pet.upid != self.upid {
+            *self = Self {
+                upid: pet.upid,
+                ..Default::default()
+            };
+        }
+
+        self.since_sample += delta;
+        while self.since_sample >= VITALS_SAMPLE_INTERVAL {
+            self.since_sample -= VITALS_SAMPLE_INTERVAL;
+            self.push(
+                timestamp - self.since_sample,
+                VitalsSample::new(pet, temperature),
+            );
+        }
+    }
+
+    fn push(&mut self, timestamp: Timestamp, sample: VitalsSample) {
+        self.entries[self.top % VITALS_ENTRIES] = (timestamp, sample);
+        self.top += 1;
+    }
+
+    /// The pet the samples were taken from
+    pub fn upid(&self) -> UniquePetId {
+        self.upid
+    }
+
+    pub fn count(&self) -> usize {
+        self.top.min(VITALS_ENTRIES)
+    }
+
+    /// Oldest kept sample is index 0
+    pub fn get_by_index(&self, index: usize) -> Option<&VitalsSample> {
+        self.entry(index).map(|(_, sample)| sample)
+    }
+
+    fn entry(&self, index: usize) -> Option<&(Timestamp, VitalsSample)> {
+        if index >= self.count() {
+            return None;
+        }
+        let oldest = self.top - self.count();
+        Some(&self.entries[(oldest + index) % VITALS_ENTRIES])
+    }
+
+    pub fn latest(&self) -> Option<&VitalsSample> {
+        self.count()
+            .checked_sub(1)
+            .and_then(|index| self.get_by_index(index))
+    }
+
+    pub fn sampled_at(&self, index: usize) -> Option<Timestamp> {
+        self.entry(index).map(|(timestamp, _)| *timestamp)
+    }
+
+    /// Kept samples from oldest to newest along with when they were taken
+    pub fn iter(&self) -> impl Iterator<Item = (Timestamp, &VitalsSample)> {
+        (0..self.count()).filter_map(|index| {
+            self.entry(index)
+                .map(|(timestamp, sample)| (*timestamp, sample))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_roll_over() {
+        let mut log = VitalsLog::default();
+        let mut pet = PetInstance::default();
+        let start = Timestamp::default();
+
+        let mut now = start;
+        for step in 0..(VITALS_ENTRIES + 4) {
+            pet.extra_weight = step as f32;
+            now = now + VITALS_SAMPLE_INTERVAL;
+            log.sim_tick(VITALS_SAMPLE_INTERVAL, now, &pet, 20.);
+        }
+
+        assert_eq!(log.count(), VITALS_ENTRIES);
+        let (oldest_at, oldest) = log.iter().next().unwrap();
+        let (latest_at, latest) = log.iter().last().unwrap();
+        assert_eq!(latest.weight - oldest.weight, VITALS_ENTRIES as u16 - 1);
+        assert_eq!(latest_at, now);
+        assert_eq!(
+            latest_at - oldest_at,
+            VITALS_SAMPLE_INTERVAL * (VITALS_ENTRIES as u32 - 1)
+        );
+        assert_eq!(latest.temperature, 20);
+    }
+
+    #[test]
+    fn test_catch_up_steps_keep_interval() {
+        let mut log = VitalsLog::default();
+        let pet = PetInstance::default();
+        let step = VITALS_SAMPLE_INTERVAL / 3 + Duration::from_secs(1);
+
+        let mut now = Timestamp::default();
+        for _ in 0..7 {
+            now = now + step;
+            log.sim_tick(step, now, &pet, 20.);
+        }
+
+        assert_eq!(log.count(), 2);
+        assert_eq!(
+            log.sampled_at(1).unwrap() - log.sampled_at(0).unwrap(),
+            VITALS_SAMPLE_INTERVAL
+        );
+    }
+
+    #[test]
+    fn test_gap_keeps_older_times() {
+        let mut log = VitalsLog::default();
+        let pet = PetInstance::default();
+
+        let first = Timestamp::default() + VITALS_SAMPLE_INTERVAL;
+        log.sim_tick(VITALS_SAMPLE_INTERVAL, first, &pet, 20.);
+        let later = first + Duration::from_hours(5);
+        log.sim_tick(VITALS_SAMPLE_INTERVAL, later, &pet, 20.);
+
+        assert_eq!(log.sampled_at(0), Some(first));
+        assert_eq!(log.sampled_at(1), Some(later));
+    }
+
+    #[test]
+    fn test_new_pet_starts_over() {
+        let mut log = VitalsLog::default();
+        let mut pet = PetInstance {
+            upid: 1,
+            ..Default::default()
+        };
+
+        let mut now = Timestamp::default();
+        for _ in 0..3 {
+            now = now + VITALS_SAMPLE_INTERVAL;
+            log.sim_tick(VITALS_SAMPLE_INTERVAL, now, &pet, 20.);
+        }
+        assert_eq!(log.count(), 3);
+
+        pet.upid = 2;
+        now = now + VITALS_SAMPLE_INTERVAL;
+        log.sim_tick(VITALS_SAMPLE_INTERVAL, now, &pet, 20.);
+        assert_eq!(log.count(), 1);
+        assert_eq!(log.upid(), 2);
+    }
+}
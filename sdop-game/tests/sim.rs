@@ -0,0 +1,888 @@
+use std::time::Duration;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use sdop_common::LifeStage;
+use sdop_game::{
+    ALL_ITEMS, Button, DEFAULT_OFFLINE_CAP, LoopbackTransport, Mood, PetName, Timestamp,
+    harness::{
+        ACHIEVEMENTS, AchievementGoal, DeathCause, ExploreOutcome, FOODS, FoodTaste, GameEvent,
+        Gene, Genome, Harness, HomeFurnitureKind, HomeFurnitureLocation, Ingredient, ItemKind,
+        LOCATIONS, Loadout, MenuOption, MoneyCategory, PET_ADULTS, PetDefinition, RECIPES,
+        SceneEnum,
+    },
+};
+
+fn at_home(h: &Harness) -> bool {
+    matches!(h.scene(), SceneEnum::Home(_))
+}
+
+#[test]
+fn test_hatch_new_pet() {
+    let mut h = Harness::blank();
+
+    h.enter_text("bob");
+
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+    assert_eq!(h.ctx().pet.name.as_str().trim(), "bob");
+    assert_eq!(h.pet_definition().life_stage, LifeStage::Baby);
+    assert_ne!(h.ctx().pet.upid, 0);
+    assert_eq!(h.ctx().pet_history.count(), 0);
+}
+
+#[test]
+fn test_status() {
+    let mut h = Harness::new();
+    h.give_money(250);
+
+    let status = h.game().status();
+    assert_eq!(status.name.as_str().trim(), "sdop");
+    assert_eq!(status.species.as_str(), h.pet_definition().name);
+    assert_eq!(status.life_stage, LifeStage::Baby);
+    assert_eq!(status.money, 250);
+    assert_eq!(status.poop_count, 0);
+    assert!(status.egg_hatch_time.is_none());
+    assert!(status.explore.is_none());
+    assert!(!status.ill);
+}
+
+#[test]
+fn test_deterministic() {
+    let mut a = Harness::new();
+    let mut b = Harness::new();
+
+    a.run_for(Duration::from_secs(30 * 60));
+    b.run_for(Duration::from_secs(30 * 60));
+
+    let save_a = a.game().get_save(a.now()).unwrap().to_bytes().unwrap();
+    let save_b = b.game().get_save(b.now()).unwrap().to_bytes().unwrap();
+    assert_eq!(save_a, save_b);
+}
+
+#[test]
+fn test_catch_up_matches_stepping() {
+    let away = Duration::from_secs(3 * 60 * 60 + 30);
+    let mut stepped = Harness::new();
+    let mut caught_up = Harness::new();
+
+    stepped.sim_for(away);
+    caught_up.catch_up(away);
+
+    let (stepped, caught_up) = (&stepped.ctx().pet, &caught_up.ctx().pet);
+    assert_eq!(stepped.age, caught_up.age);
+    assert_eq!(stepped.life_stage_age, caught_up.life_stage_age);
+    assert!((stepped.stomach_filled - caught_up.stomach_filled).abs() < 0.1);
+    assert!((stepped.weight() - caught_up.weight()).abs() < 0.1);
+}
+
+#[test]
+fn test_feeding() {
+    let mut h = Harness::new();
+    let start = h.now();
+    h.ctx_mut().pet.stomach_filled = 0.;
+
+    h.select_home_option(MenuOption::FoodSelect);
+    assert!(matches!(h.scene(), SceneEnum::FoodSelect(_)));
+
+    h.press(Button::Middle);
+    h.step([sdop_game::ButtonState::Up; 3]);
+    assert!(matches!(h.scene(), SceneEnum::Eat(_)));
+
+    assert!(h.run_until(Duration::from_secs(60), at_home));
+    assert!(h.ctx().pet.stomach_filled > 0.);
+    assert!(h.ctx().pet.food_history.ate_since_time(FOODS[0], start));
+}
+
+#[test]
+fn test_evolution() {
+    let mut h = Harness::new();
+    let baby = h.ctx().pet.def_id;
+    h.ctx_mut().pet.life_stage_age = Duration::from_secs(24 * 60 * 60);
+
+    assert!(h.run_until(Duration::from_secs(5 * 60), |h| {
+        matches!(h.scene(), SceneEnum::Evovle(_))
+    }));
+    assert!(h.run_until(Duration::from_secs(60), at_home));
+    assert_eq!(h.pet_definition().life_stage, LifeStage::Child);
+    assert!(h.ctx().pet.should_evolve().is_none());
+    assert!(h.drain_events().contains(&GameEvent::Evolved {
+        from: baby,
+        to: h.ctx().pet.def_id
+    }));
+}
+
+#[test]
+fn test_death() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.age = Duration::from_secs(8 * 24 * 60 * 60);
+    h.set_delta(Duration::from_secs(1));
+
+    assert!(h.run_until(Duration::from_secs(2 * 24 * 60 * 60), |h| {
+        matches!(h.scene(), SceneEnum::Death(_))
+    }));
+    let cause = h.ctx().pet.should_die().unwrap();
+    let events = h.drain_events();
+    assert_eq!(
+        events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::Died(_)))
+            .count(),
+        1
+    );
+    assert!(events.contains(&GameEvent::Died(cause)));
+
+    h.run_for(Duration::from_secs(60));
+    h.set_delta(Duration::from_millis(100));
+    h.press(Button::Middle);
+    h.enter_text("next");
+
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+    assert_eq!(h.ctx().pet_history.count(), 1);
+    let record = h.ctx().pet_history.get_by_index(0).unwrap();
+    assert!(record.died_of == cause);
+    assert_eq!(h.ctx().pet_history.stats().died_of(cause), 1);
+    assert!(h.ctx().pet.should_die().is_none());
+}
+
+#[test]
+fn test_breeding() {
+    let mut h = Harness::new();
+    let parent_upid = h.ctx().pet.upid;
+    let now = h.now();
+    h.ctx_mut().pet.evolve(PET_ADULTS[0], now);
+    h.ctx_mut().pet.life_stage_age = Duration::from_secs(3 * 24 * 60 * 60);
+
+    h.set_delta(Duration::from_secs(10));
+    assert!(h.run_until(Duration::from_secs(24 * 60 * 60), |h| {
+        h.ctx().suiter_system.suiter_waiting()
+    }));
+    assert!(h.drain_events().contains(&GameEvent::SuiterArrived));
+    h.set_delta(Duration::from_millis(100));
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Breed);
+    assert!(matches!(h.scene(), SceneEnum::Suiters(_)));
+
+    h.run_for(Duration::from_secs(10));
+    h.press(Button::Middle);
+    h.step([sdop_game::ButtonState::Up; 3]);
+    assert!(matches!(h.scene(), SceneEnum::Breed(_)));
+
+    assert!(h.run_until(Duration::from_secs(60), at_home));
+    let egg = h.ctx().egg.unwrap();
+    assert!(h.drain_events().contains(&GameEvent::EggLaid));
+    assert_eq!(egg.parents.unwrap().values[0].upid(), parent_upid);
+
+    // Pet would starve waiting out the full incubation
+    let now = h.now();
+    h.ctx_mut().egg.as_mut().unwrap().hatch_time = now;
+    assert!(h.run_until(Duration::from_secs(1), |h| {
+        matches!(h.scene(), SceneEnum::EggHatch(_))
+    }));
+    assert!(h.run_until(Duration::from_secs(60), |h| {
+        matches!(h.scene(), SceneEnum::NewPet(_))
+    }));
+
+    h.enter_text("baby");
+
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+    assert!(h.ctx().egg.is_none());
+    assert!(h.drain_events().contains(&GameEvent::EggHatched));
+    assert_eq!(h.ctx().pet.upid, egg.upid);
+    assert_eq!(h.ctx().pet.parents.unwrap().values[0].upid(), parent_upid);
+    assert!(h.ctx().pet_history.get_by_index(0).unwrap().died_of == DeathCause::Leaving);
+}
+
+#[test]
+fn test_away_summary() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 0.;
+
+    let summary = h.reload(Duration::from_secs(12 * 60 * 60));
+    assert_eq!(summary.away, Duration::from_secs(12 * 60 * 60));
+    assert!(summary.skipped.is_zero());
+    assert!(summary.starved);
+
+    h.run_for(Duration::from_secs(1));
+    assert!(matches!(h.scene(), SceneEnum::Away(_)));
+    h.press(Button::Middle);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+}
+
+#[test]
+fn test_offline_cap() {
+    let mut h = Harness::new();
+    let age = h.ctx().pet.age;
+    h.game_mut()
+        .set_offline_cap(Some(Duration::from_secs(60 * 60)));
+
+    let summary = h.reload(Duration::from_secs(3 * 60 * 60));
+    assert_eq!(summary.skipped, Duration::from_secs(2 * 60 * 60));
+    assert_eq!(h.ctx().pet.age - age, Duration::from_secs(60 * 60));
+}
+
+#[test]
+fn test_default_offline_cap() {
+    let mut h = Harness::new();
+
+    let summary = h.reload(DEFAULT_OFFLINE_CAP + Duration::from_secs(60 * 60));
+    assert_eq!(summary.skipped, Duration::from_secs(60 * 60));
+}
+
+#[test]
+fn test_food_preferences() {
+    let mut h = Harness::new();
+    let picky = (0..)
+        .map(PetDefinition::get_by_id)
+        .find(|def| {
+            let tastes = || def.food_preferences.iter().map(|pref| pref.taste);
+            tastes().any(|taste| taste == FoodTaste::Refused)
+                && tastes().any(|taste| taste == FoodTaste::Liked)
+        })
+        .unwrap();
+    let refused = FOODS
+        .iter()
+        .find(|food| picky.food_taste(food) == FoodTaste::Refused)
+        .unwrap();
+    let liked = FOODS
+        .iter()
+        .find(|food| picky.food_taste(food) == FoodTaste::Liked)
+        .unwrap();
+    let now = h.now();
+    let pet = &mut h.ctx_mut().pet;
+    pet.def_id = picky.id;
+    pet.stomach_filled = 0.;
+
+    assert!(!pet.eat(refused, now));
+    assert_eq!(pet.stomach_filled, 0.);
+    assert!(!pet.food_history.ate_since_time(refused, now));
+
+    assert!(pet.eat(liked, now));
+    assert_eq!(
+        pet.stomach_filled,
+        (liked.fill_factor * picky.food_multiplier(liked)).min(picky.stomach_size)
+    );
+
+    h.sim_for(Duration::from_secs(1));
+    assert!(h.ctx().pet.mood() == Mood::Happy);
+}
+
+#[test]
+fn test_sleep_and_lights() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 1000.;
+
+    // Harness starts at 10am, babies go to bed at 8pm
+    h.sim_for(Duration::from_secs(10 * 60 * 60 + 30 * 60));
+    assert!(h.ctx().pet.is_sleeping());
+    assert!(h.drain_events().contains(&GameEvent::FellAsleep));
+
+    h.sim_for(Duration::from_secs(60 * 60));
+    let events = h.drain_events();
+    assert!(events.contains(&GameEvent::LeftLightsOn), "{events:?}");
+    let mistakes = h.game().status().care_mistakes;
+    assert_eq!(mistakes, 3);
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Lights);
+    assert!(at_home(&h));
+    assert!(!h.ctx().lights.is_on());
+
+    h.sim_for(Duration::from_secs(60 * 60));
+    assert_eq!(h.game().status().care_mistakes, mistakes);
+
+    // Lights come back on in the morning
+    h.sim_for(Duration::from_secs(10 * 60 * 60));
+    assert!(!h.ctx().pet.is_sleeping());
+    assert!(h.ctx().lights.is_on());
+    assert!(h.drain_events().contains(&GameEvent::WokeUp));
+}
+
+#[test]
+fn test_room_temperature() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    h.set_temperature(-10.);
+
+    // The room takes a while to cool down
+    h.sim_for(Duration::from_secs(5 * 60));
+    assert!(h.game().status().room_temperature > 10.);
+
+    h.sim_for(Duration::from_secs(3 * 60 * 60));
+    h.ctx_mut().poops = Default::default();
+    h.sim_for(Duration::from_secs(1));
+    assert!(h.game().status().room_temperature < 10.);
+    assert!(h.ctx().pet.mood() == Mood::Sad);
+
+    h.ctx_mut()
+        .home_layout
+        .place(HomeFurnitureLocation::Left, HomeFurnitureKind::SpaceHeater);
+    h.sim_for(Duration::from_secs(3 * 60 * 60));
+    h.ctx_mut().poops = Default::default();
+    h.sim_for(Duration::from_secs(1));
+    assert!(h.game().status().room_temperature > 19.);
+    assert!(h.ctx().pet.mood() != Mood::Sad);
+}
+
+#[test]
+fn test_sensorless_winter() {
+    let mut h = Harness::new_at(Timestamp::new(NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+    )));
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    h.remove_sensor();
+
+    // Falls back to the outdoor curve which is freezing on a January night
+    h.sim_for(Duration::from_secs(4 * 60 * 60));
+    h.ctx_mut().poops = Default::default();
+    h.sim_for(Duration::from_secs(1));
+    assert!(h.game().status().room_temperature < 10.);
+    assert!(h.ctx().pet.mood() == Mood::Sad);
+}
+
+#[test]
+fn test_household() {
+    let mut h = Harness::new();
+    h.give_money(1000);
+
+    h.select_home_option(MenuOption::Adopt);
+    h.enter_text("two");
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+    assert_eq!(h.game().status().household_size, 2);
+    assert_eq!(h.ctx().pet.name.trim(), "two");
+    assert_eq!(h.ctx().household.others()[0].pet.name.trim(), "sdop");
+    assert!(h.ctx().wallet.balance() < 1000);
+    assert_eq!(
+        h.ctx().wallet.ledger().spent(MoneyCategory::Adopt),
+        1000 - h.ctx().wallet.balance()
+    );
+
+    // Everyone gets hungry, not just whoever is selected
+    let other_filled = h.ctx().household.others()[0].pet.stomach_filled;
+    h.sim_for(Duration::from_secs(60 * 60));
+    assert!(h.ctx().household.others()[0].pet.stomach_filled < other_filled);
+    // And each keeps their own vitals
+    let other_samples = h.ctx().household.others()[0].vitals.count();
+    assert!(other_samples > 0);
+    assert!(h.ctx().vitals.count() > 0);
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Household);
+    assert_eq!(h.ctx().pet.name.trim(), "sdop");
+    assert_eq!(h.ctx().vitals.upid(), h.ctx().pet.upid);
+    assert!(h.ctx().vitals.count() >= other_samples);
+    assert!(h.ctx().household.others()[0].vitals.count() > 0);
+
+    h.reload(Duration::from_secs(60));
+    assert_eq!(h.game().status().household_size, 2);
+    assert!(h.ctx().vitals.count() >= other_samples);
+    assert!(h.ctx().household.others()[0].vitals.count() > 0);
+
+    // A housemate dying gets shown, then the household carries on without them
+    h.ctx_mut().household.others_mut()[0].pet.should_die = Some(DeathCause::Starvation);
+    assert!(h.run_until(Duration::from_secs(1), |h| {
+        matches!(h.scene(), SceneEnum::Death(_))
+    }));
+    assert_eq!(h.ctx().pet.name.trim(), "two");
+
+    h.set_delta(Duration::from_secs(1));
+    h.run_for(Duration::from_secs(60));
+    h.set_delta(Duration::from_millis(100));
+    h.press(Button::Middle);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+    assert_eq!(h.ctx().pet.name.trim(), "sdop");
+    assert_eq!(h.game().status().household_size, 1);
+    assert_eq!(h.ctx().pet_history.count(), 1);
+}
+
+fn step_linked(
+    a: &mut Harness,
+    b: &mut Harness,
+    ta: &mut LoopbackTransport,
+    tb: &mut LoopbackTransport,
+) {
+    a.game_mut().link_poll(ta);
+    b.game_mut().link_poll(tb);
+    LoopbackTransport::exchange(ta, tb);
+    a.idle(1);
+    b.idle(1);
+}
+
+#[test]
+fn test_link_breeding() {
+    let mut a = Harness::new().with_delta(Duration::from_secs(1));
+    let mut b = Harness::new().with_delta(Duration::from_secs(1));
+    b.ctx_mut().pet.upid = a.ctx().pet.upid + 1;
+    b.ctx_mut().pet.name = PetName::from("friend");
+    for h in [&mut a, &mut b] {
+        let now = h.now();
+        h.ctx_mut().pet.evolve(PET_ADULTS[0], now);
+        h.ctx_mut().pet.life_stage_age = Duration::from_secs(3 * 24 * 60 * 60);
+    }
+    let (mut ta, mut tb) = (LoopbackTransport::default(), LoopbackTransport::default());
+
+    for _ in 0..5 {
+        step_linked(&mut a, &mut b, &mut ta, &mut tb);
+    }
+    assert!(a.ctx().link.connected() && b.ctx().link.connected());
+    assert!(a.drain_events().contains(&GameEvent::LinkConnected));
+
+    // Whoever is ready first might get a stranger, the friend takes their place
+    for _ in 0..24 * 60 * 60 {
+        if a.ctx().pet.should_breed() && b.ctx().pet.should_breed() {
+            break;
+        }
+        step_linked(&mut a, &mut b, &mut ta, &mut tb);
+    }
+    step_linked(&mut a, &mut b, &mut ta, &mut tb);
+    step_linked(&mut a, &mut b, &mut ta, &mut tb);
+    let suiter = a.ctx().suiter_system.suiter.unwrap();
+    assert_eq!(suiter.upid, b.ctx().pet.upid);
+    assert_eq!(suiter.name.trim(), "friend");
+    assert_eq!(b.ctx().suiter_system.suiter.unwrap().upid, a.ctx().pet.upid);
+
+    a.set_delta(Duration::from_millis(100));
+    a.run_for(Duration::from_secs(1));
+    a.select_home_option(MenuOption::Breed);
+    a.run_for(Duration::from_secs(10));
+    a.press(Button::Middle);
+    assert!(a.run_until(Duration::from_secs(60), at_home));
+    let egg = a.ctx().egg.unwrap();
+    assert_eq!(egg.parents.unwrap().values[1].upid(), b.ctx().pet.upid);
+
+    // Every gene comes from one of the parents give or take a mutation
+    let child = Genome::from_upid(egg.upid);
+    let parents = [a.ctx().pet.genome(), b.ctx().pet.genome()];
+    for gene in Gene::ALL {
+        assert!(
+            parents
+                .iter()
+                .any(|parent| parent.gene(gene).abs_diff(child.gene(gene)) <= 1),
+            "{gene:?}"
+        );
+    }
+
+    // The friend going quiet ends the visit
+    a.game_mut().link_poll(&mut ta);
+    a.run_for(Duration::from_secs(10));
+    a.game_mut().link_poll(&mut ta);
+    assert!(!a.ctx().link.connected());
+    assert!(a.drain_events().contains(&GameEvent::LinkLost));
+}
+
+#[test]
+fn test_achievements() {
+    let mut h = Harness::new();
+    let contact = ACHIEVEMENTS
+        .iter()
+        .find(|achievement| achievement.goal == AchievementGoal::SeeAlien)
+        .unwrap();
+    h.drain_events();
+
+    h.ctx_mut().pet.seen_alien = true;
+    h.run_for(Duration::from_millis(100));
+    let unlocked: Vec<_> = h
+        .drain_events()
+        .into_iter()
+        .filter(|event| matches!(event, GameEvent::AchievementUnlocked(_)))
+        .collect();
+    assert_eq!(unlocked, [GameEvent::AchievementUnlocked(contact.id)]);
+
+    // Unlocks outlive the pet that earned them
+    h.reload(Duration::from_secs(60));
+    h.ctx_mut().pet.seen_alien = false;
+    h.run_for(Duration::from_secs(1));
+    assert!(h.ctx().achievements.is_unlocked(contact));
+    assert!(
+        !h.drain_events()
+            .iter()
+            .any(|event| matches!(event, GameEvent::AchievementUnlocked(_)))
+    );
+
+    h.select_home_option(MenuOption::Trophies);
+    assert!(matches!(h.scene(), SceneEnum::Trophies(_)));
+    h.press(Button::Right);
+    h.press_times(Button::Left, 2);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+}
+
+#[test]
+fn test_vitals_log() {
+    let mut h = Harness::new();
+    h.sim_for(Duration::from_secs(60 * 60 * 6));
+
+    let vitals = &h.ctx().vitals;
+    assert_eq!(vitals.count(), 24);
+    let (first_at, first) = vitals.iter().next().unwrap();
+    let (last_at, last) = vitals.iter().last().unwrap();
+    assert_eq!(last_at - first_at, Duration::from_secs(60 * 15 * 23));
+    assert!(last.stomach < first.stomach);
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Vitals);
+    assert!(matches!(h.scene(), SceneEnum::Vitals(_)));
+    h.press(Button::Right);
+    h.press_times(Button::Left, 2);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    // Time away is sampled too
+    h.reload(Duration::from_secs(60 * 60));
+    assert_eq!(h.ctx().vitals.count(), 28);
+}
+
+#[test]
+fn test_wallet_ledger() {
+    let mut h = Harness::new();
+    h.give_money(1000);
+
+    h.select_home_option(MenuOption::Adopt);
+    h.enter_text("two");
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    let wallet = h.ctx().wallet;
+    let ledger = wallet.ledger();
+    assert_eq!(ledger.count(), 2);
+    let adopt = ledger.get_latest(0).unwrap();
+    assert_eq!(adopt.category, MoneyCategory::Adopt);
+    assert_eq!(wallet.balance(), 1000 + adopt.amount);
+    assert_eq!(ledger.earned(MoneyCategory::Gift), 1000);
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Wallet);
+    assert!(matches!(h.scene(), SceneEnum::Wallet(_)));
+    h.press_times(Button::Right, 2);
+    h.press_times(Button::Left, 3);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    h.reload(Duration::from_secs(60));
+    assert_eq!(h.ctx().wallet.balance(), wallet.balance());
+    assert_eq!(h.ctx().wallet.ledger().count(), 2);
+}
+
+#[test]
+fn test_sell_back() {
+    let mut h = Harness::new();
+    for item in ALL_ITEMS {
+        h.ctx_mut().inventory.clear_item(item);
+    }
+    h.ctx_mut().inventory.add_item(ItemKind::AnalogueClock, 2);
+    h.ctx_mut()
+        .home_layout
+        .place(HomeFurnitureLocation::Top, HomeFurnitureKind::AnalogueClock);
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Shop);
+    assert!(matches!(h.scene(), SceneEnum::Shop(_)));
+    h.press(Button::Middle);
+    h.press(Button::Middle);
+
+    let sold = h.ctx().wallet.ledger().earned(MoneyCategory::Shop);
+    assert!(sold > 0);
+    assert_eq!(h.ctx().wallet.balance(), sold);
+    assert_eq!(h.ctx().inventory.item_count(ItemKind::AnalogueClock), 1);
+
+    // The placed clock stays
+    h.press(Button::Middle);
+    assert_eq!(h.ctx().inventory.item_count(ItemKind::AnalogueClock), 1);
+    assert_eq!(h.ctx().wallet.balance(), sold);
+
+    h.press_times(Button::Left, 2);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+}
+
+fn at_stove(h: &Harness, sweet_spot: bool) -> bool {
+    matches!(
+        h.scene(),
+        SceneEnum::Cook(scene) if scene.cooking() && scene.in_sweet_spot() == sweet_spot
+    )
+}
+
+#[test]
+fn test_cook_on_stove() {
+    let mut h = Harness::new();
+    let recipe = RECIPES
+        .iter()
+        .find(|recipe| {
+            h.ctx().pet.food_taste(recipe.food) != FoodTaste::Refused
+                && recipe
+                    .ingredients
+                    .iter()
+                    .all(|ingredient| matches!(ingredient, Ingredient::Food(_)))
+        })
+        .unwrap();
+    for item in ALL_ITEMS {
+        h.ctx_mut().inventory.clear_item(item);
+    }
+    h.ctx_mut().inventory.add_item(ItemKind::Stove, 1);
+    h.ctx_mut().inventory.add_item(recipe.food.item, 1);
+    // Enough for the burnt attempt and the one that works
+    for ingredient in recipe.ingredients {
+        if let Ingredient::Food(food) = ingredient {
+            assert!(h.ctx_mut().pantry.add(food));
+            assert!(h.ctx_mut().pantry.add(food));
+        }
+    }
+    h.ctx_mut()
+        .home_layout
+        .place(HomeFurnitureLocation::Left, HomeFurnitureKind::Stove);
+    h.run_for(Duration::from_secs(1));
+
+    // Fine steps so the press lands where the marker was seen
+    h.set_delta(Duration::from_millis(10));
+    let open_stove = |h: &mut Harness| {
+        h.select_home_option(MenuOption::Inventory);
+        h.press_times(Button::Middle, 2);
+        assert!(matches!(h.scene(), SceneEnum::Cook(_)));
+        h.press(Button::Middle);
+    };
+
+    open_stove(&mut h);
+    assert!(h.run_until(Duration::from_secs(3), |h| at_stove(h, false)));
+    h.press(Button::Middle);
+    assert!(h.run_until(Duration::from_secs(5), at_home));
+    assert_eq!(h.ctx().kitchen.cooked_count(recipe.food), 0);
+    assert_eq!(h.ctx().pet.food_history.consumed_count(recipe.food), 0);
+
+    open_stove(&mut h);
+    assert!(h.run_until(Duration::from_secs(3), |h| at_stove(h, true)));
+    h.press(Button::Middle);
+    assert!(matches!(h.scene(), SceneEnum::Eat(_)));
+    assert!(h.run_until(Duration::from_secs(20), at_home));
+
+    let ctx = h.ctx();
+    assert_eq!(ctx.kitchen.cooked_count(recipe.food), 1);
+    assert_eq!(ctx.pet.food_history.consumed_count(recipe.food), 1);
+    assert!(ctx.kitchen.treating(ctx.pet.upid, h.now()));
+    assert!(ctx.inventory.has_item(recipe.food.item));
+}
+
+#[test]
+fn test_pantry_stock() {
+    let mut h = Harness::new();
+    h.give_money(1000);
+    h.ctx_mut().pet.stomach_filled = 0.;
+    let food = FOODS
+        .iter()
+        .copied()
+        .find(|food| h.ctx().inventory.has_item(food.item))
+        .unwrap();
+    let stocked = h.ctx().pantry.count(food);
+
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Shop);
+    let wares = match h.scene() {
+        SceneEnum::Shop(scene) => scene.item_count(),
+        _ => panic!("not in the shop"),
+    };
+    h.press_times(Button::Right, wares + 1);
+    h.press(Button::Middle);
+    assert_eq!(h.ctx().pantry.count(food), stocked + 1);
+    assert_eq!(h.ctx().wallet.balance(), 1000 - food.stock_cost());
+    h.press_times(Button::Left, wares + 2);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    // Nothing goes off in the fridge
+    let shelf_life = Duration::from_secs(3 * 24 * 60 * 60);
+    h.ctx_mut()
+        .home_layout
+        .place(HomeFurnitureLocation::Left, HomeFurnitureKind::Fridge);
+    let layout = h.ctx().home_layout;
+    h.ctx_mut().pantry.sim_tick(shelf_life, &layout);
+    assert_eq!(h.ctx().pantry.spoiled_count(food), 0);
+
+    h.ctx_mut().home_layout = Default::default();
+    let layout = h.ctx().home_layout;
+    h.ctx_mut().pantry.sim_tick(shelf_life, &layout);
+    assert_eq!(h.ctx().pantry.spoiled_count(food), stocked + 1);
+
+    h.select_home_option(MenuOption::FoodSelect);
+    h.press(Button::Middle);
+    assert!(matches!(h.scene(), SceneEnum::Eat(_)));
+    assert!(h.run_until(Duration::from_secs(60), at_home));
+
+    let ctx = h.ctx();
+    assert_eq!(ctx.pantry.count(food), stocked);
+    assert!(ctx.pantry.upset(ctx.pet.upid, h.now()));
+}
+
+#[test]
+fn test_expedition_event() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    // A first stage event that leads somewhere new
+    let (location, choice, unlocks) = LOCATIONS
+        .iter()
+        .find_map(|location| {
+            let event = location.stage(0)?.events.first()?;
+            event
+                .choices
+                .iter()
+                .enumerate()
+                .find_map(|(i, choice)| match choice.outcome {
+                    ExploreOutcome::Unlock(unlocks) => Some((*location, i, unlocks)),
+                    _ => None,
+                })
+        })
+        .unwrap();
+    h.ctx_mut().inventory.clear_item(unlocks.item);
+
+    // Setting off again until the event comes up
+    for _ in 0..100 {
+        h.ctx_mut().explore_system = Default::default();
+        h.ctx_mut()
+            .explore_system
+            .start_exploring(location.id, Loadout::default());
+        h.run_for(Duration::from_secs(1));
+        if h.ctx().explore_system.pending_event().is_some() {
+            break;
+        }
+    }
+    assert_eq!(
+        h.ctx()
+            .explore_system
+            .pending_event()
+            .map(|event| event.name),
+        Some(location.stage(0).unwrap().events[0].name)
+    );
+
+    h.select_home_option(MenuOption::Explore);
+    assert!(matches!(h.scene(), SceneEnum::ExploringPost(_)));
+    h.press_times(Button::Right, choice);
+    h.press(Button::Middle);
+    assert!(h.ctx().explore_system.pending_event().is_none());
+    assert!(h.ctx().inventory.has_item(unlocks.item));
+    h.press(Button::Left);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    h.sim_for(location.length);
+    let explore = &h.ctx().explore_system;
+    assert!(!explore.currently_exploring());
+    let (event, answered) = explore.last_result().events[0];
+    assert_eq!(event.name, location.stage(0).unwrap().events[0].name);
+    assert!(matches!(answered.outcome, ExploreOutcome::Unlock(_)));
+}
+
+#[test]
+fn test_explore_daily_wins() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    h.ctx_mut().pet.explore.bonus_skill = 1000;
+    let location = LOCATIONS[0];
+
+    h.select_home_option(MenuOption::Explore);
+    assert!(matches!(h.scene(), SceneEnum::ExploreSelect(_)));
+    // Straight through packing with nothing
+    h.press_times(Button::Middle, 4);
+    assert!(h.ctx().explore_system.currently_exploring());
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    h.sim_for(location.length + Duration::from_secs(60));
+    let now = h.now();
+    let explore = &h.ctx().explore_system;
+    assert!(explore.last_result().completed());
+    assert!(!explore.injured(h.ctx().pet.upid, now));
+    assert_eq!(explore.wins_left(location, now), 0);
+    assert_eq!(
+        explore.wins_left(location, now + Duration::from_secs(24 * 60 * 60)),
+        location.daily_wins
+    );
+    assert!(h.ctx().pet.explore.rested_at() > now);
+    assert!(h.ctx().pet.explore.ready_at(location) > now);
+
+    // Rested and cooled off but the day's win is already had
+    h.sim_for(location.cooldown);
+    h.run_for(Duration::from_secs(1));
+    h.ctx_mut().poops = Default::default();
+    h.run_for(Duration::from_secs(1));
+    h.select_home_option(MenuOption::Explore);
+    assert!(matches!(h.scene(), SceneEnum::ExploreSelect(_)));
+    h.press(Button::Middle);
+    assert!(!h.ctx().explore_system.currently_exploring());
+}
+
+#[test]
+fn test_explore_catch_up_across_midnight() {
+    let location = LOCATIONS[0];
+    let midnight = Timestamp::new(NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2025, 3, 4).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+    ));
+    let mut h = Harness::new_at(midnight - location.length - Duration::from_secs(30 * 60));
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    h.ctx_mut().pet.explore.bonus_skill = 1000;
+
+    h.ctx_mut()
+        .explore_system
+        .start_exploring(location.id, Loadout::default());
+
+    // Back well before midnight so the win counts for yesterday
+    h.catch_up(location.length + Duration::from_secs(2 * 60 * 60));
+    let now = h.now();
+    assert!(now > midnight);
+    let explore = &h.ctx().explore_system;
+    assert!(explore.last_result().completed());
+    assert_eq!(explore.wins_left(location, now), location.daily_wins);
+}
+
+#[test]
+fn test_explore_loadout() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    h.ctx_mut().inventory.add_item(ItemKind::Telescope, 1);
+    let location = LOCATIONS[0];
+    let food = FOODS
+        .iter()
+        .copied()
+        .find(|food| h.ctx().pantry.count(food) > 0)
+        .unwrap();
+    let stocked = h.ctx().pantry.count(food);
+
+    h.select_home_option(MenuOption::Explore);
+    h.press(Button::Middle);
+    // The first food in stock then the telescope
+    h.press(Button::Right);
+    h.press(Button::Middle);
+    h.press(Button::Right);
+    h.press_times(Button::Middle, 2);
+
+    let explore = &h.ctx().explore_system;
+    assert!(explore.currently_exploring());
+    assert_eq!(explore.loadout().food().unwrap().id, food.id);
+    assert_eq!(explore.loadout().item(), Some(ItemKind::Telescope));
+    assert!(explore.loadout().skill(location) > 0);
+    assert_eq!(h.ctx().pantry.count(food), stocked - 1);
+    assert!(h.run_until(Duration::from_secs(1), at_home));
+
+    h.sim_for(location.length + Duration::from_secs(60));
+    let explore = &h.ctx().explore_system;
+    assert!(!explore.currently_exploring());
+    assert!(explore.loadout().item().is_none());
+    assert_eq!(
+        explore.last_result().loadout.item(),
+        Some(ItemKind::Telescope)
+    );
+    // Nothing packed for a stroll down the road goes missing
+    assert!(explore.last_result().lost.is_none());
+    assert!(h.ctx().inventory.has_item(ItemKind::Telescope));
+}
+
+#[test]
+fn test_explore_drops_missing_gear() {
+    let mut h = Harness::new();
+    h.ctx_mut().pet.stomach_filled = 1000.;
+    h.ctx_mut().inventory.add_item(ItemKind::Telescope, 1);
+    let location = LOCATIONS[0];
+
+    h.ctx_mut()
+        .start_exploring(location.id, Loadout::new(None, Some(ItemKind::Telescope)));
+    assert_eq!(
+        h.ctx().explore_system.loadout().item(),
+        Some(ItemKind::Telescope)
+    );
+    h.ctx_mut().inventory.clear_item(ItemKind::Telescope);
+
+    h.sim_for(location.length + Duration::from_secs(60));
+    let result = h.ctx().explore_system.last_result();
+    assert!(result.loadout.item().is_none());
+    assert!(result.lost.is_none());
+}
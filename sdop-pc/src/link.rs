@@ -0,0 +1,121 @@
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use sdop_game::LinkTransport;
+
+const RETRY_INTERVAL: Duration = Duration::from_secs(2);
+// Kept short as it holds up the frame
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(100);
+
+enum Endpoint {
+    Listen(TcpListener),
+    Connect(SocketAddr),
+}
+
+/// Links to another sdop-pc over TCP, reconnecting whenever the connection drops
+pub struct TcpTransport {
+    endpoint: Endpoint,
+    stream: Option<TcpStream>,
+    last_attempt: Option<Instant>,
+}
+
+impl TcpTransport {
+    pub fn listen(addr: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        info!("Waiting for a link on {addr}");
+        Ok(Self::new(Endpoint::Listen(listener)))
+    }
+
+    pub fn connect(addr: &str) -> std::io::Result<Self> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| std::io::Error::new(ErrorKind::NotFound, "no address to link to"))?;
+        Ok(Self::new(Endpoint::Connect(addr)))
+    }
+
+    fn new(endpoint: Endpoint) -> Self {
+        Self {
+            endpoint,
+            stream: None,
+            last_attempt: None,
+        }
+    }
+
+    fn ensure_stream(&mut self) {
+        if self.stream.is_some() {
+            return;
+        }
+
+        let stream = match &self.endpoint {
+            Endpoint::Listen(listener) => match listener.accept() {
+                Ok((stream, addr)) => {
+                    info!("Linked with {addr}");
+                    Some(stream)
+                }
+                Err(_) => None,
+            },
+            Endpoint::Connect(addr) => {
+                if self
+                    .last_attempt
+                    .is_some_and(|last| last.elapsed() < RETRY_INTERVAL)
+                {
+                    return;
+                }
+                self.last_attempt = Some(Instant::now());
+                match TcpStream::connect_timeout(addr, CONNECT_TIMEOUT) {
+                    Ok(stream) => {
+                        info!("Linked with {addr}");
+                        Some(stream)
+                    }
+                    Err(err) => {
+                        warn!("Link to {addr} failed {err}");
+                        None
+                    }
+                }
+            }
+        };
+
+        self.stream = stream.filter(|stream| {
+            stream.set_nonblocking(true).is_ok() && stream.set_nodelay(true).is_ok()
+        });
+    }
+}
+
+impl LinkTransport for TcpTransport {
+    fn read(&mut self, buf: &mut [u8]) -> usize {
+        self.ensure_stream();
+        let Some(stream) = &mut self.stream else {
+            return 0;
+        };
+
+        match stream.read(buf) {
+            Ok(0) => {
+                info!("Link closed");
+                self.stream = None;
+                0
+            }
+            Ok(read) => read,
+            Err(err) if err.kind() == ErrorKind::WouldBlock => 0,
+            Err(err) => {
+                warn!("Link dropped {err}");
+                self.stream = None;
+                0
+            }
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let Some(stream) = &mut self.stream else {
+            return;
+        };
+
+        if let Err(err) = stream.write_all(bytes) {
+            warn!("Link dropped {err}");
+            self.stream = None;
+        }
+    }
+}
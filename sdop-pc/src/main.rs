@@ -1,5 +1,7 @@
 extern crate sdl2;
 
+mod link;
+
 use log::info;
 use sdl2::event::Event;
 use sdl2::image::LoadTexture;
@@ -7,18 +9,63 @@ use sdl2::keyboard::Keycode;
 use sdl2::pixels::Color;
 use sdl2::rect::Rect;
 use sdop_game::ButtonStates;
-use sdop_game::SaveFile;
+use sdop_game::SaveStorage;
+use sdop_game::SlotStorage;
 use sdop_game::Timestamp;
 use std::io::Cursor;
 use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::io::Write;
 use std::time::{Duration, Instant};
 
+use crate::link::TcpTransport;
+
 const BASE_WIDTH: u32 = sdop_game::WIDTH as u32;
 const BASE_HEIGHT: u32 = sdop_game::HEIGHT as u32;
 
 const SAVE_FILE_NAME: &str = "sdop.sav";
 
+enum Mode {
+    Play,
+    Record(String),
+    Replay(String),
+}
+
+struct Options {
+    mode: Mode,
+    link: Option<TcpTransport>,
+}
+
+impl Options {
+    fn from_args() -> Self {
+        let mut result = Self {
+            mode: Mode::Play,
+            link: None,
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), args.next()) {
+                ("--record", Some(path)) => result.mode = Mode::Record(path),
+                ("--replay", Some(path)) => result.mode = Mode::Replay(path),
+                ("--link-listen", Some(addr)) => {
+                    result.link = Some(TcpTransport::listen(&addr).unwrap())
+                }
+                ("--link-connect", Some(addr)) => {
+                    result.link = Some(TcpTransport::connect(&addr).unwrap())
+                }
+                _ => {
+                    eprintln!(
+                        "usage: sdop-pc [--record <file> | --replay <file>] [--link-listen <addr> | --link-connect <addr>]"
+                    );
+                    std::process::exit(1);
+                }
+            }
+        }
+        result
+    }
+}
+
 struct ActiveSong {
     song: sdop_game::Song,
     elasped: Duration,
@@ -35,6 +82,49 @@ impl ActiveSong {
     }
 }
 
+struct FileStorage(std::fs::File);
+
+impl FileStorage {
+    fn open(path: &str) -> std::io::Result<Self> {
+        std::fs::File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map(Self)
+    }
+}
+
+impl SaveStorage for FileStorage {
+    type Error = std::io::Error;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.seek(SeekFrom::Start(offset as u64))?;
+        // Anything past the end of the file is blank storage
+        let mut filled = 0;
+        while filled < buf.len() {
+            let read = self.0.read(&mut buf[filled..])?;
+            if read == 0 {
+                break;
+            }
+            filled += read;
+        }
+        buf[filled..].fill(0);
+        Ok(())
+    }
+
+    fn write(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error> {
+        self.0.seek(SeekFrom::Start(offset as u64))?;
+        self.0.write_all(data)?;
+        self.0.sync_data()
+    }
+}
+
+fn open_save_slots() -> std::io::Result<SlotStorage<FileStorage>> {
+    SlotStorage::open(FileStorage::open(SAVE_FILE_NAME)?)
+}
+
 pub fn timestamp() -> Timestamp {
     Timestamp::new(chrono::Local::now().naive_local())
 }
@@ -63,25 +153,47 @@ pub fn main() {
 
     let mut canvas = window.into_canvas().build().unwrap();
 
-    let mut game = sdop_game::Game::new(timestamp());
+    let Options { mode, mut link } = Options::from_args();
+
     let mut time_scale = 1.0f32;
-    let mut loaded = false;
-    if let Ok(mut file) = std::fs::File::open(SAVE_FILE_NAME) {
-        let mut bytes = vec![];
-        if file.read_to_end(&mut bytes).is_ok() {
-            match SaveFile::load_from_bytes(&bytes, timestamp(), &mut game) {
-                Ok(_) => {
-                    loaded = true;
-                    log::info!("Loadded save!")
-                }
-                Err(err) => log::error!("Error Loading save {}", err),
-            }
+    let mut save_slots = match open_save_slots() {
+        Ok(slots) => Some(slots),
+        Err(err) => {
+            log::error!("Error opening save {}", err);
+            None
         }
-    }
+    };
+    let save = match save_slots.as_mut().map(|slots| slots.load()) {
+        Some(Ok(Some(save))) => {
+            log::info!("Loadded save!");
+            Some(save)
+        }
+        Some(Ok(None)) | None => None,
+        Some(Err(err)) => {
+            log::error!("Error Loading save {:?}", err);
+            None
+        }
+    };
 
-    if !loaded {
-        game = sdop_game::Game::blank(Some(timestamp()));
-    }
+    let mut replay = None;
+    let mut game = match &mode {
+        Mode::Play => sdop_game::Game::start(timestamp(), save),
+        Mode::Record(path) => {
+            info!("Recording to {path}");
+            sdop_game::Game::recorded(timestamp(), save).unwrap()
+        }
+        Mode::Replay(path) => {
+            let loaded = sdop_game::Replay::from_bytes(&std::fs::read(path).unwrap()).unwrap();
+            info!(
+                "Replaying {path}, {} frames over {:?}",
+                loaded.frames.len(),
+                loaded.duration()
+            );
+            let game = loaded.new_game().unwrap();
+            replay = Some(loaded.frames.into_iter());
+            game
+        }
+    };
 
     canvas.set_draw_color(Color::RGB(0, 0, 0));
     canvas.clear();
@@ -104,7 +216,7 @@ pub fn main() {
 
     let mut input: ButtonStates = [sdop_game::ButtonState::Up; 3];
     'running: loop {
-        let delta = last_frame_time.elapsed();
+        let mut delta = last_frame_time.elapsed();
         last_frame_time = Instant::now();
         canvas.set_draw_color(Color::RGB(0, 0, 0));
         canvas.clear();
@@ -166,14 +278,25 @@ pub fn main() {
                 _ => {}
             }
         }
-        game.set_playing_song(playing_song);
-        game.set_sim_time_scale(time_scale);
-        game.update_input_states(input);
+        if let Some(frames) = &mut replay {
+            // Replays stop at the last frame and leave the final screen up
+            if let Some(frame) = frames.next() {
+                delta = frame.delta();
+                game.replay_frame(&frame);
+            }
+        } else {
+            game.set_playing_song(playing_song);
+            game.set_sim_time_scale(time_scale);
+            game.update_input_states(input);
 
-        // HERE add weather input
+            // HERE add weather input
 
-        game.tick(delta);
-        game.refresh_display(delta);
+            if let Some(link) = &mut link {
+                game.link_poll(link);
+            }
+            game.tick(delta);
+            game.refresh_display(delta);
+        }
         let texture = texture_creator
             .load_texture_bytes(game.get_display_bmp())
             .unwrap();
@@ -192,21 +315,22 @@ pub fn main() {
         canvas.present();
 
         let since_save = last_save_time.elapsed();
-        if let Some(save) = SaveFile::gen_save_bytes(timestamp(), &game) {
-            if since_save > Duration::from_secs(1) {
-                match save {
-                    Ok(bytes) => {
-                        let mut fs = std::fs::File::create(SAVE_FILE_NAME).unwrap();
-                        let _ = fs.write_all(&bytes);
-                        last_save_time = Instant::now();
-                    }
-                    Err(err) => {
-                        panic!("Error wirting save {}", err);
-                    }
+        if replay.is_none() && since_save > Duration::from_secs(1) {
+            if let Some(save) = game.get_save(timestamp()) {
+                let slots = save_slots.get_or_insert_with(|| {
+                    open_save_slots().unwrap_or_else(|err| panic!("Error opening save {}", err))
+                });
+                if let Err(err) = slots.save(&save) {
+                    panic!("Error wirting save {:?}", err);
                 }
+                last_save_time = Instant::now();
             }
         }
 
+        while let Some(event) = game.pull_event() {
+            info!("Event {:?}", event);
+        }
+
         // Sound
         if let Some(song) = game.pull_song() {
             sink.clear();
@@ -245,4 +369,13 @@ pub fn main() {
             std::thread::sleep(sleep_time);
         }
     }
+
+    if let Some(link) = &mut link {
+        game.link_close(link);
+    }
+
+    if let (Mode::Record(path), Some(recording)) = (&mode, game.recording()) {
+        std::fs::write(path, recording.to_bytes().unwrap()).unwrap();
+        info!("Wrote {} frames to {path}", recording.frames.len());
+    }
 }
@@ -1,29 +1,32 @@
 use embassy_rp::gpio::Output;
 use embedded_hal::spi::{SpiBus, SpiDevice};
+use sdop_game::{STORAGE_SIZE, SaveStorage};
 
 pub const SDOP_SAVE_ADDR: u16 = 0x100;
+// 512Kbit part, the most 16 bit addresses can reach
+const FRAM_SIZE: usize = 0x10000;
+
+const _: () = assert!(SDOP_SAVE_ADDR as usize + STORAGE_SIZE <= u16::MAX as usize + 1);
+const _: () = assert!(SDOP_SAVE_ADDR as usize + STORAGE_SIZE <= FRAM_SIZE);
 
 const CMD_WREN: u8 = 0x06;
 const CMD_WRITE: u8 = 0x02;
 const CMD_READ: u8 = 0x03;
 
-pub async fn write_enable<SPI: SpiBus>(
-    spi: &mut SPI,
-    cs: &mut Output<'_>,
-) -> Result<(), SPI::Error> {
+pub fn write_enable<SPI: SpiBus>(spi: &mut SPI, cs: &mut Output<'_>) -> Result<(), SPI::Error> {
     cs.set_low();
     spi.write(&[CMD_WREN])?;
     cs.set_high();
     Ok(())
 }
 
-pub async fn write<SPI: SpiBus>(
+pub fn write<SPI: SpiBus>(
     spi: &mut SPI,
     cs: &mut Output<'_>,
     addr: u16,
     data: &[u8],
 ) -> Result<(), SPI::Error> {
-    if let Err(err) = write_enable(spi, cs).await {
+    if let Err(err) = write_enable(spi, cs) {
         return Err(err);
     }
     let tx_prefix = [CMD_WRITE, (addr >> 8) as u8, (addr & 0xFF) as u8];
@@ -34,7 +37,7 @@ pub async fn write<SPI: SpiBus>(
     Ok(())
 }
 
-pub async fn read<SPI: SpiBus>(
+pub fn read<SPI: SpiBus>(
     spi: &mut SPI,
     cs: &mut Output<'_>,
     addr: u16,
@@ -42,8 +45,31 @@ pub async fn read<SPI: SpiBus>(
 ) -> Result<(), SPI::Error> {
     let tx_prefix = [CMD_READ, (addr >> 8) as u8, (addr & 0xFF) as u8];
     cs.set_low();
-    spi.write(&tx_prefix);
-    spi.read(buf);
+    spi.write(&tx_prefix)?;
+    spi.read(buf)?;
     cs.set_high();
     Ok(())
 }
+
+pub struct FramStorage<'a, 'd, SPI> {
+    spi: &'a mut SPI,
+    cs: &'a mut Output<'d>,
+}
+
+impl<'a, 'd, SPI: SpiBus> FramStorage<'a, 'd, SPI> {
+    pub fn new(spi: &'a mut SPI, cs: &'a mut Output<'d>) -> Self {
+        Self { spi, cs }
+    }
+}
+
+impl<SPI: SpiBus> SaveStorage for FramStorage<'_, '_, SPI> {
+    type Error = SPI::Error;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        read(self.spi, self.cs, SDOP_SAVE_ADDR + offset as u16, buf)
+    }
+
+    fn write(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error> {
+        write(self.spi, self.cs, SDOP_SAVE_ADDR + offset as u16, data)
+    }
+}
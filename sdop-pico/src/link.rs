@@ -0,0 +1,27 @@
+use embassy_rp::uart::BufferedUart;
+use embedded_io::{Read, ReadReady, Write};
+use sdop_game::LinkTransport;
+
+/// Links to another pico over UART, each TX wired to the other's RX
+pub struct UartTransport {
+    uart: BufferedUart,
+}
+
+impl UartTransport {
+    pub fn new(uart: BufferedUart) -> Self {
+        Self { uart }
+    }
+}
+
+impl LinkTransport for UartTransport {
+    fn read(&mut self, buf: &mut [u8]) -> usize {
+        match self.uart.read_ready() {
+            Ok(true) => self.uart.read(buf).unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let _ = self.uart.write_all(bytes);
+    }
+}
@@ -4,6 +4,7 @@
 use core::time::Duration;
 
 mod fram;
+mod link;
 mod notes;
 
 use embassy_executor::Spawner;
@@ -14,9 +15,10 @@ use embassy_rp::{
     block::ImageDef,
     gpio::{Input, Level, Output, Pull},
     i2c,
-    peripherals::{self, I2C0, SPI0},
+    peripherals::{self, I2C0, SPI0, UART1},
     pwm::{Config as PwmConfig, Pwm, SetDutyCycle},
     spi::Spi,
+    uart::{self, BufferedUart},
 };
 use embassy_sync::{
     blocking_mutex::raw::{CriticalSectionRawMutex, ThreadModeRawMutex},
@@ -33,7 +35,7 @@ use embedded_graphics::{
 };
 use fixed::types::extra::U4;
 use fixedstr::str_format;
-use sdop_game::{SaveFile, Timestamp};
+use sdop_game::{SaveFile, SlotStorage, Timestamp};
 use ssd1306::{
     I2CDisplayInterface, Ssd1306,
     mode::{BufferedGraphicsMode, DisplayConfig},
@@ -41,7 +43,7 @@ use ssd1306::{
     size::DisplaySize128x64,
 };
 
-use crate::notes::freq;
+use crate::{link::UartTransport, notes::freq};
 
 use {defmt_rtt as _, panic_probe as _};
 
@@ -66,6 +68,7 @@ fn temp36_c(adc_reading: u16) -> f32 {
 bind_interrupts!(struct Irqs {
     ADC_IRQ_FIFO => adc::InterruptHandler;
     I2C0_IRQ => i2c::InterruptHandler<I2C0>;
+    UART1_IRQ => uart::BufferedInterruptHandler<UART1>;
 });
 
 type Display = Ssd1306<
@@ -81,11 +84,10 @@ async fn save_task(mut spi: Spi<'static, SPI0, embassy_rp::spi::Async>, mut cs:
     loop {
         let save = PENDING_SAVE.receive().await;
 
-        if let Ok(save_bytes) = save.to_bytes() {
-            fram::write(&mut spi, &mut cs, sdop_game::SAVE_SIZE as u16, &save_bytes)
-                .await
-                .unwrap();
-        }
+        SlotStorage::open(fram::FramStorage::new(&mut spi, &mut cs))
+            .unwrap()
+            .save(&save)
+            .unwrap();
     }
 }
 
@@ -178,6 +180,7 @@ async fn game_task(
     mut display: Display,
     mut spi: Spi<'static, SPI0, embassy_rp::spi::Async>,
     mut cs: Output<'static>,
+    mut link: UartTransport,
     save_file: Option<SaveFile>,
 ) {
     let sender = CHANNEL.sender();
@@ -233,14 +236,13 @@ async fn game_task(
         if (loop_start - last_save) > embassy_time::Duration::from_secs(60) {
             last_save = loop_start;
             if let Some(save) = game.get_save(game.get_time()) {
-                if let Ok(save_bytes) = save.to_bytes() {
-                    if let Err(err) =
-                        fram::write(&mut spi, &mut cs, fram::SDOP_SAVE_ADDR, &save_bytes).await
-                    {
-                        loop {
-                            display.clear(BinaryColor::Off);
-                            display.flush().unwrap();
-                        }
+                let saved = SlotStorage::open(fram::FramStorage::new(&mut spi, &mut cs))
+                    .map_err(sdop_game::StorageError::Storage)
+                    .and_then(|mut slots| slots.save(&save));
+                if let Err(err) = saved {
+                    loop {
+                        display.clear(BinaryColor::Off);
+                        display.flush().unwrap();
                     }
                 }
             }
@@ -254,6 +256,7 @@ async fn game_task(
 
         // Game logic
         game.update_input_states(inputs);
+        game.link_poll(&mut link);
         game.tick(delta);
 
         if let Some(song) = game.pull_song() {
@@ -339,6 +342,19 @@ async fn main(spawner: Spawner) {
     // Chip Select pin
     let mut cs = Output::new(fram_cs, Level::High);
 
+    // Link to a friend's pico, TX on GP8 and RX on GP9
+    let link_tx_buf = cortex_m::singleton!(: [u8; 64] = [0; 64]).unwrap();
+    let link_rx_buf = cortex_m::singleton!(: [u8; 64] = [0; 64]).unwrap();
+    let link = UartTransport::new(BufferedUart::new(
+        pins.UART1,
+        pins.PIN_8,
+        pins.PIN_9,
+        Irqs,
+        link_tx_buf,
+        link_rx_buf,
+        uart::Config::default(),
+    ));
+
     let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate270)
         .into_buffered_graphics_mode();
 
@@ -397,14 +413,11 @@ async fn main(spawner: Spawner) {
     let save_file = match save_mode {
         SaveMode::Clear => None,
         SaveMode::Restore => SaveFile::from_bytes(include_bytes!("../sdop.sav")).ok(),
-        SaveMode::Load => {
-            let mut buf = [0u8; sdop_game::SAVE_SIZE];
-            fram::read(&mut spi, &mut cs, fram::SDOP_SAVE_ADDR, &mut buf)
-                .await
-                .unwrap();
-
-            SaveFile::from_bytes(&buf).ok()
-        }
+        SaveMode::Load => SlotStorage::open(fram::FramStorage::new(&mut spi, &mut cs))
+            .unwrap()
+            .load()
+            .ok()
+            .flatten(),
     };
 
     let mut sum: f32 = 0.;
@@ -474,6 +487,7 @@ async fn main(spawner: Spawner) {
             display,
             spi,
             cs,
+            link,
             save_file,
         )
         .unwrap(),
@@ -7,7 +7,7 @@ mod sdop_py {
 
     use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
     use pyo3::{PyRef, PyResult, prelude::*};
-    use sdop_game::{ButtonState, SaveFile, Timestamp};
+    use sdop_game::{ButtonState, STORAGE_SIZE, SlotStorage, Timestamp};
 
     #[pyclass]
     #[derive(Clone, Copy)]
@@ -65,8 +65,12 @@ mod sdop_py {
         }
 
         #[staticmethod]
-        fn load_from_save(now: PyRef<'_, GameTime>, data: Vec<u8>) -> PyResult<Self> {
-            if let Ok(save) = SaveFile::from_bytes(&data) {
+        fn load_from_save(now: PyRef<'_, GameTime>, mut data: Vec<u8>) -> PyResult<Self> {
+            data.resize(STORAGE_SIZE, 0);
+            if let Some(save) = SlotStorage::open(&mut data[..])
+                .ok()
+                .and_then(|mut slots| slots.load().ok().flatten())
+            {
                 let mut game = sdop_game::Game::blank(None);
                 game.load_save(now.clone().into(), save);
                 return Ok(GamePy { game: game });
@@ -109,6 +113,10 @@ mod sdop_py {
             ]);
         }
 
+        fn pull_event(mut self_: PyRefMut<'_, Self>) -> Option<String> {
+            self_.game.pull_event().map(|event| format!("{:?}", event))
+        }
+
         fn get_save_bytes(self_: PyRef<'_, Self>, time: PyRef<'_, GameTime>) -> Option<Vec<u8>> {
             self_
                 .game
@@ -116,6 +124,17 @@ mod sdop_py {
                 .and_then(|save| save.to_bytes().ok())
                 .map(|bytes| bytes.into_iter().collect())
         }
+
+        fn write_save(
+            self_: PyRef<'_, Self>,
+            time: PyRef<'_, GameTime>,
+            mut storage: Vec<u8>,
+        ) -> Option<Vec<u8>> {
+            let save = self_.game.get_save((*time).into())?;
+            storage.resize(STORAGE_SIZE, 0);
+            SlotStorage::open(&mut storage[..]).ok()?.save(&save).ok()?;
+            Some(storage)
+        }
     }
 
     /// Formats the sum of two numbers as string.
@@ -20,11 +20,12 @@ def main():
     screen = pygame.display.set_mode((width, height))
 
     sdop_filename = "sdop.sav"
+    save_storage = b""
 
     try:
         with open(sdop_filename, "rb") as f:
-            data = f.read()
-        game = GamePy.load_from_save(now_game_time(), data)
+            save_storage = f.read()
+        game = GamePy.load_from_save(now_game_time(), save_storage)
     except FileNotFoundError:
         print("File does not exist.")
 
@@ -64,10 +65,11 @@ def main():
         save_delta = datetime.now() - last_save
         if save_delta > timedelta(minutes=1):
             last_save = datetime.now()
-            save_bytes = game.get_save_bytes(now_game_time())
-            if save_bytes:
+            written = game.write_save(now_game_time(), save_storage)
+            if written:
+                save_storage = bytes(written)
                 with open(sdop_filename, "wb") as f:
-                    f.write(save_bytes)
+                    f.write(save_storage)
 
         bmp = game.display_bitmap()
         img = pygame.image.load(BytesIO(bmp)).convert()
@@ -1,11 +1,13 @@
 use std::{
-    io::{Read, Write},
-    path::PathBuf,
+    io::Write,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
 use clap::{Parser, Subcommand};
-use sdop_game::{ALL_ITEMS, SaveFile};
+use sdop_game::{
+    ALL_ITEMS, DeathCause, PetHistory, STORAGE_SIZE, SaveFile, SlotStorage, VitalsLog,
+};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -26,6 +28,82 @@ enum AppSubCommand {
         #[arg(short, long)]
         source: PathBuf,
     },
+    /// Writes every kept pet record to a csv and prints the lifetime stats
+    History {
+        #[arg(short, long)]
+        source: PathBuf,
+    },
+    /// Writes the vitals log to a csv
+    Vitals {
+        #[arg(short, long)]
+        source: PathBuf,
+    },
+}
+
+// Saves are written into A/B slots, files from before slots existed are shorter
+fn read_storage(source: &Path) -> Vec<u8> {
+    let mut bytes = std::fs::read(source).unwrap();
+    bytes.resize(bytes.len().max(STORAGE_SIZE), 0);
+    bytes
+}
+
+fn load_storage(bytes: &mut [u8]) -> Result<SaveFile, String> {
+    let mut slots = SlotStorage::open(bytes).map_err(|err| format!("{:?}", err))?;
+    match slots.load() {
+        Ok(Some(save)) => Ok(save),
+        Ok(None) => Err("no valid save found".to_string()),
+        Err(err) => Err(format!("{:?}", err)),
+    }
+}
+
+fn load_save(source: PathBuf) -> SaveFile {
+    if !source.exists() {
+        eprintln!("Must be a valid path");
+        std::process::exit(1);
+    }
+
+    match load_storage(&mut read_storage(&source)) {
+        Ok(save) => save,
+        Err(err) => {
+            eprintln!("Error decoding save file {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn history_csv(history: &PetHistory) -> String {
+    let mut result = String::from("upid,name,species,born,died,age_hours,died_of,weight\n");
+    for record in history.iter() {
+        result.push_str(&format!(
+            "{:010X},{},{},{},{},{},{},{:.0}\n",
+            record.upid,
+            record.name.trim(),
+            record.species(),
+            record.born.inner(),
+            record.death.inner(),
+            record.age().as_secs() / 3600,
+            record.died_of.name(),
+            record.weight()
+        ));
+    }
+    result
+}
+
+fn vitals_csv(vitals: &VitalsLog) -> String {
+    let mut result = String::from("time,stomach,mood,weight,ill,sleeping,temperature\n");
+    for (time, sample) in vitals.iter() {
+        result.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            time.inner(),
+            sample.stomach,
+            sample.mood.name(),
+            sample.weight,
+            sample.ill,
+            sample.sleeping,
+            sample.temperature
+        ));
+    }
+    result
 }
 
 fn main() {
@@ -35,23 +113,7 @@ fn main() {
         AppSubCommand::Decode { source, unlock_all } => {
             println!("Loading {:?}", source.as_os_str());
 
-            if !source.exists() {
-                eprintln!("Must be a valid path");
-                std::process::exit(1);
-            }
-
-            let mut save = {
-                let mut file = std::fs::File::open(source).unwrap();
-                let mut bytes = vec![];
-                file.read_to_end(&mut bytes).unwrap();
-                match SaveFile::from_bytes(&bytes) {
-                    Ok(save) => save,
-                    Err(err) => {
-                        eprintln!("Error decoding save file {}", err);
-                        std::process::exit(1);
-                    }
-                }
-            };
+            let mut save = load_save(source);
 
             if unlock_all {
                 for item in ALL_ITEMS {
@@ -90,14 +152,113 @@ fn main() {
 
             println!("Loaded save");
 
-            let bytes = save.to_bytes().unwrap();
-
             let target = PathBuf::from_str("sdop.sav").unwrap();
 
+            // Keep the existing slots so the edited save lands as the newest one
+            let mut bytes = if target.exists() {
+                read_storage(&target)
+            } else {
+                vec![0; STORAGE_SIZE]
+            };
+            SlotStorage::open(&mut bytes[..])
+                .unwrap()
+                .save(&save)
+                .unwrap();
+
             let mut file = std::fs::File::create(target.clone()).unwrap();
             file.write_all(&bytes).unwrap();
 
             println!("Wrote encoded save to {:?}", target);
         }
+        AppSubCommand::History { source } => {
+            let save = load_save(source);
+            let history = &save.pet_records;
+
+            let target = PathBuf::from_str("sdop-history.csv").unwrap();
+            let mut file = std::fs::File::create(target.clone()).unwrap();
+            file.write_all(history_csv(history).as_bytes()).unwrap();
+
+            println!("Wrote {} records to {:?}", history.count(), target);
+
+            let stats = history.stats();
+            println!("Raised {}", stats.raised);
+            if let Some(longest) = &stats.longest_lived {
+                println!(
+                    "Longest lived {} at {}h",
+                    longest.name.trim(),
+                    longest.age().as_secs() / 3600
+                );
+            }
+            for cause in DeathCause::ALL {
+                println!("{} {}", cause.name(), stats.died_of(cause));
+            }
+        }
+        AppSubCommand::Vitals { source } => {
+            let save = load_save(source);
+
+            let target = PathBuf::from_str("sdop-vitals.csv").unwrap();
+            let mut file = std::fs::File::create(target.clone()).unwrap();
+            file.write_all(vitals_csv(&save.vitals).as_bytes()).unwrap();
+
+            println!("Wrote {} samples to {:?}", save.vitals.count(), target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use sdop_game::PetRecord;
+
+    use super::*;
+
+    fn slot_file(save: &SaveFile) -> Vec<u8> {
+        let mut bytes = vec![0; STORAGE_SIZE];
+        let mut slots = SlotStorage::open(&mut bytes[..]).unwrap();
+        slots.save(&SaveFile::default()).unwrap();
+        slots.save(save).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_history_from_slot_file() {
+        let mut save = SaveFile::default();
+        let death = save.pet.born + Duration::from_secs(5 * 3600);
+        save.pet_records.add(PetRecord::from_pet_instance(
+            &save.pet,
+            death,
+            DeathCause::OldAge,
+        ));
+
+        let loaded = load_storage(&mut slot_file(&save)).unwrap();
+        let csv = history_csv(&loaded.pet_records);
+
+        let mut lines = csv.lines().skip(1);
+        let row = lines.next().unwrap();
+        assert!(row.starts_with(&format!("{:010X},", save.pet.upid)));
+        assert!(row.contains(",5,"));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_vitals_from_slot_file() {
+        let loaded = load_storage(&mut slot_file(&SaveFile::default())).unwrap();
+        let csv = vitals_csv(&loaded.vitals);
+        assert_eq!(csv.lines().count(), loaded.vitals.count() + 1);
+    }
+
+    #[test]
+    fn test_loads_newest_slot() {
+        let save = SaveFile {
+            money: 42,
+            ..Default::default()
+        };
+        assert_eq!(load_storage(&mut slot_file(&save)).unwrap().money, 42);
+    }
+
+    #[test]
+    fn test_empty_file_has_no_save() {
+        assert!(load_storage(&mut [0; STORAGE_SIZE]).is_err());
     }
 }
@@ -12,6 +12,8 @@ use notan::draw::*;
 use notan::prelude::*;
 use sdop_game::ButtonState;
 use sdop_game::Game;
+use sdop_game::STORAGE_SIZE;
+use sdop_game::SlotStorage;
 use sdop_game::Timestamp;
 use std::io::Write;
 use std::time::Duration;
@@ -45,6 +47,7 @@ const RIGHT_BUTTON: Box2D<f32, f32> = Box2D::new(
 #[derive(AppState)]
 struct State {
     game: Game,
+    save_storage: Vec<u8>,
     last_save: DateTime<Utc>,
     last_update: DateTime<Utc>,
     last_render: DateTime<Utc>,
@@ -66,23 +69,24 @@ fn setup(gfx: &mut Graphics) -> State {
     let mut game = Game::new(timestamp());
 
     let mut loaded = false;
+    let mut save_storage = vec![];
     if let Some(cookie) = wasm_cookies::get(COOKIE_NAME) {
         if let Ok(encoded) = cookie {
             if let Ok(base64_decoded) = BASE64_STANDARD.decode(encoded) {
-                let mut decompressed = vec![];
-                {
-                    let mut decoder = GzDecoder::new(&mut decompressed);
-                    decoder.write_all(&base64_decoded).unwrap();
-                }
-                if let Ok((save, _)) =
-                    bincode::decode_from_slice(&decompressed, bincode::config::standard())
-                {
-                    game.load_save(timestamp(), save);
-                    loaded = true;
-                }
+                let mut decoder = GzDecoder::new(&mut save_storage);
+                decoder.write_all(&base64_decoded).unwrap();
             }
         }
     }
+    save_storage.resize(STORAGE_SIZE, 0);
+
+    if let Some(save) = SlotStorage::open(&mut save_storage[..])
+        .ok()
+        .and_then(|mut slots| slots.load().ok().flatten())
+    {
+        game.load_save(timestamp(), save);
+        loaded = true;
+    }
 
     if !loaded {
         game = Game::blank(Some(timestamp()));
@@ -90,6 +94,7 @@ fn setup(gfx: &mut Graphics) -> State {
 
     State {
         game,
+        save_storage,
         last_save: chrono::Utc::now(),
         last_render: chrono::Utc::now(),
         last_update: chrono::Utc::now(),
@@ -183,13 +188,16 @@ fn update(app: &mut App, state: &mut State) {
     state.game.tick(delta.to_std().unwrap());
 
     if chrono::Utc::now() - state.last_save > chrono::Duration::seconds(5) {
-        if let Some(save) = sdop_game::SaveFile::gen_save_bytes(timestamp(), &state.game) {
-            match save {
-                Ok(bytes) => {
+        if let Some(save) = state.game.get_save(timestamp()) {
+            let saved = SlotStorage::open(&mut state.save_storage[..])
+                .map_err(sdop_game::StorageError::Storage)
+                .and_then(|mut slots| slots.save(&save));
+            match saved {
+                Ok(()) => {
                     let mut comprressed = vec![];
                     {
                         let mut encoder = GzEncoder::new(&mut comprressed, Compression::default());
-                        encoder.write_all(&bytes).unwrap();
+                        encoder.write_all(&state.save_storage).unwrap();
                     }
                     let base64_encoded = BASE64_STANDARD.encode(comprressed);
                     wasm_cookies::set(
@@ -202,7 +210,7 @@ fn update(app: &mut App, state: &mut State) {
                     state.last_save = chrono::Utc::now();
                 }
                 Err(err) => {
-                    panic!("Error wirting save {}", err);
+                    panic!("Error wirting save {:?}", err);
                 }
             }
         }